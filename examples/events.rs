@@ -30,6 +30,11 @@ fn main() {
             DefaultPlugins.set(ImagePlugin::default_nearest()),
             SpritesheetAnimationPlugin::default(),
         ))
+        // Also emit the optional ClipStart events
+        .insert_resource(AnimationEventSettings {
+            clip_starts: true,
+            ..default()
+        })
         .add_systems(Startup, (spawn_character, create_ui))
         .add_systems(
             Update,
@@ -146,22 +151,28 @@ fn create_ui(mut commands: Commands) {
                     });
             };
 
+            add_event(EventType::Started);
+            add_event(EventType::ClipStart);
             add_event(EventType::MarkerHit);
             add_event(EventType::ClipRepetitionEnd);
             add_event(EventType::ClipEnd);
             add_event(EventType::RepetitionEnd);
             add_event(EventType::End);
+            add_event(EventType::Empty);
         });
 }
 
 // Component attached to a UI square to be highlighted when the given event type is received
 #[derive(Debug, Component, Clone, Copy, PartialEq, Eq, Hash)]
 enum EventType {
+    Started,
+    ClipStart,
     MarkerHit,
     ClipRepetitionEnd,
     ClipEnd,
     RepetitionEnd,
     End,
+    Empty,
 }
 
 fn show_triggered_events(
@@ -174,6 +185,12 @@ fn show_triggered_events(
 
     for event in events.read() {
         match event {
+            AnimationEvent::AnimationStarted { .. } => {
+                triggered_events.insert(EventType::Started);
+            }
+            AnimationEvent::ClipStart { .. } => {
+                triggered_events.insert(EventType::ClipStart);
+            }
             AnimationEvent::MarkerHit { .. } => {
                 triggered_events.insert(EventType::MarkerHit);
             }
@@ -189,6 +206,9 @@ fn show_triggered_events(
             AnimationEvent::AnimationEnd { .. } => {
                 triggered_events.insert(EventType::End);
             }
+            AnimationEvent::EmptyAnimation { .. } => {
+                triggered_events.insert(EventType::Empty);
+            }
         }
     }
 
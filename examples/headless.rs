@@ -12,7 +12,10 @@ fn main() {
     App::new()
         .add_plugins((
             MinimalPlugins,
-            SpritesheetAnimationPlugin { enable_3d: false },
+            SpritesheetAnimationPlugin {
+                enable_3d: false,
+                ..default()
+            },
         ))
         .add_systems(Startup, spawn_animation)
         .add_systems(Update, log_animations_events)
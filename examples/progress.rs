@@ -55,14 +55,31 @@ fn spawn_character(
         ..default()
     };
 
+    let character = commands
+        .spawn((
+            Sprite::from_atlas_image(image, atlas),
+            SpritesheetAnimation::from_id(animation1_id),
+            // Store the two animation IDs in a component for convenience
+            AllAnimations {
+                animation1_id,
+                animation2_id,
+            },
+        ))
+        .id();
+
+    // Spawn a scrubber to display the progress of the animation and seek with the mouse
+
     commands.spawn((
-        Sprite::from_atlas_image(image, atlas),
-        SpritesheetAnimation::from_id(animation1_id),
-        // Store the two animation IDs in a component for convenience
-        AllAnimations {
-            animation1_id,
-            animation2_id,
+        AnimationScrubber::new(character),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(40.0),
+            left: Val::Percent(25.0),
+            width: Val::Percent(50.0),
+            height: Val::Px(30.0),
+            ..default()
         },
+        BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
     ));
 
     // Help text
@@ -1,23 +1,30 @@
-// A stress test with thousands of animated 3D sprites
+// A stress test with thousands of animated sprites
 //
 // CLI:
 //
 // Pass "2d" for 2D sprites (default)
 // Pass "3d" for 3D sprites
+// Pass "ui" for UI images
+// Pass "mix" for an even mix of 2D sprites, 3D sprites and UI images
 //
 // Pass --sprites X to render X sprites (default is 100 000)
+// Pass --seed X to spawn the same sprites and animations across runs (random by default)
+// Pass --report-interval X to print the animator timings and the event counts every X seconds (default is 5)
+//
+// The reports can be used as a performance baseline, for instance when reporting issues.
 //
 // Best executed in --release mode!
 
 #[path = "./common/mod.rs"]
 pub mod common;
 
-use bevy::prelude::*;
-use bevy_spritesheet_animation::prelude::*;
+use std::time::{Duration, Instant};
+
+use bevy::{prelude::*, render::camera::ClearColorConfig};
+use bevy_spritesheet_animation::{plugin::AnimationSystemSet, prelude::*};
 use clap::{Parser, ValueEnum};
-use common::random_position;
 use iyes_perf_ui::prelude::*;
-use rand::{seq::SliceRandom, Rng};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
 
 #[derive(ValueEnum, Clone)]
 enum Mode {
@@ -25,6 +32,10 @@ enum Mode {
     TwoD,
     #[clap(name = "3d")]
     ThreeD,
+    #[clap(name = "ui")]
+    Ui,
+    #[clap(name = "mix")]
+    Mix,
 }
 
 #[derive(Parser, Resource)]
@@ -34,6 +45,22 @@ struct Cli {
 
     #[arg(long, default_value_t = 100_000)]
     sprites: usize,
+
+    #[arg(long)]
+    seed: Option<u64>,
+
+    #[arg(long, default_value_t = 5.0)]
+    report_interval: f32,
+}
+
+// Measurements accumulated between two reports
+#[derive(Resource)]
+struct Stats {
+    last_report: Instant,
+    animator_start: Option<Instant>,
+    animator_time: Duration,
+    updates: u32,
+    events: usize,
 }
 
 fn main() {
@@ -47,7 +74,22 @@ fn main() {
             PerfUiPlugin,
         ))
         .insert_resource(cli)
+        .insert_resource(Stats {
+            last_report: Instant::now(),
+            animator_start: None,
+            animator_time: Duration::ZERO,
+            updates: 0,
+            events: 0,
+        })
         .add_systems(Startup, spawn_sprites)
+        .add_systems(
+            PostUpdate,
+            (
+                start_animator_timer.before(AnimationSystemSet),
+                (stop_animator_timer, count_events).after(AnimationSystemSet),
+            ),
+        )
+        .add_systems(Last, report_stats)
         .run();
 }
 
@@ -60,12 +102,32 @@ fn spawn_sprites(
 ) {
     // Spawn a camera
 
+    let camera_3d = (
+        Camera3d::default(),
+        Transform::from_xyz(0.0, 1000.0, 4000.0).looking_at(Vec3::ZERO, Dir3::Y),
+    );
+
     match cli.mode {
-        Mode::TwoD => commands.spawn(Camera2d),
-        Mode::ThreeD => commands.spawn((
-            Camera3d::default(),
-            Transform::from_xyz(0.0, 1000.0, 4000.0).looking_at(Vec3::ZERO, Dir3::Y),
-        )),
+        Mode::TwoD | Mode::Ui => {
+            commands.spawn(Camera2d);
+        }
+        Mode::ThreeD => {
+            commands.spawn(camera_3d);
+        }
+        Mode::Mix => {
+            // Draw the 2D sprites and the UI on top of the 3D sprites
+
+            commands.spawn(camera_3d);
+
+            commands.spawn((
+                Camera2d,
+                Camera {
+                    order: 1,
+                    clear_color: ClearColorConfig::None,
+                    ..default()
+                },
+            ));
+        }
     };
 
     // Create clips from a spritesheet
@@ -90,7 +152,10 @@ fn spawn_sprites(
 
     // Create 100 animations from those clips, each with random parameters
 
-    let mut rng = rand::thread_rng();
+    let mut rng = match cli.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
 
     let animation_directions = [
         AnimationDirection::Forwards,
@@ -119,25 +184,53 @@ fn spawn_sprites(
         ..default()
     };
 
-    for _ in 0..cli.sprites {
-        let animation = animation_ids.choose(&mut rng).unwrap();
+    for index in 0..cli.sprites {
+        let animation = SpritesheetAnimation::from_id(*animation_ids.choose(&mut rng).unwrap());
 
-        let transform = Transform::from_translation(random_position());
+        let position = Vec3::new(
+            rng.gen_range(-640.0..640.0),
+            rng.gen_range(-360.0..360.0),
+            0.0,
+        );
 
-        match cli.mode {
+        let mode = match cli.mode {
+            Mode::Mix => [Mode::TwoD, Mode::ThreeD, Mode::Ui][index % 3].clone(),
+            ref mode => mode.clone(),
+        };
+
+        match mode {
             Mode::TwoD => commands.spawn((
                 Sprite::from_atlas_image(image.clone(), atlas.clone()),
-                SpritesheetAnimation::from_id(*animation),
-                transform,
+                animation,
+                Transform::from_translation(position),
             )),
             Mode::ThreeD => commands.spawn((
                 Sprite3d::from_atlas_image(image.clone(), atlas.clone()),
-                SpritesheetAnimation::from_id(*animation),
-                transform,
+                animation,
+                Transform::from_translation(position),
+            )),
+            Mode::Ui => commands.spawn((
+                ImageNode::from_atlas_image(image.clone(), atlas.clone()),
+                animation,
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(position.x + 640.0),
+                    top: Val::Px(position.y + 360.0),
+                    width: Val::Px(96.0),
+                    height: Val::Px(96.0),
+                    ..default()
+                },
             )),
+            Mode::Mix => unreachable!(),
         };
     }
 
+    println!(
+        "spawned {} animated entities with {} animations",
+        cli.sprites,
+        animation_ids.len()
+    );
+
     // UI
 
     commands.spawn((
@@ -149,3 +242,40 @@ fn spawn_sprites(
         PerfUiWidgetBar::new(PerfUiEntryFPS::default()),
     ));
 }
+
+fn start_animator_timer(mut stats: ResMut<Stats>) {
+    stats.animator_start = Some(Instant::now());
+}
+
+fn stop_animator_timer(mut stats: ResMut<Stats>) {
+    if let Some(start) = stats.animator_start.take() {
+        stats.animator_time += start.elapsed();
+        stats.updates += 1;
+    }
+}
+
+fn count_events(mut events: EventReader<AnimationEvent>, mut stats: ResMut<Stats>) {
+    stats.events += events.read().count();
+}
+
+fn report_stats(mut stats: ResMut<Stats>, cli: Res<Cli>) {
+    let elapsed = stats.last_report.elapsed();
+
+    if elapsed.as_secs_f32() < cli.report_interval || stats.updates == 0 {
+        return;
+    }
+
+    println!(
+        "{} updates in {:.1}s | animator: {:.3}ms per update | events: {} ({:.0} per second)",
+        stats.updates,
+        elapsed.as_secs_f32(),
+        stats.animator_time.as_secs_f64() * 1000.0 / stats.updates as f64,
+        stats.events,
+        stats.events as f32 / elapsed.as_secs_f32()
+    );
+
+    stats.last_report = Instant::now();
+    stats.animator_time = Duration::ZERO;
+    stats.updates = 0;
+    stats.events = 0;
+}
@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{fmt, time::Duration};
 
 use bevy::reflect::prelude::*;
 
@@ -22,8 +22,9 @@ impl fmt::Display for AnimationId {
 /// Specifies the duration of an [Animation].
 ///
 /// Defaults to `PerFrame(100)`.
-#[derive(Debug, Clone, Copy, Reflect)]
-#[reflect(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "animation_files", derive(serde::Deserialize))]
 pub enum AnimationDuration {
     /// Specifies the duration of each frame in milliseconds
     PerFrame(u32),
@@ -42,6 +43,7 @@ impl Default for AnimationDuration {
 /// Defaults to `AnimationRepeat::Loop`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 #[reflect(Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "animation_files", derive(serde::Deserialize))]
 pub enum AnimationRepeat {
     /// Loops indefinitely
     Loop,
@@ -60,6 +62,7 @@ impl Default for AnimationRepeat {
 /// Defaults to `AnimationDirection::Forwards`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 #[reflect(Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "animation_files", derive(serde::Deserialize))]
 pub enum AnimationDirection {
     /// Frames play from left to right
     Forwards,
@@ -104,8 +107,8 @@ impl Default for AnimationDirection {
 ///
 /// let animation_id = library.register_animation(animation);
 /// ```
-#[derive(Debug, Clone, Reflect)]
-#[reflect(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
 pub struct Animation {
     /// The IDs of the [Clip](crate::prelude::Clip)s that compose this animation
     clip_ids: Vec<ClipId>,
@@ -118,6 +121,12 @@ pub struct Animation {
     direction: Option<AnimationDirection>,
     /// The optional easing of this animation
     easing: Option<Easing>,
+    /// The optional maximum rate at which this animation updates
+    max_fps: Option<u32>,
+    /// The optional exact duration of each repetition of this animation in milliseconds, see [Animation::retimed]
+    retimed_duration: Option<u32>,
+    /// Optional names of the clips of this animation
+    clip_names: Vec<(ClipId, String)>,
 }
 
 impl Animation {
@@ -129,6 +138,9 @@ impl Animation {
             repetitions: None,
             direction: None,
             easing: None,
+            max_fps: None,
+            retimed_duration: None,
+            clip_names: Vec::new(),
         }
     }
 
@@ -140,6 +152,9 @@ impl Animation {
             repetitions: None,
             direction: None,
             easing: None,
+            max_fps: None,
+            retimed_duration: None,
+            clip_names: Vec::new(),
         }
     }
 
@@ -147,6 +162,195 @@ impl Animation {
         &self.clip_ids
     }
 
+    /// Appends a clip to the animation.
+    ///
+    /// This makes it easy to splice a few reusable clips, registered once in the library, into many animations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// // Reusable clips
+    ///
+    /// let blink_id = library.register_clip(Clip::from_frames([8, 9, 8]));
+    /// let breathe_id = library.register_clip(Clip::from_frames([10, 11, 12, 11]));
+    ///
+    /// // Animations built from them
+    ///
+    /// let idle_id = library.register_clip(Clip::from_frames([0, 1]));
+    ///
+    /// let idle = Animation::from_clip(idle_id)
+    ///     .with_clip(blink_id)
+    ///     .with_clips([breathe_id, breathe_id]);
+    ///
+    /// assert_eq!(idle.clip_ids(), [idle_id, blink_id, breathe_id, breathe_id]);
+    /// ```
+    pub fn with_clip(&self, clip_id: ClipId) -> Self {
+        let mut other = self.clone();
+        other.clip_ids.push(clip_id);
+        other
+    }
+
+    pub fn add_clip(&mut self, clip_id: ClipId) -> &mut Self {
+        self.clip_ids.push(clip_id);
+        self
+    }
+
+    /// Appends several clips to the animation, see [Animation::with_clip].
+    pub fn with_clips(&self, clip_ids: impl IntoIterator<Item = ClipId>) -> Self {
+        let mut other = self.clone();
+        other.clip_ids.extend(clip_ids);
+        other
+    }
+
+    pub fn add_clips(&mut self, clip_ids: impl IntoIterator<Item = ClipId>) -> &mut Self {
+        self.clip_ids.extend(clip_ids);
+        self
+    }
+
+    /// Inserts a clip at the given position in the animation.
+    ///
+    /// The clip is appended if the position exceeds the number of clips.
+    ///
+    /// This is convenient to assemble animations procedurally, for instance to prepend an optional intro clip.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let intro_id = library.register_clip(Clip::from_frames([0, 1, 2]));
+    /// let run_id = library.register_clip(Clip::from_frames([3, 4, 5]));
+    /// let stop_id = library.register_clip(Clip::from_frames([6, 7]));
+    ///
+    /// let mut animation = Animation::from_clips([run_id, stop_id]);
+    ///
+    /// animation.insert_clip_at(0, intro_id);
+    ///
+    /// assert_eq!(animation.clip_ids(), [intro_id, run_id, stop_id]);
+    ///
+    /// animation.move_clip(stop_id, 1);
+    ///
+    /// assert_eq!(animation.clip_ids(), [intro_id, stop_id, run_id]);
+    ///
+    /// animation.remove_clip(intro_id);
+    ///
+    /// assert_eq!(animation.clip_ids(), [stop_id, run_id]);
+    /// ```
+    pub fn with_clip_at(&self, index: usize, clip_id: ClipId) -> Self {
+        let mut other = self.clone();
+        other.insert_clip_at(index, clip_id);
+        other
+    }
+
+    pub fn insert_clip_at(&mut self, index: usize, clip_id: ClipId) -> &mut Self {
+        self.clip_ids
+            .insert(index.min(self.clip_ids.len()), clip_id);
+        self
+    }
+
+    /// Removes all the occurrences of a clip from the animation, see [Animation::with_clip_at].
+    pub fn without_clip(&self, clip_id: ClipId) -> Self {
+        let mut other = self.clone();
+        other.remove_clip(clip_id);
+        other
+    }
+
+    pub fn remove_clip(&mut self, clip_id: ClipId) -> &mut Self {
+        self.clip_ids.retain(|id| *id != clip_id);
+        self.clip_names.retain(|(id, _)| *id != clip_id);
+        self
+    }
+
+    /// Moves the first occurrence of a clip to the given position in the animation, see [Animation::with_clip_at].
+    ///
+    /// The clip is moved to the end if the position exceeds the number of clips.
+    /// Nothing happens if the animation does not contain the clip.
+    pub fn with_clip_moved(&self, clip_id: ClipId, index: usize) -> Self {
+        let mut other = self.clone();
+        other.move_clip(clip_id, index);
+        other
+    }
+
+    pub fn move_clip(&mut self, clip_id: ClipId, index: usize) -> &mut Self {
+        if let Some(current_index) = self.clip_ids.iter().position(|id| *id == clip_id) {
+            self.clip_ids.remove(current_index);
+            self.clip_ids
+                .insert(index.min(self.clip_ids.len()), clip_id);
+        }
+        self
+    }
+
+    /// Gives a name to a clip of the animation.
+    ///
+    /// Clip IDs are opaque: naming the clips makes it easy to match the [AnimationEvent](crate::prelude::AnimationEvent)s of specific clips.
+    /// Unlike the names given with [AnimationLibrary::name_clip](crate::prelude::AnimationLibrary::name_clip), which are global,
+    /// these names are local to the animation, so that different animations can use the same names for their own clips.
+    ///
+    /// Naming a clip again replaces its previous name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # fn spawn_bullet() {}
+    /// # let mut library = AnimationLibrary::default();
+    /// let aim_id = library.register_clip(Clip::from_frames([0, 1]));
+    /// let shoot_id = library.register_clip(Clip::from_frames([2, 3, 4]));
+    ///
+    /// let animation = Animation::from_clips([aim_id, shoot_id]).with_clip_name(shoot_id, "shoot");
+    ///
+    /// assert_eq!(animation.clip_named("shoot"), Some(shoot_id));
+    ///
+    /// let animation_id = library.register_animation(animation);
+    ///
+    /// fn on_clip_end(mut events: EventReader<AnimationEvent>, library: Res<AnimationLibrary>) {
+    ///     for event in events.read() {
+    ///         if let AnimationEvent::ClipEnd { animation_id, clip_id, .. } = event {
+    ///             let animation = library.get_animation(*animation_id);
+    ///
+    ///             if animation.clip_named("shoot") == Some(*clip_id) {
+    ///                 spawn_bullet();
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn with_clip_name(&self, clip_id: ClipId, name: impl Into<String>) -> Self {
+        let mut other = self.clone();
+        other.set_clip_name(clip_id, name);
+        other
+    }
+
+    pub fn set_clip_name(&mut self, clip_id: ClipId, name: impl Into<String>) -> &mut Self {
+        let name = name.into();
+
+        match self.clip_names.iter_mut().find(|(id, _)| *id == clip_id) {
+            Some((_, current_name)) => *current_name = name,
+            None => self.clip_names.push((clip_id, name)),
+        }
+
+        self
+    }
+
+    /// Returns the name given to a clip of the animation, if any.
+    pub fn clip_name(&self, clip_id: ClipId) -> Option<&str> {
+        self.clip_names
+            .iter()
+            .find(|(id, _)| *id == clip_id)
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// Returns the clip of the animation with the given name, if any.
+    pub fn clip_named(&self, name: &str) -> Option<ClipId> {
+        self.clip_names
+            .iter()
+            .find(|(_, clip_name)| clip_name == name)
+            .map(|(clip_id, _)| *clip_id)
+    }
+
     pub fn duration(&self) -> &Option<AnimationDuration> {
         &self.duration
     }
@@ -210,4 +414,80 @@ impl Animation {
         self.easing = Some(easing);
         self
     }
+
+    pub fn max_fps(&self) -> &Option<u32> {
+        &self.max_fps
+    }
+
+    /// Limits the rate at which this animation updates, independently of the rendering rate.
+    ///
+    /// The time is only applied to the playback in steps of `1 / max_fps` seconds,
+    /// which gives a choppy look to the animation while the rest of the game stays smooth.
+    ///
+    /// The overall duration of the animation is unchanged.
+    pub fn with_max_fps(&self, max_fps: u32) -> Self {
+        Self {
+            max_fps: Some(max_fps),
+            ..self.clone()
+        }
+    }
+
+    pub fn set_max_fps(&mut self, max_fps: u32) -> &mut Self {
+        self.max_fps = Some(max_fps);
+        self
+    }
+
+    pub fn retimed_duration(&self) -> &Option<u32> {
+        &self.retimed_duration
+    }
+
+    /// Returns a copy of the animation that lasts the given total duration.
+    ///
+    /// The frames are resolved as usual, from the durations of the animation and its clips
+    /// (including the [specific durations](crate::prelude::Clip::with_frame_duration) of frames and the easing),
+    /// then their durations are scaled to fit the new duration.
+    /// With a finite number of [repetitions](Animation::with_repetitions), the total duration covers all of them.
+    ///
+    /// This is convenient to match an animation to gameplay timings, like the cooldown of an attack.
+    ///
+    /// # Note
+    ///
+    /// The frame durations are whole milliseconds: the rounding remainder is spread over the frames so that each repetition lasts exactly its share of the total duration.
+    /// If the total duration cannot be split evenly between the repetitions, the share of each repetition is rounded down to the millisecond.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let clip = Clip::from_frames([0, 1, 2, 3]).with_duration(AnimationDuration::PerFrame(100));
+    /// let clip_id = library.register_clip(clip);
+    ///
+    /// let attack = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(2));
+    ///
+    /// // Match the 600ms cooldown of the attack
+    ///
+    /// let attack_id = library.register_animation(attack.retimed(Duration::from_millis(600)));
+    ///
+    /// let frame_durations: Vec<Duration> = library
+    ///     .animation_frames(attack_id)
+    ///     .map(|frame| frame.duration)
+    ///     .collect();
+    ///
+    /// assert_eq!(frame_durations, vec![Duration::from_millis(75); 4]);
+    /// ```
+    pub fn retimed(&self, total_duration: Duration) -> Self {
+        let repetitions = match self.repetitions {
+            Some(AnimationRepeat::Times(repetitions)) => repetitions.max(1),
+            _ => 1,
+        };
+
+        let repetition_duration_ms = total_duration.as_millis() / repetitions as u128;
+
+        Self {
+            retimed_duration: Some(repetition_duration_ms.min(u32::MAX as u128) as u32),
+            ..self.clone()
+        }
+    }
 }
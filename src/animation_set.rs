@@ -0,0 +1,837 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use bevy::{
+    asset::{
+        io::Reader, Asset, AssetEvent, AssetId, AssetLoader, AssetServer, Assets, LoadContext,
+    },
+    ecs::{
+        event::EventReader,
+        system::{Local, Res, ResMut},
+    },
+    reflect::TypePath,
+};
+use serde::Deserialize;
+
+use crate::{
+    animation::{Animation, AnimationDirection, AnimationDuration, AnimationRepeat},
+    clip::{Clip, ClipId},
+    diagnostics,
+    easing::Easing,
+    events::AnimationMarkerId,
+    library::AnimationLibrary,
+};
+
+/// A Bevy asset that defines clips and animations in a RON file.
+///
+/// Requires the `animation_files` feature.
+///
+/// Files with the `.anim.ron` extension are loaded with the asset server like any other asset.
+/// Once loaded, their clips, animations and markers are registered in the [AnimationLibrary] under their names,
+/// so they can be retrieved with [AnimationLibrary::animation_with_name], [AnimationLibrary::clip_with_name] and [AnimationLibrary::marker_with_name].
+///
+/// When a file is modified and the asset server watches for changes (with Bevy's `file_watcher` feature),
+/// the clips and animations with the same names are updated in place and the entities playing them continue with the new parameters.
+/// The clips and animations removed from the file lose their names, but the entities playing them are not interrupted.
+/// The handle of the asset must be kept alive for the file to be reloaded.
+///
+/// The names are shared by all the sets and the clips and animations registered by the app.
+/// If a loaded set defines a name that is already in use, the definition is skipped with a warning instead of replacing the existing one.
+///
+/// The loader rejects files with unknown fields, frames outside of the declared `grid`, misplaced or duplicate markers and references to unknown clips.
+/// The errors point at the faulty definitions, see [AnimationSetLoaderError].
+///
+/// # Example
+///
+/// ```ron
+/// // assets/character.anim.ron
+/// (
+///     grid: Some((8, 2)),
+///     clips: {
+///         "run": (
+///             frames: [0, 1, 2, 3, 4, 5],
+///             duration: Some(PerFrame(80)),
+///             markers: [("footstep", 1), ("footstep", 4)],
+///         ),
+///         "jump": (
+///             frames: [8, 9, 10],
+///             easing: Some(Out(Quadratic)),
+///         ),
+///     },
+///     animations: {
+///         "run": (clips: ["run"]),
+///         "jump": (clips: ["jump"], repetitions: Some(Times(1))),
+///     },
+/// )
+/// ```
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// #[derive(Resource)]
+/// struct CharacterAnimations(Handle<AnimationSet>);
+///
+/// fn load_animations(mut commands: Commands, assets: Res<AssetServer>) {
+///     commands.insert_resource(CharacterAnimations(assets.load("character.anim.ron")));
+/// }
+///
+/// fn spawn_character(
+///     mut commands: Commands,
+///     library: Res<AnimationLibrary>,
+///     # image: Handle<Image>,
+///     # atlas: TextureAtlas,
+/// ) {
+///     if let Some(animation_id) = library.animation_with_name("run") {
+///         commands.spawn((
+///             Sprite::from_atlas_image(image, atlas),
+///             SpritesheetAnimation::from_id(animation_id),
+///         ));
+///     }
+/// }
+/// ```
+#[derive(Asset, TypePath, Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnimationSet {
+    /// The (optional) number of columns and rows of the spritesheet, to check the frame indices of the clips
+    #[serde(default)]
+    pub grid: Option<(usize, usize)>,
+
+    /// The clips of the set, by name
+    #[serde(default)]
+    pub clips: HashMap<String, ClipDefinition>,
+
+    /// The animations of the set, by name
+    #[serde(default)]
+    pub animations: HashMap<String, AnimationDefinition>,
+}
+
+/// The definition of a [Clip] in an [AnimationSet].
+///
+/// The optional parameters keep the defaults of [Clip] when omitted.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClipDefinition {
+    /// The atlas indices of the frames
+    pub frames: Vec<usize>,
+
+    /// See [Clip::with_duration]
+    #[serde(default)]
+    pub duration: Option<AnimationDuration>,
+
+    /// See [Clip::with_repetitions]
+    #[serde(default)]
+    pub repetitions: Option<usize>,
+
+    /// See [Clip::with_direction]
+    #[serde(default)]
+    pub direction: Option<AnimationDirection>,
+
+    /// See [Clip::with_easing]
+    #[serde(default)]
+    pub easing: Option<Easing>,
+
+    /// See [Clip::with_flip_x]
+    #[serde(default)]
+    pub flip_x: Option<bool>,
+
+    /// See [Clip::with_flip_y]
+    #[serde(default)]
+    pub flip_y: Option<bool>,
+
+    /// The markers of the clip, as pairs of marker names and frame indices
+    ///
+    /// The markers are created in the library if they do not exist yet.
+    /// A marker can be placed on several frames but only once per frame.
+    #[serde(default)]
+    pub markers: Vec<(String, usize)>,
+}
+
+/// The definition of an [Animation] in an [AnimationSet].
+///
+/// The optional parameters keep the defaults of [Animation] when omitted.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnimationDefinition {
+    /// The names of the clips of the animation, from the same set
+    pub clips: Vec<String>,
+
+    /// See [Animation::with_duration]
+    #[serde(default)]
+    pub duration: Option<AnimationDuration>,
+
+    /// See [Animation::with_repetitions]
+    #[serde(default)]
+    pub repetitions: Option<AnimationRepeat>,
+
+    /// See [Animation::with_direction]
+    #[serde(default)]
+    pub direction: Option<AnimationDirection>,
+
+    /// See [Animation::with_easing]
+    #[serde(default)]
+    pub easing: Option<Easing>,
+}
+
+/// The asset loader for [AnimationSet]s.
+#[derive(Debug, Default)]
+pub struct AnimationSetLoader;
+
+/// An error that occurs when loading an [AnimationSet].
+#[derive(Debug)]
+pub enum AnimationSetLoaderError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file is not a valid RON animation set (syntax errors, unknown fields, ...)
+    Ron(ron::error::SpannedError),
+    /// The file is a valid RON animation set but its content is inconsistent
+    Invalid {
+        /// The problem
+        error: AnimationSetError,
+        /// The position of the faulty definition in the file
+        position: ron::error::Position,
+    },
+}
+
+impl fmt::Display for AnimationSetLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnimationSetLoaderError::Io(error) => {
+                write!(f, "could not read the animation set: {error}")
+            }
+            AnimationSetLoaderError::Ron(error) => {
+                write!(f, "invalid animation set: {error}")
+            }
+            AnimationSetLoaderError::Invalid { error, position } => {
+                write!(
+                    f,
+                    "invalid animation set: {}:{}: {error}",
+                    position.line, position.col
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnimationSetLoaderError {}
+
+/// A problem in the content of an [AnimationSet], detected by [AnimationSet::validate].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnimationSetError {
+    /// A clip has a frame outside of the grid of the set
+    FrameOutOfBounds {
+        /// The name of the clip
+        clip: String,
+        /// The faulty atlas index
+        frame: usize,
+        /// The number of cells in the grid
+        frame_count: usize,
+    },
+    /// A marker is placed after the last frame of its clip
+    MarkerOutOfBounds {
+        /// The name of the clip
+        clip: String,
+        /// The name of the marker
+        marker: String,
+        /// The faulty frame index
+        frame_index: usize,
+    },
+    /// A marker is placed twice on the same frame of a clip
+    DuplicateMarker {
+        /// The name of the clip
+        clip: String,
+        /// The name of the marker
+        marker: String,
+        /// The frame index
+        frame_index: usize,
+    },
+    /// An animation refers to a clip that is not defined in the set
+    UnknownClip {
+        /// The name of the animation
+        animation: String,
+        /// The name of the missing clip
+        clip: String,
+    },
+}
+
+impl fmt::Display for AnimationSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnimationSetError::FrameOutOfBounds {
+                clip,
+                frame,
+                frame_count,
+            } => write!(
+                f,
+                "clip \"{clip}\" has frame {frame} but the grid only has {frame_count} cells"
+            ),
+            AnimationSetError::MarkerOutOfBounds {
+                clip,
+                marker,
+                frame_index,
+            } => write!(
+                f,
+                "marker \"{marker}\" is placed on frame {frame_index} of clip \"{clip}\", after its last frame"
+            ),
+            AnimationSetError::DuplicateMarker {
+                clip,
+                marker,
+                frame_index,
+            } => write!(
+                f,
+                "marker \"{marker}\" is placed twice on frame {frame_index} of clip \"{clip}\""
+            ),
+            AnimationSetError::UnknownClip { animation, clip } => {
+                write!(f, "animation \"{animation}\" refers to an unknown clip \"{clip}\"")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnimationSetError {}
+
+impl AnimationSetError {
+    /// Locates the faulty definition in the source of a set.
+    ///
+    /// Deserialized values do not keep their position, so this looks for the name of the definition in the map that contains it.
+    /// Falls back to the start of the file if it cannot be found.
+    fn position_in(&self, source: &str) -> ron::error::Position {
+        let strings = quoted_strings(source);
+
+        let key = |field: &str, name: &str| {
+            strings.iter().position(|string| {
+                string.field == field && string.depth == 2 && string.text == name && string.is_key
+            })
+        };
+
+        let offset = match self {
+            AnimationSetError::FrameOutOfBounds { clip, .. } => {
+                key("clips", clip).map(|index| strings[index].offset)
+            }
+            AnimationSetError::MarkerOutOfBounds {
+                clip,
+                marker,
+                frame_index,
+            }
+            | AnimationSetError::DuplicateMarker {
+                clip,
+                marker,
+                frame_index,
+            } => key("clips", clip).and_then(|index| {
+                // Out of bounds markers are reported on their first placement, duplicate ones on their second
+
+                let skip = usize::from(matches!(self, AnimationSetError::DuplicateMarker { .. }));
+
+                strings[index + 1..]
+                    .iter()
+                    .take_while(|string| string.depth > 2)
+                    .filter(|string| {
+                        string.text == marker
+                            && frame_index_after(&source[string.offset + string.text.len() + 2..])
+                                == Some(*frame_index)
+                    })
+                    .nth(skip)
+                    .map(|string| string.offset)
+            }),
+            AnimationSetError::UnknownClip { animation, clip } => key("animations", animation)
+                .and_then(|index| {
+                    strings[index + 1..]
+                        .iter()
+                        .take_while(|string| string.depth > 2)
+                        .find(|string| string.text == clip)
+                        .map(|string| string.offset)
+                }),
+        };
+
+        position_of(source, offset.unwrap_or(0))
+    }
+}
+
+/// A quoted string in the source of a set
+struct QuotedString<'a> {
+    /// The offset of the opening quote
+    offset: usize,
+    /// The content, without the quotes (escape sequences are kept as is)
+    text: &'a str,
+    /// The nesting depth (the fields of the set are at depth 1)
+    depth: usize,
+    /// The field of the set that contains the string
+    field: &'a str,
+    /// Whether the string is followed by a colon, as map keys are
+    is_key: bool,
+}
+
+/// Lists the quoted strings of the source of a set, skipping the comments
+fn quoted_strings(source: &str) -> Vec<QuotedString<'_>> {
+    let bytes = source.as_bytes();
+
+    let mut strings = Vec::new();
+    let mut depth = 0;
+    let mut field = "";
+    let mut offset = 0;
+
+    while offset < bytes.len() {
+        match bytes[offset] {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+            b'/' if bytes.get(offset + 1) == Some(&b'/') => {
+                offset = source[offset..]
+                    .find('\n')
+                    .map_or(bytes.len(), |length| offset + length);
+            }
+            b'"' => {
+                let start = offset + 1;
+
+                offset = start;
+
+                while offset < bytes.len() && bytes[offset] != b'"' {
+                    offset += if bytes[offset] == b'\\' { 2 } else { 1 };
+                }
+
+                let end = offset.min(bytes.len());
+
+                strings.push(QuotedString {
+                    offset: start - 1,
+                    text: &source[start..end],
+                    depth,
+                    field,
+                    is_key: source
+                        .get(end + 1..)
+                        .is_some_and(|after| after.trim_start().starts_with(':')),
+                });
+            }
+            byte if depth == 1 && (byte.is_ascii_alphabetic() || byte == b'_') => {
+                let length = source[offset..]
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(bytes.len() - offset);
+
+                if source[offset + length..].trim_start().starts_with(':') {
+                    field = &source[offset..offset + length];
+                }
+
+                offset += length - 1;
+            }
+            _ => {}
+        }
+
+        offset += 1;
+    }
+
+    strings
+}
+
+/// Parses the frame index that follows the name of a marker in a `("marker", frame_index)` pair
+fn frame_index_after(source: &str) -> Option<usize> {
+    let after = source.trim_start().strip_prefix(',')?.trim_start();
+
+    let digits = after
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(after.len());
+
+    after[..digits].parse().ok()
+}
+
+/// Converts an offset into a line/column position, both starting at 1
+fn position_of(source: &str, offset: usize) -> ron::error::Position {
+    let before = &source[..offset];
+
+    let line = before.matches('\n').count() + 1;
+    let col = before.len() - before.rfind('\n').map_or(0, |index| index + 1) + 1;
+
+    ron::error::Position { line, col }
+}
+
+impl From<std::io::Error> for AnimationSetLoaderError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<ron::error::SpannedError> for AnimationSetLoaderError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        Self::Ron(error)
+    }
+}
+
+impl AssetLoader for AnimationSetLoader {
+    type Asset = AnimationSet;
+    type Settings = ();
+    type Error = AnimationSetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+
+        reader.read_to_end(&mut bytes).await?;
+
+        AnimationSet::from_ron(&String::from_utf8_lossy(&bytes))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["anim.ron"]
+    }
+}
+
+impl AnimationSet {
+    /// Parses and validates an animation set from RON.
+    ///
+    /// This is what [AnimationSetLoader] does with the files loaded by the asset server.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// let result = AnimationSet::from_ron(r#"(
+    ///     grid: Some((4, 1)),
+    ///     clips: {
+    ///         "run": (frames: [0, 1, 2, 3, 4]),
+    ///     },
+    /// )"#);
+    ///
+    /// let Err(AnimationSetLoaderError::Invalid { error, position }) = result else {
+    ///     panic!();
+    /// };
+    ///
+    /// assert!(matches!(error, AnimationSetError::FrameOutOfBounds { frame: 4, .. }));
+    /// assert_eq!((position.line, position.col), (4, 9));
+    /// ```
+    pub fn from_ron(source: &str) -> Result<Self, AnimationSetLoaderError> {
+        let animation_set: AnimationSet = ron::de::from_str(source)?;
+
+        animation_set
+            .validate()
+            .map_err(|error| AnimationSetLoaderError::Invalid {
+                position: error.position_in(source),
+                error,
+            })?;
+
+        Ok(animation_set)
+    }
+
+    /// Checks the consistency of the set and returns the first problem found.
+    ///
+    /// The definitions are checked in the alphabetical order of their names.
+    pub fn validate(&self) -> Result<(), AnimationSetError> {
+        let mut clip_names: Vec<_> = self.clips.keys().collect();
+        clip_names.sort();
+
+        for clip_name in clip_names {
+            let definition = &self.clips[clip_name];
+
+            if let Some((columns, rows)) = self.grid {
+                let frame_count = columns * rows;
+
+                if let Some(&frame) = definition
+                    .frames
+                    .iter()
+                    .find(|frame| **frame >= frame_count)
+                {
+                    return Err(AnimationSetError::FrameOutOfBounds {
+                        clip: clip_name.clone(),
+                        frame,
+                        frame_count,
+                    });
+                }
+            }
+
+            let mut placed_markers = HashSet::new();
+
+            for (marker_name, frame_index) in &definition.markers {
+                if *frame_index >= definition.frames.len() {
+                    return Err(AnimationSetError::MarkerOutOfBounds {
+                        clip: clip_name.clone(),
+                        marker: marker_name.clone(),
+                        frame_index: *frame_index,
+                    });
+                }
+
+                if !placed_markers.insert((marker_name, frame_index)) {
+                    return Err(AnimationSetError::DuplicateMarker {
+                        clip: clip_name.clone(),
+                        marker: marker_name.clone(),
+                        frame_index: *frame_index,
+                    });
+                }
+            }
+        }
+
+        let mut animation_names: Vec<_> = self.animations.keys().collect();
+        animation_names.sort();
+
+        for animation_name in animation_names {
+            for clip_name in &self.animations[animation_name].clips {
+                if !self.clips.contains_key(clip_name) {
+                    return Err(AnimationSetError::UnknownClip {
+                        animation: animation_name.clone(),
+                        clip: clip_name.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Registers the clips and animations of the set in a library, or updates the ones with the same names.
+    ///
+    /// This is done automatically for the sets loaded with the asset server,
+    /// which also checks that the names are not used by other sets.
+    pub fn register(&self, library: &mut AnimationLibrary) {
+        self.register_names(library, None, "animation set");
+    }
+
+    /// Registers the clips and animations of the set and returns their names.
+    ///
+    /// If the names previously registered by the set are given, the names registered by others are not replaced
+    /// and the names that are not part of the set anymore are removed.
+    fn register_names(
+        &self,
+        library: &mut AnimationLibrary,
+        previous_names: Option<&RegisteredNames>,
+        source: &str,
+    ) -> RegisteredNames {
+        let mut names = RegisteredNames::default();
+
+        // Clips first, so that the animations can refer to them
+
+        let mut clip_ids = HashMap::new();
+
+        for (name, definition) in &self.clips {
+            let existing_clip_id = library.clip_with_name(name);
+
+            if existing_clip_id.is_some()
+                && previous_names.is_some_and(|previous_names| !previous_names.clips.contains(name))
+            {
+                diagnostics::warning(format_args!(
+                    "{source}: the clip name \"{name}\" is already in use, skipping it"
+                ));
+
+                continue;
+            }
+
+            let clip = definition.to_clip(library);
+
+            let clip_id = match existing_clip_id {
+                Some(clip_id) => {
+                    library.edit_clip(clip_id, |existing_clip| *existing_clip = clip);
+
+                    clip_id
+                }
+                None => {
+                    let clip_id = library.register_clip(clip);
+
+                    // Cannot fail as the name is not in use
+                    let _ = library.name_clip(clip_id, name);
+
+                    clip_id
+                }
+            };
+
+            clip_ids.insert(name.as_str(), clip_id);
+            names.clips.insert(name.clone());
+        }
+
+        for (name, definition) in &self.animations {
+            let existing_animation_id = library.animation_with_name(name);
+
+            if existing_animation_id.is_some()
+                && previous_names
+                    .is_some_and(|previous_names| !previous_names.animations.contains(name))
+            {
+                diagnostics::warning(format_args!(
+                    "{source}: the animation name \"{name}\" is already in use, skipping it"
+                ));
+
+                continue;
+            }
+
+            let Some(animation) = definition.to_animation(name, &clip_ids) else {
+                continue;
+            };
+
+            match existing_animation_id {
+                Some(animation_id) => library.edit_animation(animation_id, |existing_animation| {
+                    *existing_animation = animation
+                }),
+                None => {
+                    let animation_id = library.register_animation(animation);
+
+                    let _ = library.name_animation(animation_id, name);
+                }
+            }
+
+            names.animations.insert(name.clone());
+        }
+
+        // Remove the names of the clips and animations that are not part of the set anymore
+
+        if let Some(previous_names) = previous_names {
+            unregister_names(
+                library,
+                previous_names.clips.difference(&names.clips),
+                previous_names.animations.difference(&names.animations),
+            );
+        }
+
+        names
+    }
+}
+
+/// The names of the clips and animations registered by an [AnimationSet]
+#[derive(Debug, Default)]
+pub(crate) struct RegisteredNames {
+    clips: HashSet<String>,
+    animations: HashSet<String>,
+}
+
+impl RegisteredNames {
+    /// Removes the names from the library, leaving their clips and animations registered
+    fn unregister(&self, library: &mut AnimationLibrary) {
+        unregister_names(library, &self.clips, &self.animations);
+    }
+}
+
+fn unregister_names<'a>(
+    library: &mut AnimationLibrary,
+    clip_names: impl IntoIterator<Item = &'a String>,
+    animation_names: impl IntoIterator<Item = &'a String>,
+) {
+    for name in clip_names {
+        if let Some(clip_id) = library.clip_with_name(name) {
+            library.remove_clip_name(clip_id);
+        }
+    }
+
+    for name in animation_names {
+        if let Some(animation_id) = library.animation_with_name(name) {
+            library.remove_animation_name(animation_id);
+        }
+    }
+}
+
+impl ClipDefinition {
+    fn to_clip(&self, library: &mut AnimationLibrary) -> Clip {
+        let mut clip = Clip::from_frames(self.frames.iter().copied());
+
+        if let Some(duration) = self.duration {
+            clip.set_duration(duration);
+        }
+
+        if let Some(repetitions) = self.repetitions {
+            clip.set_repetitions(repetitions);
+        }
+
+        if let Some(direction) = self.direction {
+            clip.set_direction(direction);
+        }
+
+        if let Some(easing) = self.easing {
+            clip.set_easing(easing);
+        }
+
+        if let Some(flip_x) = self.flip_x {
+            clip.set_flip_x(flip_x);
+        }
+
+        if let Some(flip_y) = self.flip_y {
+            clip.set_flip_y(flip_y);
+        }
+
+        for (marker_name, frame_index) in &self.markers {
+            let marker_id = marker_with_name_or_new(library, marker_name);
+
+            clip = clip.with_marker(marker_id, *frame_index);
+        }
+
+        clip
+    }
+}
+
+impl AnimationDefinition {
+    fn to_animation(&self, name: &str, set_clip_ids: &HashMap<&str, ClipId>) -> Option<Animation> {
+        let mut clip_ids = Vec::with_capacity(self.clips.len());
+
+        for clip_name in &self.clips {
+            match set_clip_ids.get(clip_name.as_str()) {
+                Some(clip_id) => clip_ids.push(*clip_id),
+                None => {
+                    diagnostics::warning(format_args!("animation \"{name}\" refers to an unknown clip \"{clip_name}\", skipping it"));
+
+                    return None;
+                }
+            }
+        }
+
+        let mut animation = Animation::from_clips(clip_ids);
+
+        if let Some(duration) = self.duration {
+            animation.set_duration(duration);
+        }
+
+        if let Some(repetitions) = self.repetitions {
+            animation.set_repetitions(repetitions);
+        }
+
+        if let Some(direction) = self.direction {
+            animation.set_direction(direction);
+        }
+
+        if let Some(easing) = self.easing {
+            animation.set_easing(easing);
+        }
+
+        Some(animation)
+    }
+}
+
+fn marker_with_name_or_new(library: &mut AnimationLibrary, name: &str) -> AnimationMarkerId {
+    library.marker_with_name(name).unwrap_or_else(|| {
+        let marker_id = library.new_marker();
+
+        // Cannot fail as the name is not in use
+        let _ = library.name_marker(marker_id, name);
+
+        marker_id
+    })
+}
+
+/// Registers the animation sets in the library when they are loaded or modified.
+pub(crate) fn register_animation_sets(
+    mut events: EventReader<AssetEvent<AnimationSet>>,
+    animation_sets: Res<Assets<AnimationSet>>,
+    asset_server: Res<AssetServer>,
+    mut library: ResMut<AnimationLibrary>,
+    mut registered_names: Local<HashMap<AssetId<AnimationSet>, RegisteredNames>>,
+) {
+    for event in events.read() {
+        match event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => {
+                if let Some(animation_set) = animation_sets.get(*id) {
+                    let source = asset_server
+                        .get_path(*id)
+                        .map(|path| path.to_string())
+                        .unwrap_or_else(|| format!("animation set {id:?}"));
+
+                    let previous_names = registered_names.remove(id).unwrap_or_default();
+
+                    let names =
+                        animation_set.register_names(&mut library, Some(&previous_names), &source);
+
+                    registered_names.insert(*id, names);
+                }
+            }
+            // Release the names of the dropped sets so that they can be registered again when the sets are reloaded
+            AssetEvent::Removed { id } | AssetEvent::Unused { id } => {
+                if let Some(names) = registered_names.remove(id) {
+                    names.unregister(&mut library);
+                }
+            }
+            AssetEvent::LoadedWithDependencies { .. } => {}
+        }
+    }
+}
@@ -1,31 +1,52 @@
 pub mod cache;
 mod iterator;
+pub mod sampling;
+pub mod timeline;
 
 use crate::{
     animation::AnimationId,
     animator::iterator::{AnimationIterator, IteratorFrame},
+    clip::ClipImage,
     components::{
+        animation_overrides::AnimationOverrides,
+        clip_target::ClipTarget,
+        flip_transition::{FlipTransition, FlipTransitionStyle},
+        frame_trail::{FrameTrail, FrameTrailSample},
+        hit_pause::{HitPauseGroup, HitPauseOnMarker, HitPauseScope},
+        no_animation_culling::NoAnimationCulling,
+        persistent_animation_key::PersistentAnimationKey,
+        playback_history::{PlaybackHistory, PlaybackRecord},
         sprite3d::Sprite3d,
-        spritesheet_animation::{AnimationProgress, SpritesheetAnimation},
+        spritesheet_animation::{AnimationProgress, FrameRemap, SpritesheetAnimation},
     },
-    events::AnimationEvent,
+    diagnostics,
+    events::{AnimationEvent, AnimationEventSettings, AnimationMarkerId},
+    hooks::{FrameContext, FrameInfo},
     library::AnimationLibrary,
+    plugin::{AnimationCulling, EmptyAnimationBehavior, FocusLossBehavior},
 };
 use bevy::{
     ecs::{
         entity::Entity,
         event::EventWriter,
-        query::QueryData,
+        query::{Has, QueryData, Without},
         reflect::*,
         system::{Query, Resource},
     },
+    hierarchy::Children,
+    math::Vec3,
     reflect::prelude::*,
-    sprite::Sprite,
+    render::view::{ViewVisibility, Visibility},
+    sprite::{Sprite, TextureAtlas},
     time::Time,
+    transform::components::{GlobalTransform, Transform},
     ui::widget::ImageNode,
 };
 use iterator::AnimationIteratorEvent;
-use std::{collections::HashMap, time::Duration};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    time::Duration,
+};
 
 #[derive(Debug, Reflect)]
 #[reflect(Debug)]
@@ -39,6 +60,102 @@ struct AnimationInstance {
 
     /// Time accumulated since the last frame
     accumulated_time: Duration,
+
+    /// The maximum rate at which the animation updates
+    max_fps: Option<u32>,
+
+    /// Time not applied to the playback yet because of the max rate
+    unreleased_time: Duration,
+
+    /// Whether the playback has started.
+    ///
+    /// The first frame is assigned as soon as the instance is created but the playback
+    /// only starts when the animation is not paused and its delay has elapsed.
+    started: bool,
+
+    /// Whether the entity has been hidden because the animation has no frames
+    hid_entity: bool,
+
+    /// The remapping applied to the current frame
+    frame_remap: FrameRemap,
+
+    /// The key under which the progress is preserved when the instance is dropped
+    persistent_key: Option<String>,
+
+    /// The progress of the last frame if the animation ended, to detect seeks after the end
+    ended_progress: Option<AnimationProgress>,
+
+    /// The time left before the playback resumes after a hit-pause
+    hit_pause_remaining: Duration,
+
+    /// The time left before the sprite is flipped by a [FlipTransition]
+    turn_remaining: Duration,
+
+    /// The overrides applied to the animation
+    overrides: Option<AnimationOverrides>,
+
+    /// The speed factor of the animation during the last update
+    speed_factor: f32,
+
+    /// Time not applied to the playback yet because the entity is culled
+    culled_time: Duration,
+
+    /// Whether the playback should restart from scratch during the next update, see [Animator::restart]
+    restart_requested: bool,
+}
+
+impl AnimationInstance {
+    /// Returns the part of some elapsed time to apply to the playback.
+    ///
+    /// With a max rate, the time is only released in whole steps and the rest is kept for the next updates.
+    fn release_time(&mut self, elapsed: Duration) -> Duration {
+        let Some(max_fps) = self.max_fps.filter(|max_fps| *max_fps > 0) else {
+            return elapsed;
+        };
+
+        let step = Duration::from_secs(1) / max_fps;
+
+        self.unreleased_time += elapsed;
+
+        let steps = (self.unreleased_time.as_nanos() / step.as_nanos()) as u32;
+        let released = step * steps;
+
+        self.unreleased_time -= released;
+
+        released
+    }
+}
+
+/// The progress of a dropped animation instance, preserved with a [PersistentAnimationKey]
+/// or while a [FlipTransitionStyle::TurnAnimation] interrupts it
+#[derive(Debug, Reflect)]
+#[reflect(Debug)]
+struct PersistedProgress {
+    animation_id: AnimationId,
+    progress: AnimationProgress,
+    accumulated_time: Duration,
+}
+
+/// A Bevy resource to globally drop the skippable frames of the animations.
+///
+/// This is intended for low-spec or battery-saver modes: when enabled, the frames marked with
+/// [Clip::with_skippable_frames](crate::prelude::Clip::with_skippable_frames) are not displayed
+/// and the previous frames are held for their duration instead, so animations keep the same overall duration.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn enable_battery_saver(mut frame_skipping: ResMut<FrameSkipping>) {
+///     frame_skipping.enabled = true;
+/// }
+/// ```
+#[derive(Resource, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Resource, Debug, Default)]
+pub struct FrameSkipping {
+    /// Whether skippable frames should be dropped
+    pub enabled: bool,
 }
 
 /// The animator is responsible for playing animations as time advances.
@@ -48,6 +165,56 @@ pub struct Animator {
     /// Instances of animations currently being played.
     /// Each animation instance is associated to an entity with a [SpritesheetAnimation] component.
     animation_instances: HashMap<Entity, AnimationInstance>,
+
+    /// What to do with entities that play an animation without any frame
+    empty_animation_behavior: EmptyAnimationBehavior,
+
+    /// Progress of the dropped instances with a [PersistentAnimationKey]
+    persisted_progress: HashMap<String, PersistedProgress>,
+
+    /// Whether frame transitions should be collected for the frame hooks
+    collect_frame_transitions: bool,
+
+    /// The frame transitions collected during the last update
+    #[reflect(ignore)]
+    frame_transitions: Vec<FrameContext>,
+
+    /// Whether to collect the entities whose atlas index changed for the [FrameJustChanged](crate::components::frame_just_changed::FrameJustChanged) markers
+    collect_frame_changes: bool,
+
+    /// The atlas index displayed by each entity after the previous update
+    #[reflect(ignore)]
+    displayed_atlas_indices: HashMap<Entity, usize>,
+
+    /// The entities whose atlas index changed during the last update
+    #[reflect(ignore)]
+    frame_changes: Vec<Entity>,
+
+    /// The positions of the marker listeners, for the marker distance of the event settings
+    #[reflect(ignore)]
+    marker_listeners: Vec<Vec3>,
+
+    /// The time already spent on the starting frame of the entities that switch animation in sync with others
+    synchronized_starts: HashMap<Entity, Duration>,
+
+    /// Instances transferred from other entities, to be picked up by their new entities during the next update
+    transferred_instances: HashMap<Entity, AnimationInstance>,
+
+    /// Progress of the animations interrupted by a turn animation, resumed once it is over
+    interrupted_progress: HashMap<Entity, PersistedProgress>,
+
+    /// Which animations are updated less often because they are not on screen
+    culling: AnimationCulling,
+
+    /// The positions of the cameras, for the distance culling
+    #[reflect(ignore)]
+    cameras: Vec<Vec3>,
+
+    /// Which animations are paused while the window is not focused
+    focus_loss_behavior: FocusLossBehavior,
+
+    /// Whether the window lost the focus
+    window_unfocused: bool,
 }
 
 /// A query data type for the [`Animator::update`] system.
@@ -59,25 +226,393 @@ pub struct SpritesheetAnimationQuery {
     sprite: Option<&'static mut Sprite>,
     sprite3d: Option<&'static mut Sprite3d>,
     image_node: Option<&'static mut ImageNode>,
+    visibility: Option<&'static mut Visibility>,
+    children: Option<&'static Children>,
+    persistent_key: Option<&'static PersistentAnimationKey>,
+    history: Option<&'static mut PlaybackHistory>,
+    trail: Option<&'static mut FrameTrail>,
+    transform: Option<&'static Transform>,
+    global_transform: Option<&'static GlobalTransform>,
+    hit_pause: Option<&'static HitPauseOnMarker>,
+    hit_pause_group: Option<&'static HitPauseGroup>,
+    flip_transition: Option<&'static FlipTransition>,
+    overrides: Option<&'static AnimationOverrides>,
+    view_visibility: Option<&'static ViewVisibility>,
+    no_culling: Has<NoAnimationCulling>,
+}
+
+/// A query data type for the child entities animated by clips with a [ClipTarget].
+#[derive(QueryData)]
+#[query_data(mutable, derive(Debug))]
+pub struct ClipTargetQuery {
+    target: &'static ClipTarget,
+    sprite: Option<&'static mut Sprite>,
+    sprite3d: Option<&'static mut Sprite3d>,
+    image_node: Option<&'static mut ImageNode>,
 }
 
 impl Animator {
+    pub(crate) fn new(
+        empty_animation_behavior: EmptyAnimationBehavior,
+        collect_frame_changes: bool,
+        culling: AnimationCulling,
+        focus_loss_behavior: FocusLossBehavior,
+    ) -> Self {
+        Self {
+            animation_instances: HashMap::new(),
+            empty_animation_behavior,
+            persisted_progress: HashMap::new(),
+            collect_frame_transitions: false,
+            frame_transitions: Vec::new(),
+            collect_frame_changes,
+            displayed_atlas_indices: HashMap::new(),
+            frame_changes: Vec::new(),
+            marker_listeners: Vec::new(),
+            synchronized_starts: HashMap::new(),
+            transferred_instances: HashMap::new(),
+            interrupted_progress: HashMap::new(),
+            culling,
+            cameras: Vec::new(),
+            focus_loss_behavior,
+            window_unfocused: false,
+        }
+    }
+
+    /// Enables or disables the collection of frame transitions for the frame hooks
+    pub(crate) fn set_collect_frame_transitions(&mut self, enabled: bool) {
+        self.collect_frame_transitions = enabled;
+    }
+
+    /// Extracts the frame transitions collected during the last update
+    pub(crate) fn take_frame_transitions(&mut self) -> Vec<FrameContext> {
+        std::mem::take(&mut self.frame_transitions)
+    }
+
+    /// Returns which animations are culled
+    pub(crate) fn culling(&self) -> &AnimationCulling {
+        &self.culling
+    }
+
+    /// Updates whether the window is focused, for the [FocusLossBehavior]
+    pub(crate) fn set_window_focused(&mut self, focused: bool) {
+        self.window_unfocused = !focused;
+    }
+
+    /// Updates the positions of the cameras for the distance culling
+    pub(crate) fn set_cameras(&mut self, positions: impl IntoIterator<Item = Vec3>) {
+        self.cameras.clear();
+        self.cameras.extend(positions);
+    }
+
+    /// Sets the positions of the marker listeners for the next update
+    pub(crate) fn set_marker_listeners(&mut self, positions: impl IntoIterator<Item = Vec3>) {
+        self.marker_listeners.clear();
+        self.marker_listeners.extend(positions);
+    }
+
+    /// Extracts the entities whose atlas index changed during the last update
+    pub(crate) fn take_frame_changes(&mut self) -> Vec<Entity> {
+        std::mem::take(&mut self.frame_changes)
+    }
+
+    /// Returns the progress of the animation played by an entity and the time already spent on its current frame.
+    ///
+    /// Returns None if the entity is not playing an animation.
+    pub(crate) fn playback(&self, entity: Entity) -> Option<(AnimationProgress, Duration)> {
+        let instance = self.animation_instances.get(&entity)?;
+
+        let (_, progress) = instance.current_frame.as_ref()?;
+
+        Some((*progress, instance.accumulated_time))
+    }
+
+    /// Makes the next animation of an entity start with some time already spent on its starting frame,
+    /// to keep it in sync with another entity (see [Animator::playback])
+    pub(crate) fn synchronize_start(&mut self, entity: Entity, accumulated_time: Duration) {
+        self.synchronized_starts.insert(entity, accumulated_time);
+    }
+
+    /// Moves the animation played by an entity to another entity, which continues the playback seamlessly.
+    ///
+    /// This is useful when an entity is replaced by another one, for instance when a character is swapped for its ragdoll.
+    ///
+    /// The destination entity must have a [SpritesheetAnimation] component when the animations are next updated.
+    /// Its animation and progress are then overwritten with the ones of the transferred animation.
+    ///
+    /// The source entity is usually despawned afterwards.
+    /// If it keeps its [SpritesheetAnimation] component, it restarts its animation from its current progress.
+    ///
+    /// Returns false if the source entity is not playing an animation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// #[derive(Component)]
+    /// struct Ragdoll;
+    ///
+    /// fn swap_for_ragdoll(
+    ///     mut commands: Commands,
+    ///     mut animator: ResMut<Animator>,
+    ///     characters: Query<(Entity, &Sprite, &SpritesheetAnimation), Added<Ragdoll>>,
+    /// ) {
+    ///     for (entity, sprite, animation) in &characters {
+    ///         let ragdoll = commands
+    ///             .spawn((sprite.clone(), SpritesheetAnimation::from_id(animation.animation_id)))
+    ///             .id();
+    ///
+    ///         animator.transfer(entity, ragdoll);
+    ///
+    ///         commands.entity(entity).despawn();
+    ///     }
+    /// }
+    /// ```
+    pub fn transfer(&mut self, source: Entity, destination: Entity) -> bool {
+        let Some(instance) = self.animation_instances.remove(&source) else {
+            return false;
+        };
+
+        self.transferred_instances.insert(destination, instance);
+
+        true
+    }
+
+    /// Restarts the animation played by an entity from scratch, reusing its animation instance.
+    ///
+    /// This is intended for pooled entities, like projectiles that are hidden and shown again instead of being despawned and respawned:
+    /// the animation plays again from its first frame and emits its [AnimationStarted](crate::prelude::AnimationEvent::AnimationStarted) event again,
+    /// without the cost of tearing down and creating a new instance.
+    ///
+    /// The restart takes effect during the next update. The [delay](SpritesheetAnimation::delay) of the animation is not applied again.
+    ///
+    /// Returns false if the entity is not playing an animation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// #[derive(Component)]
+    /// struct Bullet;
+    ///
+    /// fn fire_from_pool(
+    ///     mut animator: ResMut<Animator>,
+    ///     mut bullets: Query<(Entity, &mut Visibility), With<Bullet>>,
+    /// ) {
+    ///     if let Some((entity, mut visibility)) = bullets
+    ///         .iter_mut()
+    ///         .find(|(_, visibility)| **visibility == Visibility::Hidden)
+    ///     {
+    ///         *visibility = Visibility::Inherited;
+    ///
+    ///         animator.restart(entity);
+    ///     }
+    /// }
+    /// ```
+    pub fn restart(&mut self, entity: Entity) -> bool {
+        let Some(instance) = self.animation_instances.get_mut(&entity) else {
+            return false;
+        };
+
+        instance.restart_requested = true;
+
+        true
+    }
+
+    /// Returns the (remapped) atlas index currently displayed by an entity.
+    ///
+    /// Returns None if the entity is not animated or if its current frame targets a child entity.
+    pub(crate) fn atlas_index(&self, entity: Entity) -> Option<usize> {
+        let instance = self.animation_instances.get(&entity)?;
+
+        let (frame, _) = instance.current_frame.as_ref()?;
+
+        frame
+            .target
+            .is_none()
+            .then(|| instance.frame_remap.apply(frame.atlas_index))
+    }
+
+    /// Predicts the next marker that the animation of an entity will hit within some time, along with the time left until it is hit.
+    ///
+    /// The prediction follows the current progress, the speed factor and the hit-pauses of the animation,
+    /// so that systems can act slightly ahead of the playback: audio systems can schedule sounds to compensate for their latency
+    /// and AI can anticipate the active frames of attacks for instance.
+    ///
+    /// The prediction assumes that the animation keeps playing with the same parameters.
+    /// Returns None if the entity is not playing an animation or if no marker is hit within the given time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Footstep(AnimationMarkerId);
+    ///
+    /// fn schedule_footsteps(
+    ///     animator: Res<Animator>,
+    ///     characters: Query<Entity, With<SpritesheetAnimation>>,
+    ///     footstep: Res<Footstep>,
+    /// ) {
+    ///     // Compensate for the latency of the audio backend
+    ///
+    ///     let latency = Duration::from_millis(40);
+    ///
+    ///     for entity in &characters {
+    ///         if let Some((marker, time_left)) = animator.next_marker_within(entity, latency) {
+    ///             if marker == footstep.0 {
+    ///                 // Play the sound in `time_left`...
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn next_marker_within(
+        &self,
+        entity: Entity,
+        within: Duration,
+    ) -> Option<(AnimationMarkerId, Duration)> {
+        let instance = self.animation_instances.get(&entity)?;
+
+        if instance.speed_factor <= 0.0 {
+            return None;
+        }
+
+        let (current_frame, _) = instance.current_frame.as_ref()?;
+
+        // Convert the animation time to real time
+
+        let real_time = |animation_time: Duration| {
+            instance.hit_pause_remaining + animation_time.div_f32(instance.speed_factor)
+        };
+
+        // Walk the next frames with a copy of the iterator
+
+        let mut time_left = current_frame
+            .duration
+            .saturating_sub(instance.accumulated_time);
+
+        for (frame, _) in instance.iterator.clone() {
+            if real_time(time_left) > within {
+                return None;
+            }
+
+            let marker = frame.events.iter().find_map(|event| match event {
+                AnimationIteratorEvent::MarkerHit { marker_id, .. } => Some(*marker_id),
+                _ => None,
+            });
+
+            if let Some(marker) = marker {
+                return Some((marker, real_time(time_left)));
+            }
+
+            time_left += frame.duration;
+        }
+
+        None
+    }
+
     /// Plays the animations
+    #[allow(clippy::too_many_arguments)]
     pub fn update(
         &mut self,
         time: &Time,
-        library: &AnimationLibrary,
+        library: &mut AnimationLibrary,
+        event_settings: &AnimationEventSettings,
+        frame_skipping: &FrameSkipping,
         event_writer: &mut EventWriter<AnimationEvent>,
         query: &mut Query<SpritesheetAnimationQuery>,
+        target_query: &mut Query<ClipTargetQuery, Without<SpritesheetAnimation>>,
     ) {
         // Clear outdated animation instances associated to entities that do not have the component anymore
+        // (preserving the progress of the persistent ones)
+
+        let persisted_progress = &mut self.persisted_progress;
+
+        self.animation_instances.retain(|entity, instance| {
+            let keep = query.contains(*entity);
+
+            if !keep {
+                if let (Some(key), Some((_, progress))) =
+                    (&instance.persistent_key, &instance.current_frame)
+                {
+                    persisted_progress.insert(
+                        key.clone(),
+                        PersistedProgress {
+                            animation_id: instance.animation_id,
+                            progress: *progress,
+                            accumulated_time: instance.accumulated_time,
+                        },
+                    );
+                }
+            }
 
-        self.animation_instances
-            .retain(|entity, _state| query.contains(*entity));
+            keep
+        });
+
+        // Drop the transferred instances whose destination entity cannot play them
+
+        self.transferred_instances
+            .retain(|entity, _| query.contains(*entity));
+
+        self.interrupted_progress
+            .retain(|entity, _| query.contains(*entity));
+
+        // Retrieve the animations edited in the library since the last update
+
+        let edited_animations = library.take_edited_animations();
+
+        // The members of the hit-pause groups and the group pauses triggered during this update
+
+        let mut hit_pause_group_members = Vec::new();
+        let mut group_hit_pauses = Vec::new();
 
         // Run animations for all the entities
 
         for mut item in query.iter_mut() {
+            if let Some(group) = item.hit_pause_group {
+                hit_pause_group_members.push((item.entity, group.0));
+            }
+
+            // Check if the entity emits events, which can be disabled per entity or per kind of target
+
+            let emit_events = item.spritesheet_animation.emit_events
+                && (item.sprite.is_none() || event_settings.sprite)
+                && (item.sprite3d.is_none() || event_settings.sprite3d)
+                && (item.image_node.is_none() || event_settings.image_node);
+
+            // Check if the entity is close enough to a listener to emit marker events
+
+            let emit_markers = match (event_settings.marker_max_distance, item.global_transform) {
+                (Some(max_distance), Some(transform)) if !self.marker_listeners.is_empty() => {
+                    let position = transform.translation();
+
+                    self.marker_listeners.iter().any(|listener| {
+                        listener.distance_squared(position) <= max_distance * max_distance
+                    })
+                }
+                _ => true,
+            };
+
+            // Pick up the animation instance transferred from another entity, if any
+
+            if let Some(instance) = self.transferred_instances.remove(&item.entity) {
+                item.spritesheet_animation.animation_id = instance.animation_id;
+
+                if let Some((frame, progress)) = &instance.current_frame {
+                    item.spritesheet_animation.progress = *progress;
+
+                    Self::display_frame(&mut item, target_query, frame);
+                } else if let Some(progress) = instance.ended_progress {
+                    item.spritesheet_animation.progress = progress;
+                }
+
+                self.animation_instances.insert(item.entity, instance);
+            }
+
             // Create a new animation instance if:
             let needs_new_animation_instance = match self.animation_instances.get(&item.entity) {
                 // The entity has an animation instance already but it switched animation
@@ -87,24 +622,145 @@ impl Animator {
             };
 
             if needs_new_animation_instance {
+                // Face the requested direction right away on newly animated entities
+
+                if !self.animation_instances.contains_key(&item.entity) {
+                    if let Some(flip_transition) = item.flip_transition {
+                        Self::assign_flip_x(&mut item, flip_transition.flip_x);
+                    }
+
+                    // Pick the speed of the entity in its jitter range
+
+                    if item.spritesheet_animation.speed_jitter.is_some() {
+                        item.spritesheet_animation
+                            .apply_speed_jitter(item.entity.to_bits());
+                    }
+                }
+
                 // Create a new iterator for this animation
 
-                let cache = library.get_animation_cache(item.spritesheet_animation.animation_id);
+                let cache = library.get_animation_cache_with_overrides(
+                    item.spritesheet_animation.animation_id,
+                    item.overrides,
+                );
 
                 let mut iterator = AnimationIterator::new(cache.clone());
 
+                iterator.set_frame_skipping(frame_skipping.enabled);
+                iterator.set_clip_start_events(event_settings.clip_starts);
+                iterator.set_disabled_clips(&item.spritesheet_animation.disabled_clips);
+
+                // Seed the duration variations per entity
+
+                iterator.set_jitter_seed(item.entity.to_bits());
+
+                // Resume the preserved progress of a persistent animation,
+                // unless a starting progress is specified
+
+                let persisted = item
+                    .persistent_key
+                    .filter(|_| item.spritesheet_animation.progress == AnimationProgress::default())
+                    .and_then(|key| self.persisted_progress.remove(&key.0))
+                    .filter(|persisted| {
+                        persisted.animation_id == item.spritesheet_animation.animation_id
+                    });
+
+                // Same for an animation that was interrupted by a turn animation
+                // (kept while the turn animation itself is played)
+
+                let persisted =
+                    persisted.or_else(|| match self.interrupted_progress.entry(item.entity) {
+                        Entry::Occupied(interrupted)
+                            if interrupted.get().animation_id
+                                == item.spritesheet_animation.animation_id
+                                && item.spritesheet_animation.progress
+                                    == AnimationProgress::default() =>
+                        {
+                            Some(interrupted.remove())
+                        }
+                        _ => None,
+                    });
+
+                if let Some(persisted) = &persisted {
+                    item.spritesheet_animation.progress = persisted.progress;
+                }
+
+                // Pick up the time spent on the frame by the entity this one is synchronized with, if any
+
+                let mut synchronized_start = self.synchronized_starts.remove(&item.entity);
+
                 // Move to the starting progress if specified
 
                 if item.spritesheet_animation.progress != AnimationProgress::default() {
                     // Start from the beginning if the progress is invalid
-                    if !iterator.to(item.spritesheet_animation.progress) {
+                    if let Err(error) = iterator.to(item.spritesheet_animation.progress) {
+                        diagnostics::warning(format_args!("{error}, cannot update progress"));
+
                         item.spritesheet_animation.progress = AnimationProgress::default();
+                        synchronized_start = None;
                     }
                 }
 
                 // Create the instance and immediately play the first frame
+                //
+                // (the events of the first frame are held back until the playback starts)
+
+                let first_frame = Self::play_frame(
+                    &mut iterator,
+                    &mut item,
+                    target_query,
+                    event_writer,
+                    false,
+                    emit_markers,
+                    Duration::ZERO,
+                    time.elapsed(),
+                );
+
+                // Handle animations without any frame
+
+                let previous_instance_hid_entity = self
+                    .animation_instances
+                    .get(&item.entity)
+                    .is_some_and(|instance| instance.hid_entity);
+
+                let mut hid_entity = false;
 
-                let first_frame = Self::play_frame(&mut iterator, &mut item, event_writer);
+                if first_frame.is_none() {
+                    if emit_events {
+                        event_writer.send(AnimationEvent::EmptyAnimation {
+                            entity: item.entity,
+                            animation_id: item.spritesheet_animation.animation_id,
+                        });
+                    }
+
+                    if self.empty_animation_behavior == EmptyAnimationBehavior::Hide {
+                        if let Some(visibility) = item.visibility.as_deref_mut() {
+                            *visibility = Visibility::Hidden;
+                            hid_entity = true;
+                        }
+                    }
+                } else if previous_instance_hid_entity {
+                    // Show the entity again if it was hidden by a previous empty animation
+
+                    if let Some(visibility) = item.visibility.as_deref_mut() {
+                        *visibility = Visibility::Inherited;
+                    }
+                }
+
+                if self.collect_frame_transitions {
+                    if let Some(frame) = &first_frame {
+                        let previous_frame = self
+                            .animation_instances
+                            .get(&item.entity)
+                            .and_then(|instance| instance.current_frame.as_ref());
+
+                        self.frame_transitions.push(Self::frame_context(
+                            &item,
+                            previous_frame,
+                            frame,
+                        ));
+                    }
+                }
 
                 self.animation_instances.insert(
                     item.entity,
@@ -112,40 +768,187 @@ impl Animator {
                         animation_id: item.spritesheet_animation.animation_id,
                         iterator,
                         current_frame: first_frame,
-                        accumulated_time: Duration::ZERO,
+                        // A resumed or synchronized animation continues its playback
+                        accumulated_time: persisted
+                            .as_ref()
+                            .map(|persisted| persisted.accumulated_time)
+                            .or(synchronized_start)
+                            .unwrap_or_default(),
+                        max_fps: *library
+                            .get_animation(item.spritesheet_animation.animation_id)
+                            .max_fps(),
+                        unreleased_time: Duration::ZERO,
+                        started: persisted.is_some(),
+                        hid_entity,
+                        frame_remap: item.spritesheet_animation.frame_remap.clone(),
+                        persistent_key: item.persistent_key.map(|key| key.0.clone()),
+                        ended_progress: None,
+                        hit_pause_remaining: Duration::ZERO,
+                        turn_remaining: Duration::ZERO,
+                        overrides: item.overrides.copied(),
+                        speed_factor: item.spritesheet_animation.speed_factor,
+                        culled_time: Duration::ZERO,
+                        restart_requested: false,
                     },
                 );
             }
 
             let animation_instance = self.animation_instances.get_mut(&item.entity).unwrap();
 
+            // Restart the playback in place if requested
+            //
+            // (the events of the first frame are held back until the playback starts, as for a new instance)
+
+            if std::mem::take(&mut animation_instance.restart_requested)
+                && animation_instance
+                    .iterator
+                    .to(AnimationProgress::default())
+                    .is_ok()
+            {
+                animation_instance.current_frame = Self::play_frame(
+                    &mut animation_instance.iterator,
+                    &mut item,
+                    target_query,
+                    event_writer,
+                    false,
+                    emit_markers,
+                    Duration::ZERO,
+                    time.elapsed(),
+                );
+
+                animation_instance.accumulated_time = Duration::ZERO;
+                animation_instance.unreleased_time = Duration::ZERO;
+                animation_instance.started = false;
+                animation_instance.ended_progress = None;
+                animation_instance.hit_pause_remaining = Duration::ZERO;
+                animation_instance.turn_remaining = Duration::ZERO;
+                animation_instance.culled_time = Duration::ZERO;
+            }
+
+            // Follow the global frame skipping setting, which may change at any time
+
+            animation_instance
+                .iterator
+                .set_frame_skipping(frame_skipping.enabled);
+
+            animation_instance
+                .iterator
+                .set_clip_start_events(event_settings.clip_starts);
+
+            // Follow the clips enabled for this entity, which may change at any time
+
+            animation_instance
+                .iterator
+                .set_disabled_clips(&item.spritesheet_animation.disabled_clips);
+
+            // Follow the edits of the animation and of its overrides (restarting it if its current frame does not exist anymore)
+
+            let overrides = item.overrides.copied();
+
+            if edited_animations.contains(&animation_instance.animation_id)
+                || animation_instance.overrides != overrides
+            {
+                animation_instance.overrides = overrides;
+
+                let mut iterator =
+                    AnimationIterator::new(library.get_animation_cache_with_overrides(
+                        animation_instance.animation_id,
+                        overrides.as_ref(),
+                    ));
+
+                iterator.set_frame_skipping(frame_skipping.enabled);
+                iterator.set_clip_start_events(event_settings.clip_starts);
+                iterator.set_disabled_clips(&item.spritesheet_animation.disabled_clips);
+                iterator.set_jitter_seed(item.entity.to_bits());
+
+                if let Some((_, progress)) = &animation_instance.current_frame {
+                    // Produce the current frame again so that the iterator points to the next one
+                    // (its events have already been emitted)
+
+                    if iterator.to(*progress).is_ok() {
+                        if let Some(frame) = iterator.next() {
+                            animation_instance.current_frame = Some(frame);
+                        }
+                    }
+                }
+
+                animation_instance.iterator = iterator;
+
+                animation_instance.max_fps = *library
+                    .get_animation(animation_instance.animation_id)
+                    .max_fps();
+            }
+
+            // Apply remapping updates to the current frame immediately
+
+            if animation_instance.frame_remap != item.spritesheet_animation.frame_remap {
+                animation_instance.frame_remap = item.spritesheet_animation.frame_remap.clone();
+
+                if let Some((frame, _)) = &animation_instance.current_frame {
+                    Self::display_frame(&mut item, target_query, frame);
+                }
+            }
+
             // Apply manual progress updates
+            // (including after the end of the animation, to replay it)
 
-            if animation_instance
+            let last_progress = animation_instance
                 .current_frame
                 .as_ref()
-                .filter(|frame| item.spritesheet_animation.progress != frame.1)
-                .is_some()
+                .map(|(_, progress)| *progress)
+                .or(animation_instance.ended_progress);
+
+            if last_progress.is_some_and(|progress| item.spritesheet_animation.progress != progress)
             {
+                let current_label = animation_instance
+                    .current_frame
+                    .as_ref()
+                    .and_then(|(frame, _)| frame.label);
+
                 if animation_instance
                     .iterator
                     .to(item.spritesheet_animation.progress)
+                    .inspect_err(|error| match current_label {
+                        Some(label) => diagnostics::warning(format_args!(
+                            "{error}, cannot update progress from frame \"{label}\""
+                        )),
+                        None => {
+                            diagnostics::warning(format_args!("{error}, cannot update progress"))
+                        }
+                    })
+                    .is_ok()
                 {
-                    Self::play_frame(&mut animation_instance.iterator, &mut item, event_writer)
-                        .inspect(|new_frame| {
-                            animation_instance.current_frame = Some(new_frame.clone());
-                            animation_instance.accumulated_time = Duration::ZERO;
-                        });
+                    Self::play_frame(
+                        &mut animation_instance.iterator,
+                        &mut item,
+                        target_query,
+                        event_writer,
+                        animation_instance.started && emit_events,
+                        emit_markers,
+                        Duration::ZERO,
+                        time.elapsed(),
+                    )
+                    .inspect(|new_frame| {
+                        if self.collect_frame_transitions {
+                            self.frame_transitions.push(Self::frame_context(
+                                &item,
+                                animation_instance.current_frame.as_ref(),
+                                new_frame,
+                            ));
+                        }
+
+                        animation_instance.current_frame = Some(new_frame.clone());
+                        animation_instance.accumulated_time = Duration::ZERO;
+                        animation_instance.ended_progress = None;
+                    });
                 } else {
                     // Restore to the last valid progress if invalid
-                    item.spritesheet_animation.progress = animation_instance
-                        .current_frame
-                        .as_ref()
-                        .map(|(_, progress)| *progress)
-                        .unwrap_or_default()
+                    item.spritesheet_animation.progress = last_progress.unwrap_or_default();
                 }
             }
 
+            animation_instance.speed_factor = item.spritesheet_animation.speed_factor;
+
             // Skip the update if the animation is paused
             //
             // (skipped AFTER the setup above so that the first frame is assigned, even if paused)
@@ -154,11 +957,175 @@ impl Animator {
                 continue;
             }
 
+            // Freeze the animation while the window is not focused
+
+            if self.window_unfocused && self.focus_loss_behavior.pauses(item.image_node.is_some()) {
+                continue;
+            }
+
+            let mut delta = time.delta();
+
+            // Hold back the time of the culled entities to apply it in larger steps,
+            // or all at once when they are not culled anymore
+
+            if Self::is_culled(&self.culling, &self.cameras, &item) {
+                animation_instance.culled_time += delta;
+
+                if animation_instance.culled_time < self.culling.culled_update_interval {
+                    continue;
+                }
+
+                delta = std::mem::take(&mut animation_instance.culled_time);
+            } else {
+                delta += std::mem::take(&mut animation_instance.culled_time);
+            }
+
+            // Hold the current frame during a hit-pause
+
+            if !animation_instance.hit_pause_remaining.is_zero() {
+                let paused = delta.min(animation_instance.hit_pause_remaining);
+
+                animation_instance.hit_pause_remaining -= paused;
+                delta -= paused;
+
+                if !animation_instance.hit_pause_remaining.is_zero() {
+                    continue;
+                }
+            }
+
+            // Turn around when the direction requested with a FlipTransition changes
+
+            if let Some(flip_transition) = item.flip_transition.copied() {
+                let turning = match flip_transition.style {
+                    FlipTransitionStyle::Hold(_) => !animation_instance.turn_remaining.is_zero(),
+                    FlipTransitionStyle::TurnAnimation(turn_animation_id) => {
+                        animation_instance.animation_id == turn_animation_id
+                    }
+                };
+
+                if !turning && Self::flip_x(&item) != Some(flip_transition.flip_x) {
+                    match flip_transition.style {
+                        FlipTransitionStyle::Hold(duration) => {
+                            animation_instance.turn_remaining = duration;
+                        }
+                        FlipTransitionStyle::TurnAnimation(turn_animation_id) => {
+                            // Resume the interrupted animation after the turn animation,
+                            // from the frame where it was interrupted
+
+                            let animation_id = item.spritesheet_animation.animation_id;
+
+                            self.interrupted_progress.insert(
+                                item.entity,
+                                PersistedProgress {
+                                    animation_id,
+                                    progress: item.spritesheet_animation.progress,
+                                    accumulated_time: animation_instance.accumulated_time,
+                                },
+                            );
+
+                            item.spritesheet_animation
+                                .queued_animations
+                                .push_front(animation_id);
+
+                            item.spritesheet_animation.switch(turn_animation_id);
+
+                            continue;
+                        }
+                    }
+                }
+
+                // Hold the current frame, then flip the sprite
+
+                if matches!(flip_transition.style, FlipTransitionStyle::Hold(_)) {
+                    let held = delta.min(animation_instance.turn_remaining);
+
+                    animation_instance.turn_remaining -= held;
+                    delta -= held;
+
+                    if !animation_instance.turn_remaining.is_zero() {
+                        continue;
+                    }
+
+                    Self::assign_flip_x(&mut item, flip_transition.flip_x);
+                }
+            }
+
+            // Start the playback once the delay has elapsed
+
+            let just_started = !animation_instance.started;
+
+            if !animation_instance.started {
+                if delta < item.spritesheet_animation.delay {
+                    item.spritesheet_animation.delay -= delta;
+                    continue;
+                }
+
+                // Keep the remaining time for the playback
+
+                delta -= item.spritesheet_animation.delay;
+
+                if !item.spritesheet_animation.delay.is_zero() {
+                    item.spritesheet_animation.delay = Duration::ZERO;
+                }
+
+                animation_instance.started = true;
+            }
+
             // Update the animation
 
-            animation_instance.accumulated_time += Duration::from_secs_f32(
-                time.delta_secs() * item.spritesheet_animation.speed_factor,
-            );
+            let reversed = item.spritesheet_animation.speed_factor < 0.0;
+
+            let elapsed = animation_instance.release_time(Duration::from_secs_f32(
+                delta.as_secs_f32() * item.spritesheet_animation.speed_factor.abs(),
+            ));
+
+            if !reversed {
+                animation_instance.accumulated_time += elapsed;
+            }
+
+            // Emit the start event along with the events of the first frame that were held back
+            // (animations without frames never start)
+
+            if just_started {
+                if let Some((frame, _)) = animation_instance
+                    .current_frame
+                    .as_ref()
+                    .filter(|_| emit_events)
+                {
+                    event_writer.send(AnimationEvent::AnimationStarted {
+                        entity: item.entity,
+                        animation_id: animation_instance.animation_id,
+                    });
+
+                    Self::emit_events(
+                        &frame.events,
+                        animation_instance.animation_id,
+                        &item.entity,
+                        animation_instance.accumulated_time,
+                        emit_markers,
+                        event_writer,
+                    );
+                }
+            }
+
+            // Step back through the frames if the animation plays backwards
+
+            if reversed {
+                Self::rewind(
+                    animation_instance,
+                    elapsed,
+                    &mut item,
+                    target_query,
+                    event_writer,
+                    self.collect_frame_transitions
+                        .then_some(&mut self.frame_transitions),
+                    time.elapsed(),
+                );
+
+                continue;
+            }
+
+            let was_playing = animation_instance.current_frame.is_some();
 
             while let Some(current_frame) = animation_instance
                 .current_frame
@@ -171,145 +1138,590 @@ impl Animator {
 
                 // Fetch the next frame
 
-                animation_instance.current_frame =
-                    Self::play_frame(&mut animation_instance.iterator, &mut item, event_writer)
-                        .or_else(|| {
-                            // The animation is over
-
-                            // Emit the end events if the animation just ended
-
-                            event_writer.send(AnimationEvent::ClipRepetitionEnd {
-                                entity: item.entity,
-                                animation_id: animation_instance.animation_id,
-                                clip_id: current_frame.0.clip_id,
-                                clip_repetition: current_frame.0.clip_repetition,
-                            });
-
-                            event_writer.send(AnimationEvent::ClipEnd {
-                                entity: item.entity,
-                                animation_id: animation_instance.animation_id,
-                                clip_id: current_frame.0.clip_id,
-                            });
-
-                            event_writer.send(AnimationEvent::AnimationRepetitionEnd {
-                                entity: item.entity,
-                                animation_id: animation_instance.animation_id,
-                                animation_repetition: current_frame.0.animation_repetition,
-                            });
-
-                            event_writer.send(AnimationEvent::AnimationEnd {
-                                entity: item.entity,
-                                animation_id: animation_instance.animation_id,
-                            });
-
-                            None
-                        });
+                let next_frame = Self::play_frame(
+                    &mut animation_instance.iterator,
+                    &mut item,
+                    target_query,
+                    event_writer,
+                    emit_events,
+                    emit_markers,
+                    animation_instance.accumulated_time,
+                    time.elapsed(),
+                );
+
+                if self.collect_frame_transitions {
+                    if let Some(frame) = &next_frame {
+                        self.frame_transitions.push(Self::frame_context(
+                            &item,
+                            Some(current_frame),
+                            frame,
+                        ));
+                    }
+                }
+
+                // Check if the next frame triggers a hit-pause
+
+                let hit_pause = item.hit_pause.filter(|hit_pause| {
+                    next_frame.as_ref().is_some_and(|(frame, _)| {
+                        frame.events.iter().any(|event| {
+                            matches!(
+                                event,
+                                AnimationIteratorEvent::MarkerHit { marker_id, .. }
+                                    if *marker_id == hit_pause.marker
+                            )
+                        })
+                    })
+                });
+
+                animation_instance.current_frame = next_frame.or_else(|| {
+                    // The animation is over
+
+                    if !emit_events {
+                        return None;
+                    }
+
+                    // Emit the end events if the animation just ended
+
+                    event_writer.send(AnimationEvent::ClipRepetitionEnd {
+                        entity: item.entity,
+                        animation_id: animation_instance.animation_id,
+                        clip_id: current_frame.0.clip_id,
+                        clip_tag: current_frame.0.clip_tag,
+                        clip_repetition: current_frame.0.clip_repetition,
+                    });
+
+                    event_writer.send(AnimationEvent::ClipEnd {
+                        entity: item.entity,
+                        animation_id: animation_instance.animation_id,
+                        clip_id: current_frame.0.clip_id,
+                        clip_tag: current_frame.0.clip_tag,
+                    });
+
+                    event_writer.send(AnimationEvent::AnimationRepetitionEnd {
+                        entity: item.entity,
+                        animation_id: animation_instance.animation_id,
+                        animation_repetition: current_frame.0.animation_repetition,
+                    });
+
+                    event_writer.send(AnimationEvent::AnimationEnd {
+                        entity: item.entity,
+                        animation_id: animation_instance.animation_id,
+                    });
+
+                    None
+                });
+
+                // Pause on the marker frame, keeping the time already spent on it for when the playback resumes
+
+                if let Some(hit_pause) = hit_pause {
+                    animation_instance.hit_pause_remaining = hit_pause.duration;
+
+                    if let HitPauseScope::Group(group) = hit_pause.scope {
+                        group_hit_pauses.push((group, hit_pause.duration));
+                    }
+
+                    break;
+                }
             }
+
+            // Remember where the animation ended and continue with the next queued animation, if any
+
+            if was_playing && animation_instance.current_frame.is_none() {
+                animation_instance.ended_progress = Some(item.spritesheet_animation.progress);
+
+                // Face the requested direction once a turn animation is over
+
+                if let Some(flip_transition) =
+                    item.flip_transition.copied().filter(|flip_transition| {
+                        flip_transition.style
+                            == FlipTransitionStyle::TurnAnimation(animation_instance.animation_id)
+                    })
+                {
+                    Self::assign_flip_x(&mut item, flip_transition.flip_x);
+                }
+
+                if let Some(next_animation_id) =
+                    item.spritesheet_animation.queued_animations.pop_front()
+                {
+                    item.spritesheet_animation.switch(next_animation_id);
+                }
+            }
+        }
+
+        // Pause the other members of the groups for which a hit-pause was triggered
+
+        for (group, duration) in group_hit_pauses {
+            for (entity, _) in hit_pause_group_members
+                .iter()
+                .filter(|(_, member_group)| *member_group == group)
+            {
+                if let Some(instance) = self.animation_instances.get_mut(entity) {
+                    instance.hit_pause_remaining = instance.hit_pause_remaining.max(duration);
+                }
+            }
+        }
+
+        // Compare the displayed atlas indices with the ones of the previous update
+
+        if self.collect_frame_changes {
+            self.collect_frame_changes();
+        }
+    }
+
+    /// Checks if an entity is culled, see [AnimationCulling]
+    fn is_culled(
+        culling: &AnimationCulling,
+        cameras: &[Vec3],
+        item: &SpritesheetAnimationQueryItem<'_>,
+    ) -> bool {
+        if item.no_culling {
+            return false;
+        }
+
+        let offscreen = culling.offscreen
+            && item
+                .view_visibility
+                .is_some_and(|view_visibility| !view_visibility.get());
+
+        let distant = match (culling.max_distance, item.global_transform) {
+            (Some(max_distance), Some(transform)) if !cameras.is_empty() => {
+                let position = transform.translation();
+
+                cameras
+                    .iter()
+                    .all(|camera| camera.distance_squared(position) > max_distance * max_distance)
+            }
+            _ => false,
+        };
+
+        offscreen || distant
+    }
+
+    /// Records the entities that display a different atlas index than after the previous update
+    fn collect_frame_changes(&mut self) {
+        let animation_instances = &self.animation_instances;
+
+        self.displayed_atlas_indices
+            .retain(|entity, _| animation_instances.contains_key(entity));
+
+        let entities: Vec<Entity> = self.animation_instances.keys().copied().collect();
+
+        for entity in entities {
+            let Some(atlas_index) = self.atlas_index(entity) else {
+                continue;
+            };
+
+            if self.displayed_atlas_indices.insert(entity, atlas_index) != Some(atlas_index) {
+                self.frame_changes.push(entity);
+            }
+        }
+    }
+
+    /// Gathers the context of a frame transition for the frame hooks
+    fn frame_context(
+        item: &SpritesheetAnimationQueryItem<'_>,
+        previous_frame: Option<&(IteratorFrame, AnimationProgress)>,
+        frame: &(IteratorFrame, AnimationProgress),
+    ) -> FrameContext {
+        let frame_info = |(frame, progress): &(IteratorFrame, AnimationProgress)| FrameInfo {
+            atlas_index: frame.atlas_index,
+            progress: *progress,
+            clip_id: frame.clip_id,
+            clip_repetition: frame.clip_repetition,
+            label: frame.label,
+        };
+
+        FrameContext {
+            entity: item.entity,
+            animation_id: item.spritesheet_animation.animation_id,
+            previous_frame: previous_frame.map(frame_info),
+            frame: frame_info(frame),
+            events: Self::promote_events(
+                &frame.0.events,
+                item.spritesheet_animation.animation_id,
+                &item.entity,
+                Duration::ZERO,
+            )
+            .collect(),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn play_frame(
         iterator: &mut AnimationIterator,
         item: &mut SpritesheetAnimationQueryItem<'_>,
+        target_query: &mut Query<ClipTargetQuery, Without<SpritesheetAnimation>>,
         event_writer: &mut EventWriter<AnimationEvent>,
+        emit_events: bool,
+        emit_markers: bool,
+        elapsed: Duration,
+        now: Duration,
     ) -> Option<(IteratorFrame, AnimationProgress)> {
         let maybe_frame = iterator.next();
 
         if let Some((frame, progress)) = &maybe_frame {
             // Update the sprite
-            // (we compare the indices to prevent needless "Changed" events)
 
-            if let Some(atlas) = item
-                .sprite
-                .as_deref_mut()
-                .and_then(|sprite| sprite.texture_atlas.as_mut())
-            {
-                if atlas.index != frame.atlas_index {
-                    atlas.index = frame.atlas_index;
-                }
+            Self::display_frame(item, target_query, frame);
+
+            item.spritesheet_animation.progress = *progress;
+
+            // Emit events
+
+            if emit_events {
+                Animator::emit_events(
+                    &frame.events,
+                    item.spritesheet_animation.animation_id,
+                    &item.entity,
+                    elapsed,
+                    emit_markers,
+                    event_writer,
+                );
             }
 
-            if let Some(atlas) = item
-                .sprite3d
-                .as_deref_mut()
-                .and_then(|sprite| sprite.texture_atlas.as_mut())
-            {
-                if atlas.index != frame.atlas_index {
-                    atlas.index = frame.atlas_index;
-                }
+            // Record the frame in the playback history
+
+            if let Some(history) = item.history.as_deref_mut() {
+                let animation_id = item.spritesheet_animation.animation_id;
+
+                history.record(PlaybackRecord {
+                    time: now,
+                    animation_id,
+                    progress: *progress,
+                    atlas_index: item
+                        .spritesheet_animation
+                        .frame_remap
+                        .apply(frame.atlas_index),
+                    events: if emit_events {
+                        Self::promote_events(&frame.events, animation_id, &item.entity, elapsed)
+                            .collect()
+                    } else {
+                        Vec::new()
+                    },
+                });
             }
 
-            if let Some(atlas) = item
-                .image_node
-                .as_deref_mut()
-                .and_then(|image| image.texture_atlas.as_mut())
-            {
-                if atlas.index != frame.atlas_index {
-                    atlas.index = frame.atlas_index;
+            // Sample the frame for the trail
+
+            if frame.target.is_none() {
+                if let Some(trail) = item.trail.as_deref_mut() {
+                    trail.sample(FrameTrailSample {
+                        atlas_index: item
+                            .spritesheet_animation
+                            .frame_remap
+                            .apply(frame.atlas_index),
+                        transform: item.transform.copied().unwrap_or_default(),
+                    });
                 }
             }
+        }
 
-            item.spritesheet_animation.progress = *progress;
+        maybe_frame
+    }
 
-            // Emit events
+    /// Steps an animation back in time, without emitting events.
+    ///
+    /// The animation stops at its first frame.
+    /// An animation that reached its end can be played backwards from its last frame.
+    #[allow(clippy::too_many_arguments)]
+    fn rewind(
+        animation_instance: &mut AnimationInstance,
+        mut elapsed: Duration,
+        item: &mut SpritesheetAnimationQueryItem<'_>,
+        target_query: &mut Query<ClipTargetQuery, Without<SpritesheetAnimation>>,
+        event_writer: &mut EventWriter<AnimationEvent>,
+        mut frame_transitions: Option<&mut Vec<FrameContext>>,
+        now: Duration,
+    ) {
+        // Restore the last frame if the animation ended
 
-            Animator::emit_events(
-                &frame.events,
-                item.spritesheet_animation.animation_id,
-                &item.entity,
+        if animation_instance.current_frame.is_none() {
+            let Some(ended_progress) = animation_instance.ended_progress else {
+                return;
+            };
+
+            if animation_instance.iterator.to(ended_progress).is_err() {
+                return;
+            }
+
+            animation_instance.current_frame = Self::play_frame(
+                &mut animation_instance.iterator,
+                item,
+                target_query,
                 event_writer,
+                false,
+                false,
+                Duration::ZERO,
+                now,
             );
+
+            let Some((frame, _)) = &animation_instance.current_frame else {
+                return;
+            };
+
+            animation_instance.accumulated_time = frame.duration;
+            animation_instance.ended_progress = None;
         }
 
-        maybe_frame
+        while let Some(current_frame) = &animation_instance.current_frame {
+            // Consume the time spent on the current frame
+
+            if elapsed <= animation_instance.accumulated_time {
+                animation_instance.accumulated_time -= elapsed;
+                return;
+            }
+
+            elapsed -= animation_instance.accumulated_time;
+
+            // Go back to the previous frame, from its end
+
+            let Some(previous_progress) = animation_instance
+                .iterator
+                .previous_progress(current_frame.1)
+            else {
+                animation_instance.accumulated_time = Duration::ZERO;
+                return;
+            };
+
+            if animation_instance.iterator.to(previous_progress).is_err() {
+                return;
+            }
+
+            let Some(previous_frame) = Self::play_frame(
+                &mut animation_instance.iterator,
+                item,
+                target_query,
+                event_writer,
+                false,
+                false,
+                Duration::ZERO,
+                now,
+            ) else {
+                return;
+            };
+
+            if let Some(frame_transitions) = frame_transitions.as_deref_mut() {
+                frame_transitions.push(Self::frame_context(
+                    item,
+                    Some(current_frame),
+                    &previous_frame,
+                ));
+            }
+
+            animation_instance.accumulated_time = previous_frame.0.duration;
+            animation_instance.current_frame = Some(previous_frame);
+        }
+    }
+
+    /// Assigns the atlas index and flips of a frame to the entity targeted by its clip
+    fn display_frame(
+        item: &mut SpritesheetAnimationQueryItem<'_>,
+        target_query: &mut Query<ClipTargetQuery, Without<SpritesheetAnimation>>,
+        frame: &IteratorFrame,
+    ) {
+        let atlas_index = item
+            .spritesheet_animation
+            .frame_remap
+            .apply(frame.atlas_index);
+
+        match frame.target {
+            // The animated entity itself
+            None => Self::assign_frame(
+                item.sprite.as_deref_mut(),
+                item.sprite3d.as_deref_mut(),
+                item.image_node.as_deref_mut(),
+                atlas_index,
+                frame,
+            ),
+            // The children with a matching ClipTarget
+            Some(target) => {
+                for child in item.children.iter().flat_map(|children| children.iter()) {
+                    if let Ok(mut target_item) = target_query.get_mut(*child) {
+                        if *target_item.target == target {
+                            Self::assign_frame(
+                                target_item.sprite.as_deref_mut(),
+                                target_item.sprite3d.as_deref_mut(),
+                                target_item.image_node.as_deref_mut(),
+                                atlas_index,
+                                frame,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the horizontal flip of the sprite, 3D sprite or image of an entity
+    fn flip_x(item: &SpritesheetAnimationQueryItem<'_>) -> Option<bool> {
+        item.sprite
+            .as_ref()
+            .map(|sprite| sprite.flip_x)
+            .or(item.sprite3d.as_ref().map(|sprite| sprite.flip_x))
+            .or(item.image_node.as_ref().map(|image| image.flip_x))
+    }
+
+    /// Flips the sprite, 3D sprite or image of an entity horizontally
+    fn assign_flip_x(item: &mut SpritesheetAnimationQueryItem<'_>, flip_x: bool) {
+        if let Some(sprite) = item.sprite.as_deref_mut() {
+            if sprite.flip_x != flip_x {
+                sprite.flip_x = flip_x;
+            }
+        }
+
+        if let Some(sprite) = item.sprite3d.as_deref_mut() {
+            if sprite.flip_x != flip_x {
+                sprite.flip_x = flip_x;
+            }
+        }
+
+        if let Some(image) = item.image_node.as_deref_mut() {
+            if image.flip_x != flip_x {
+                image.flip_x = flip_x;
+            }
+        }
+    }
+
+    fn assign_frame(
+        sprite: Option<&mut Sprite>,
+        sprite3d: Option<&mut Sprite3d>,
+        image_node: Option<&mut ImageNode>,
+        atlas_index: usize,
+        frame: &IteratorFrame,
+    ) {
+        // We compare the values to prevent needless "Changed" events
+
+        fn assign<T: PartialEq>(value: &mut T, new_value: T) {
+            if *value != new_value {
+                *value = new_value;
+            }
+        }
+
+        fn assign_flip(flip: &mut bool, new_flip: Option<bool>) {
+            if let Some(new_flip) = new_flip {
+                assign(flip, new_flip);
+            }
+        }
+
+        fn assign_layout(atlas: &mut TextureAtlas, clip_image: &Option<ClipImage>) {
+            if let Some(clip_image) = clip_image {
+                assign(&mut atlas.layout, clip_image.atlas_layout.clone());
+            }
+        }
+
+        if let Some(sprite) = sprite {
+            if let Some(clip_image) = &frame.image {
+                assign(&mut sprite.image, clip_image.image.clone());
+            }
+
+            if let Some(atlas) = sprite.texture_atlas.as_mut() {
+                assign_layout(atlas, &frame.image);
+                assign(&mut atlas.index, atlas_index);
+            }
+
+            assign_flip(&mut sprite.flip_x, frame.flip_x);
+            assign_flip(&mut sprite.flip_y, frame.flip_y);
+        }
+
+        if let Some(sprite) = sprite3d {
+            if let Some(clip_image) = &frame.image {
+                assign(&mut sprite.image, clip_image.image.clone());
+            }
+
+            if let Some(atlas) = sprite.texture_atlas.as_mut() {
+                assign_layout(atlas, &frame.image);
+                assign(&mut atlas.index, atlas_index);
+            }
+
+            assign_flip(&mut sprite.flip_x, frame.flip_x);
+            assign_flip(&mut sprite.flip_y, frame.flip_y);
+        }
+
+        if let Some(image) = image_node {
+            if let Some(clip_image) = &frame.image {
+                assign(&mut image.image, clip_image.image.clone());
+            }
+
+            if let Some(atlas) = image.texture_atlas.as_mut() {
+                assign_layout(atlas, &frame.image);
+                assign(&mut atlas.index, atlas_index);
+            }
+
+            assign_flip(&mut image.flip_x, frame.flip_x);
+            assign_flip(&mut image.flip_y, frame.flip_y);
+        }
     }
 
     fn emit_events(
         animation_events: &[AnimationIteratorEvent],
         animation_id: AnimationId,
         entity: &Entity,
+        elapsed: Duration,
+        emit_markers: bool,
         event_writer: &mut EventWriter<AnimationEvent>,
     ) {
-        animation_events.iter().for_each(|event| {
-            event_writer.send(
-                // Promote AnimationIteratorEvents to regular AnimationEvents
-                match event {
-                    AnimationIteratorEvent::MarkerHit {
-                        marker_id,
-                        animation_repetition,
-                        clip_id,
-                        clip_repetition,
-                    } => AnimationEvent::MarkerHit {
-                        entity: *entity,
-                        marker_id: *marker_id,
-                        animation_id,
-                        animation_repetition: *animation_repetition,
-                        clip_id: *clip_id,
-                        clip_repetition: *clip_repetition,
-                    },
-                    AnimationIteratorEvent::ClipRepetitionEnd {
-                        clip_id,
-                        clip_repetition,
-                    } => AnimationEvent::ClipRepetitionEnd {
-                        entity: *entity,
-                        animation_id,
-                        clip_id: *clip_id,
-                        clip_repetition: *clip_repetition,
-                    },
-                    AnimationIteratorEvent::ClipEnd { clip_id } => AnimationEvent::ClipEnd {
-                        entity: *entity,
-                        animation_id,
-                        clip_id: *clip_id,
-                    },
-                    AnimationIteratorEvent::AnimationRepetitionEnd {
-                        animation_repetition,
-                    } => AnimationEvent::AnimationRepetitionEnd {
-                        entity: *entity,
-                        animation_id,
-                        animation_repetition: *animation_repetition,
-                    },
-                },
-            );
-        });
+        for event in Self::promote_events(animation_events, animation_id, entity, elapsed)
+            .filter(|event| emit_markers || !matches!(event, AnimationEvent::MarkerHit { .. }))
+        {
+            event_writer.send(event);
+        }
+    }
+
+    /// Promotes AnimationIteratorEvents to regular AnimationEvents
+    fn promote_events<'a>(
+        animation_events: &'a [AnimationIteratorEvent],
+        animation_id: AnimationId,
+        entity: &'a Entity,
+        elapsed: Duration,
+    ) -> impl Iterator<Item = AnimationEvent> + 'a {
+        animation_events.iter().map(move |event| match event {
+            AnimationIteratorEvent::MarkerHit {
+                marker_id,
+                animation_repetition,
+                clip_id,
+                clip_repetition,
+            } => AnimationEvent::MarkerHit {
+                entity: *entity,
+                marker_id: *marker_id,
+                animation_id,
+                animation_repetition: *animation_repetition,
+                clip_id: *clip_id,
+                clip_repetition: *clip_repetition,
+            },
+            AnimationIteratorEvent::ClipRepetitionEnd {
+                clip_id,
+                clip_tag,
+                clip_repetition,
+            } => AnimationEvent::ClipRepetitionEnd {
+                entity: *entity,
+                animation_id,
+                clip_id: *clip_id,
+                clip_tag: *clip_tag,
+                clip_repetition: *clip_repetition,
+            },
+            AnimationIteratorEvent::ClipEnd { clip_id, clip_tag } => AnimationEvent::ClipEnd {
+                entity: *entity,
+                animation_id,
+                clip_id: *clip_id,
+                clip_tag: *clip_tag,
+            },
+            AnimationIteratorEvent::AnimationRepetitionEnd {
+                animation_repetition,
+            } => AnimationEvent::AnimationRepetitionEnd {
+                entity: *entity,
+                animation_id,
+                animation_repetition: *animation_repetition,
+            },
+            AnimationIteratorEvent::ClipStart {
+                clip_id,
+                animation_repetition,
+            } => AnimationEvent::ClipStart {
+                entity: *entity,
+                animation_id,
+                clip_id: *clip_id,
+                animation_repetition: *animation_repetition,
+                elapsed,
+            },
+        })
     }
 }
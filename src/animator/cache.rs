@@ -1,23 +1,48 @@
 use crate::{
-    animation::{AnimationDirection, AnimationDuration, AnimationId, AnimationRepeat},
-    clip::{Clip, ClipId},
+    animation::{Animation, AnimationDirection, AnimationDuration, AnimationId, AnimationRepeat},
+    clip::{Clip, ClipId, ClipImage, ClipTag, FrameLabel, MarkerRepetitions},
+    components::{
+        clip_target::ClipTarget,
+        spritesheet_animation::{AnimationProgress, ProgressError},
+    },
+    diagnostics,
     easing::Easing,
     events::AnimationMarkerId,
     library::AnimationLibrary,
-    CRATE_NAME,
 };
-use bevy::{log::warn, reflect::prelude::*};
+use bevy::reflect::prelude::*;
 use std::time::Duration;
 
 /// A pre-computed frame of animation, ready to be played back.
 #[derive(Debug, Clone, Reflect)]
 #[reflect(Debug)]
 pub struct CacheFrame {
+    /// The atlas index of the frame, as defined in its clip
     pub atlas_index: usize,
+    /// The duration of the frame, before any random variation
     pub duration: Duration,
+    /// The clip that contains the frame
     pub clip_id: ClipId,
+    /// The repetition of the clip that contains the frame
     pub clip_repetition: usize,
+    /// The events emitted when the frame is reached
     pub events: Vec<AnimationCacheEvent>,
+    /// Whether this frame can be dropped when frame skipping is enabled
+    pub skippable: bool,
+    /// The debug label of the frame
+    pub label: Option<FrameLabel>,
+    /// Maximum random variation of the duration, as a fraction of the duration
+    pub duration_jitter: f32,
+    /// The child entity to animate instead of the animated entity
+    pub target: Option<ClipTarget>,
+    /// The tag of the clip
+    pub clip_tag: Option<ClipTag>,
+    /// Whether the clip flips the sprite horizontally
+    pub flip_x: Option<bool>,
+    /// Whether the clip flips the sprite vertically
+    pub flip_y: Option<bool>,
+    /// The spritesheet image of the clip
+    pub image: Option<ClipImage>,
 }
 
 /// A partial version of AnimationEvent.
@@ -35,13 +60,19 @@ pub enum AnimationCacheEvent {
         marker_id: AnimationMarkerId,
         clip_id: ClipId,
         clip_repetition: usize,
+        repetitions: Option<MarkerRepetitions>,
     },
     ClipRepetitionEnd {
         clip_id: ClipId,
+        clip_tag: Option<ClipTag>,
         clip_repetition: usize,
     },
     ClipEnd {
         clip_id: ClipId,
+        clip_tag: Option<ClipTag>,
+    },
+    ClipStart {
+        clip_id: ClipId,
     },
 }
 
@@ -70,6 +101,72 @@ pub struct AnimationCache {
 }
 
 impl AnimationCache {
+    /// Checks that a progress points to a frame of the animation
+    pub fn validate_progress(&self, progress: AnimationProgress) -> Result<(), ProgressError> {
+        if progress.frame >= self.frames.len() {
+            Err(ProgressError::InvalidFrame {
+                frame: progress.frame,
+                frame_count: self.frames.len(),
+            })
+        } else if let Some(repetitions) = self
+            .repetitions
+            .filter(|repetitions| progress.repetition >= *repetitions)
+        {
+            Err(ProgressError::InvalidRepetition {
+                repetition: progress.repetition,
+                repetitions,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the frames of a repetition of the animation, with their atlas indices, durations and events.
+    ///
+    /// This is a read-only view of the timeline resolved from the parameters of the animation and its clips, for tools like animation previews.
+    /// Frame skipping is not applied.
+    ///
+    /// With [AnimationDirection::PingPong], the odd repetitions list the frames in reverse
+    /// and the first frame of the repetitions after the first one is not played, as it is the same as the last frame of the previous repetition.
+    pub fn repetition_frames(&self, repetition: usize) -> &[CacheFrame] {
+        match &self.frames_pong {
+            Some(frames_pong) if repetition % 2 == 1 => frames_pong,
+            _ => &self.frames,
+        }
+    }
+
+    /// Returns the events cached for a frame of the animation.
+    ///
+    /// The events are the ones emitted when the frame is reached, so the timeline can be looked ahead,
+    /// for instance to prepare effects a few frames before a marker.
+    /// The end events generated during the playback are not included (see [AnimationCacheEvent]).
+    ///
+    /// Returns None if the progress does not point to a frame of the animation.
+    pub fn frame_events(&self, progress: AnimationProgress) -> Option<&[AnimationCacheEvent]> {
+        self.validate_progress(progress).ok()?;
+
+        self.repetition_frames(progress.repetition)
+            .get(progress.frame)
+            .map(|frame| frame.events.as_slice())
+    }
+
+    /// Returns an estimation of the memory used by the cache, in bytes
+    pub fn memory_size(&self) -> usize {
+        let frames_size = |frames: &[CacheFrame]| {
+            frames
+                .iter()
+                .map(|frame| {
+                    std::mem::size_of::<CacheFrame>()
+                        + frame.events.capacity() * std::mem::size_of::<AnimationCacheEvent>()
+                })
+                .sum::<usize>()
+        };
+
+        std::mem::size_of::<Self>()
+            + frames_size(&self.frames)
+            + self.frames_pong.as_deref().map(frames_size).unwrap_or(0)
+    }
+
     fn empty() -> Self {
         Self {
             frames: Vec::new(),
@@ -80,8 +177,14 @@ impl AnimationCache {
     }
 
     pub fn new(animation_id: AnimationId, library: &AnimationLibrary) -> AnimationCache {
-        let animation = library.get_animation(animation_id);
+        Self::from_animation(library.get_animation(animation_id), library)
+    }
 
+    /// Builds the cache of an animation that is not necessarily registered in the library, like an animation with [AnimationOverrides](crate::prelude::AnimationOverrides)
+    pub(crate) fn from_animation(
+        animation: &Animation,
+        library: &AnimationLibrary,
+    ) -> AnimationCache {
         // If the animation repeats 0 times, just create an empty cache that will play no frames
         // TODO should use the first frame only instead?
 
@@ -157,9 +260,27 @@ impl AnimationCache {
                     }
                 };
 
+                // Compute the duration of each frame, taking into account the frames with specific durations
+
+                let frame_duration_scale = match animation.duration() {
+                    // No duration is defined for the animation: keep the specific durations
+                    None => Some(1.0),
+
+                    // The per-frame duration is defined for the animation: override the specific durations with it
+                    Some(AnimationDuration::PerFrame(_)) => None,
+
+                    // The per-cycle duration of the animation is defined: scale the specific durations like the rest of the animation
+                    Some(AnimationDuration::PerRepetition(animation_cycle_duration)) => {
+                        Some(*animation_cycle_duration as f32 / animation_duration_ms as f32)
+                    }
+                };
+
+                let clip_frame_durations_ms = clip_data
+                    .frame_durations_ms(clip_frame_corrected_duration_ms, frame_duration_scale);
+
                 // Generate the frames for the current clip
 
-                ClipFrames::new(clip_data, clip_frame_corrected_duration_ms)
+                ClipFrames::new(clip_data, clip_frame_durations_ms)
             })
             .collect();
 
@@ -168,9 +289,19 @@ impl AnimationCache {
         let animation_direction = animation.direction().unwrap_or_default();
         let animation_easing = animation.easing().unwrap_or_default();
 
-        let (all_frames, all_frames_pong) =
+        let (mut all_frames, mut all_frames_pong) =
             animation_frames.build(animation_direction, animation_easing);
 
+        // Scale the frames of retimed animations to the exact duration of a repetition
+
+        if let Some(repetition_duration_ms) = animation.retimed_duration() {
+            retime_frames(&mut all_frames, *repetition_duration_ms);
+
+            if let Some(frames_pong) = &mut all_frames_pong {
+                retime_frames(frames_pong, *repetition_duration_ms);
+            }
+        }
+
         // Done!
 
         let animation_repetition_count = match animation_repetitions {
@@ -187,6 +318,42 @@ impl AnimationCache {
     }
 }
 
+/// Scales the durations of some frames so that they last a total duration.
+///
+/// The durations are rounded down to the millisecond and the remaining milliseconds
+/// are given to the frames that lost the most to the rounding.
+fn retime_frames(frames: &mut [CacheFrame], duration_ms: u32) {
+    let frames_duration: u128 = frames.iter().map(|frame| frame.duration.as_nanos()).sum();
+
+    if frames_duration == 0 {
+        return;
+    }
+
+    let mut remainders = Vec::with_capacity(frames.len());
+    let mut assigned_ms = 0;
+
+    for (index, frame) in frames.iter_mut().enumerate() {
+        let scaled_duration = frame.duration.as_nanos() * duration_ms as u128;
+
+        let frame_duration_ms = scaled_duration / frames_duration;
+
+        frame.duration = Duration::from_millis(frame_duration_ms as u64);
+        assigned_ms += frame_duration_ms;
+
+        remainders.push((scaled_duration % frames_duration, index));
+    }
+
+    // Largest remainders first, in playing order for equal remainders
+
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    let missing_ms = duration_ms as usize - assigned_ms as usize;
+
+    for (_, index) in remainders.into_iter().take(missing_ms) {
+        frames[index].duration += Duration::from_millis(1);
+    }
+}
+
 #[derive(Clone)]
 struct ClipData {
     id: ClipId,
@@ -225,7 +392,7 @@ impl ClipData {
             AnimationDuration::PerRepetition(repetition_duration) => repetition_duration,
         };
 
-        Self {
+        let mut clip_data = Self {
             id: clip_id,
             clip,
             duration,
@@ -233,7 +400,81 @@ impl ClipData {
             direction,
             easing,
             duration_with_repetitions_ms,
+        };
+
+        // If some frames have specific durations, sum up the actual frame durations instead
+
+        if !clip_data.clip.frame_durations().is_empty() {
+            let frame_durations_ms =
+                clip_data.frame_durations_ms(clip_data.uniform_frame_duration_ms(), Some(1.0));
+
+            let repetition_duration_ms: u32 = frame_durations_ms.iter().sum();
+
+            clip_data.duration_with_repetitions_ms = match direction {
+                AnimationDirection::Forwards | AnimationDirection::Backwards => {
+                    repetition_duration_ms * repetitions as u32
+                }
+                // After the first repetition, PingPong skips the first frame of ping repetitions and the last frame of pong repetitions
+                AnimationDirection::PingPong => {
+                    let first_ms = frame_durations_ms.first().copied().unwrap_or(0);
+                    let last_ms = frame_durations_ms.last().copied().unwrap_or(0);
+
+                    (0..repetitions)
+                        .map(|repetition| match repetition {
+                            0 => repetition_duration_ms,
+                            r if r % 2 == 0 => repetition_duration_ms - first_ms,
+                            _ => repetition_duration_ms - last_ms,
+                        })
+                        .sum()
+                }
+            };
         }
+
+        clip_data
+    }
+
+    /// Returns the duration of the frames without specific durations.
+    fn uniform_frame_duration_ms(&self) -> u32 {
+        match self.duration {
+            AnimationDuration::PerFrame(frame_duration_ms) => frame_duration_ms,
+            AnimationDuration::PerRepetition(repetition_duration_ms) => {
+                repetition_duration_ms / self.clip.frames().len().max(1) as u32
+            }
+        }
+    }
+
+    /// Returns the duration of each frame of the clip.
+    ///
+    /// # Arguments
+    ///
+    /// * `corrected_frame_duration_ms` - the duration to assign to the frames if the clip has no frames with specific durations
+    /// * `frame_duration_scale` - the scale to apply to the frame durations if the clip has frames with specific durations, or None to ignore them
+    fn frame_durations_ms(
+        &self,
+        corrected_frame_duration_ms: u32,
+        frame_duration_scale: Option<f32>,
+    ) -> Vec<u32> {
+        let uniform_frame_duration_ms = self.uniform_frame_duration_ms();
+
+        (0..self.clip.frames().len())
+            .map(|frame_index| {
+                match (
+                    self.clip.frame_durations().get(&frame_index),
+                    frame_duration_scale,
+                ) {
+                    // Specific duration
+                    (Some(frame_duration_ms), Some(scale)) => {
+                        (*frame_duration_ms as f32 * scale) as u32
+                    }
+                    // Regular duration in a clip with specific durations: scale it the same way
+                    (None, Some(scale)) if !self.clip.frame_durations().is_empty() => {
+                        (uniform_frame_duration_ms as f32 * scale) as u32
+                    }
+                    // Uniform durations
+                    _ => corrected_frame_duration_ms,
+                }
+            })
+            .collect()
     }
 }
 
@@ -244,6 +485,8 @@ struct Frame {
     atlas_index: usize,
     duration: Duration,
     markers: Vec<AnimationMarkerId>,
+    skippable: bool,
+    label: Option<FrameLabel>,
 }
 
 #[derive(Clone)]
@@ -252,7 +495,7 @@ struct ClipRepetitionFrames {
 }
 
 impl ClipRepetitionFrames {
-    fn new(clip_data: &ClipData, frame_duration_ms: u32) -> Self {
+    fn new(clip_data: &ClipData, frame_durations_ms: &[u32]) -> Self {
         Self {
             frames: clip_data
                 .clip
@@ -271,8 +514,10 @@ impl ClipRepetitionFrames {
 
                     Frame {
                         atlas_index: *frame_atlas_index,
-                        duration: Duration::from_millis(frame_duration_ms as u64),
+                        duration: Duration::from_millis(frame_durations_ms[frame_index] as u64),
                         markers,
+                        skippable: clip_data.clip.skippable_frames().contains(&frame_index),
+                        label: clip_data.clip.frame_labels().get(&frame_index).copied(),
                     }
                 })
                 // Filter out frames with no duration
@@ -307,9 +552,8 @@ struct ClipFrames {
 }
 
 impl ClipFrames {
-    fn new(clip_data: ClipData, frame_duration_override_ms: u32) -> Self {
-        let reference_repetition =
-            ClipRepetitionFrames::new(&clip_data, frame_duration_override_ms);
+    fn new(clip_data: ClipData, frame_durations_ms: Vec<u32>) -> Self {
+        let reference_repetition = ClipRepetitionFrames::new(&clip_data, &frame_durations_ms);
 
         Self {
             repetitions: (0..clip_data.repetitions)
@@ -440,14 +684,28 @@ impl AnimationFrames {
                                     marker_id: *marker,
                                     clip_id: clip.data.id,
                                     clip_repetition: repetition_index,
+                                    repetitions: clip
+                                        .data
+                                        .clip
+                                        .marker_repetitions()
+                                        .get(marker)
+                                        .copied(),
                                 })
                                 .collect(),
+                            skippable: frame.skippable,
+                            label: frame.label,
+                            duration_jitter: clip.data.clip.duration_jitter(),
+                            target: *clip.data.clip.target(),
+                            clip_tag: *clip.data.clip.tag(),
+                            flip_x: *clip.data.clip.flip_x(),
+                            flip_y: *clip.data.clip.flip_y(),
+                            image: clip.data.clip.image().clone(),
                         })
                         .collect();
 
                     // Inject a ClipRepetitionEnd event on the first frame of each repetition after the first one
 
-                    if let Some((previous_clip_id, previous_clip_repetition)) =
+                    if let Some((previous_clip_id, previous_clip_tag, previous_clip_repetition)) =
                         previous_clip_repetition
                     {
                         // At this point, we can safely access [0] as empty cycles have been filtered out
@@ -455,11 +713,13 @@ impl AnimationFrames {
                             .events
                             .push(AnimationCacheEvent::ClipRepetitionEnd {
                                 clip_id: previous_clip_id,
+                                clip_tag: previous_clip_tag,
                                 clip_repetition: previous_clip_repetition,
                             });
                     }
 
-                    previous_clip_repetition = Some((clip.data.id, repetition_index));
+                    previous_clip_repetition =
+                        Some((clip.data.id, *clip.data.clip.tag(), repetition_index));
 
                     // Merge with the full clip
 
@@ -471,15 +731,24 @@ impl AnimationFrames {
                 // Because we'll return None at the end of the animation, the Animator will be
                 // responsible for generating ClipRepetitionEnd/ClipEnd for the last animation cycle
 
-                if let Some(previous_clip_id) = previous_clip {
+                if let Some((previous_clip_id, previous_clip_tag)) = previous_clip {
                     all_clip_frames[0]
                         .events
                         .push(AnimationCacheEvent::ClipEnd {
                             clip_id: previous_clip_id,
+                            clip_tag: previous_clip_tag,
                         });
                 }
 
-                previous_clip = Some(clip.data.id);
+                previous_clip = Some((clip.data.id, *clip.data.clip.tag()));
+
+                // Inject a ClipStart event on the first frame of each clip
+
+                if let Some(first_frame) = all_clip_frames.first_mut() {
+                    first_frame.events.push(AnimationCacheEvent::ClipStart {
+                        clip_id: clip.data.id,
+                    });
+                }
 
                 // Merge with the full animation
 
@@ -514,7 +783,7 @@ fn apply_easing(frame_durations: Vec<&mut Duration>, easing: Easing) {
     let total_duration_ms: u32 = frame_durations.iter().map(|d| d.as_millis() as u32).sum();
 
     if total_duration_ms == 0 {
-        warn!("{CRATE_NAME}: zero duration, cannot apply easing");
+        diagnostics::warning(format_args!("zero duration, cannot apply easing"));
 
         return;
     }
@@ -1,10 +1,16 @@
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashSet, sync::Arc, time::Duration};
 
-use bevy::{log::warn, reflect::prelude::*};
+use bevy::reflect::prelude::*;
 
 use crate::{
-    animation::AnimationDirection, clip::ClipId,
-    components::spritesheet_animation::AnimationProgress, events::AnimationMarkerId, CRATE_NAME,
+    animation::AnimationDirection,
+    clip::{ClipId, ClipImage, ClipTag, FrameLabel},
+    components::{
+        clip_target::ClipTarget,
+        spritesheet_animation::{AnimationProgress, ProgressError},
+    },
+    events::AnimationMarkerId,
+    hash::{hash_to_unit, hash_u64},
 };
 
 use super::cache::{AnimationCache, AnimationCacheEvent, CacheFrame};
@@ -19,6 +25,12 @@ pub struct IteratorFrame {
     pub clip_repetition: usize,
     pub animation_repetition: usize,
     pub events: Vec<AnimationIteratorEvent>,
+    pub label: Option<FrameLabel>,
+    pub target: Option<ClipTarget>,
+    pub clip_tag: Option<ClipTag>,
+    pub flip_x: Option<bool>,
+    pub flip_y: Option<bool>,
+    pub image: Option<ClipImage>,
 }
 
 /// A partial version of AnimationEvent.
@@ -35,17 +47,23 @@ pub enum AnimationIteratorEvent {
     },
     ClipRepetitionEnd {
         clip_id: ClipId,
+        clip_tag: Option<ClipTag>,
         clip_repetition: usize,
     },
     ClipEnd {
         clip_id: ClipId,
+        clip_tag: Option<ClipTag>,
     },
     AnimationRepetitionEnd {
         animation_repetition: usize,
     },
+    ClipStart {
+        clip_id: ClipId,
+        animation_repetition: usize,
+    },
 }
 
-#[derive(Debug, Reflect)]
+#[derive(Debug, Clone, Reflect)]
 #[reflect(Debug)]
 /// An iterator that advances an animation frame by frame.
 ///
@@ -60,6 +78,18 @@ pub struct AnimationIterator {
     /// Marks when a repetition just completed so that end events can be emitted on the next iteration
     /// (the value is the last frame)
     repetition_just_ended: Option<CacheFrame>,
+
+    /// Whether skippable frames should be merged into the previous frame
+    skip_frames: bool,
+
+    /// Seed for the random variations of the frame durations
+    jitter_seed: u64,
+
+    /// Whether ClipStart events should be produced
+    clip_start_events: bool,
+
+    /// Clips whose frames should be skipped
+    disabled_clips: HashSet<ClipId>,
 }
 
 impl AnimationIterator {
@@ -68,57 +98,135 @@ impl AnimationIterator {
             cache,
             next_frame_progress: AnimationProgress::default(),
             repetition_just_ended: None,
+            skip_frames: false,
+            jitter_seed: 0,
+            clip_start_events: false,
+            disabled_clips: HashSet::new(),
+        }
+    }
+
+    /// Enables or disables frame skipping for the next produced frames.
+    pub fn set_frame_skipping(&mut self, enabled: bool) {
+        self.skip_frames = enabled;
+    }
+
+    /// Sets the seed for the random variations of the frame durations.
+    pub fn set_jitter_seed(&mut self, seed: u64) {
+        self.jitter_seed = seed;
+    }
+
+    /// Enables or disables the ClipStart events for the next produced frames.
+    pub fn set_clip_start_events(&mut self, enabled: bool) {
+        self.clip_start_events = enabled;
+    }
+
+    /// Sets the clips whose frames should be skipped for the next produced frames.
+    pub fn set_disabled_clips(&mut self, disabled_clips: &HashSet<ClipId>) {
+        // Compare the sets to prevent needless copies
+
+        if self.disabled_clips != *disabled_clips {
+            self.disabled_clips = disabled_clips.clone();
         }
     }
 
     /// Sets the current animation progress.
     ///
-    /// Returns false if the indices are invalid.
-    pub fn to(&mut self, progress: AnimationProgress) -> bool {
-        // Validate the target progress
-
-        if progress.frame >= self.cache.frames.len() {
-            warn!(
-                "{CRATE_NAME}: invalid frame {} in {}-frame animation, cannot update progress",
-                progress.frame,
-                self.cache.frames.len().saturating_sub(1)
-            );
-
-            false
-        } else if let Some(repetitions) = self
-            .cache
-            .repetitions
-            .filter(|repetitions| progress.repetition >= *repetitions)
-        {
-            warn!(
-                "{CRATE_NAME}: invalid repetition {} in {}-repetition animation, cannot update progress",
-                progress.frame,
-                repetitions.saturating_sub(1)
-            );
-
-            false
-        } else {
-            // Update the iterator
+    /// Returns an error if the indices are invalid.
+    pub fn to(&mut self, progress: AnimationProgress) -> Result<(), ProgressError> {
+        self.cache.validate_progress(progress)?;
+
+        self.next_frame_progress = progress;
+        self.repetition_just_ended = None;
 
-            self.next_frame_progress = progress;
-            self.repetition_just_ended = None;
+        Ok(())
+    }
+
+    /// Returns the progress of the frame produced before the frame at some progress, if any.
+    ///
+    /// The frames that are not produced (disabled clips, merged skippable frames) are passed over.
+    pub fn previous_progress(&self, progress: AnimationProgress) -> Option<AnimationProgress> {
+        let mut progress = progress;
+
+        loop {
+            // PingPong: the first frame is skipped after the first repetition
+
+            let first_frame = |repetition: usize| {
+                if repetition > 0
+                    && matches!(self.cache.animation_direction, AnimationDirection::PingPong)
+                {
+                    1
+                } else {
+                    0
+                }
+            };
 
-            true
+            progress = if progress.frame > first_frame(progress.repetition) {
+                AnimationProgress {
+                    frame: progress.frame - 1,
+                    repetition: progress.repetition,
+                }
+            } else if progress.repetition > 0 {
+                AnimationProgress {
+                    frame: self.cache.frames.len().checked_sub(1)?,
+                    repetition: progress.repetition - 1,
+                }
+            } else {
+                return None;
+            };
+
+            let frames = self.cache.repetition_frames(progress.repetition);
+            let frame = frames.get(progress.frame)?;
+
+            let merged = self.skip_frames
+                && frame.skippable
+                && progress.frame > first_frame(progress.repetition)
+                && frames
+                    .get(progress.frame - 1)
+                    .is_some_and(|previous_frame| {
+                        previous_frame.clip_id == frame.clip_id
+                            && previous_frame.clip_repetition == frame.clip_repetition
+                    });
+
+            if !merged && !self.disabled_clips.contains(&frame.clip_id) {
+                return Some(progress);
+            }
         }
     }
 
+    /// Returns a pseudo-random value in [-1, 1] for a frame, derived from the seed.
+    ///
+    /// The same seed and progress always produce the same value.
+    fn jitter_variation(seed: u64, progress: AnimationProgress) -> f32 {
+        let hash = hash_u64(
+            seed ^ (progress.frame as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+                ^ (progress.repetition as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F),
+        );
+
+        hash_to_unit(hash) * 2.0 - 1.0
+    }
+
     /// Promotes AnimationCacheEvents to AnimationIteratorEvents
+    ///
+    /// The markers restricted to other repetitions of the animation are dropped.
     fn promote_events(
         animation_events: &[AnimationCacheEvent],
         animation_repetition: usize,
     ) -> Vec<AnimationIteratorEvent> {
         animation_events
             .iter()
+            .filter(|event| match event {
+                AnimationCacheEvent::MarkerHit {
+                    repetitions: Some(repetitions),
+                    ..
+                } => repetitions.contains(animation_repetition),
+                _ => true,
+            })
             .map(|event| match event {
                 AnimationCacheEvent::MarkerHit {
                     marker_id,
                     clip_id,
                     clip_repetition,
+                    ..
                 } => AnimationIteratorEvent::MarkerHit {
                     marker_id: *marker_id,
                     animation_repetition,
@@ -127,14 +235,23 @@ impl AnimationIterator {
                 },
                 AnimationCacheEvent::ClipRepetitionEnd {
                     clip_id,
+                    clip_tag,
                     clip_repetition,
                 } => AnimationIteratorEvent::ClipRepetitionEnd {
                     clip_id: *clip_id,
+                    clip_tag: *clip_tag,
                     clip_repetition: *clip_repetition,
                 },
-                AnimationCacheEvent::ClipEnd { clip_id } => {
-                    AnimationIteratorEvent::ClipEnd { clip_id: *clip_id }
+                AnimationCacheEvent::ClipEnd { clip_id, clip_tag } => {
+                    AnimationIteratorEvent::ClipEnd {
+                        clip_id: *clip_id,
+                        clip_tag: *clip_tag,
+                    }
                 }
+                AnimationCacheEvent::ClipStart { clip_id } => AnimationIteratorEvent::ClipStart {
+                    clip_id: *clip_id,
+                    animation_repetition,
+                },
             })
             .collect()
     }
@@ -144,6 +261,57 @@ impl Iterator for AnimationIterator {
     type Item = (IteratorFrame, AnimationProgress);
 
     fn next(&mut self) -> Option<Self::Item> {
+        // Skip the frames of the disabled clips but keep the events that do not belong to them
+
+        let mut carried_events = Vec::new();
+
+        loop {
+            let (mut frame, progress) = self.next_frame()?;
+
+            frame
+                .events
+                .retain(|event| !Self::is_disabled_clip_event(&self.disabled_clips, event));
+
+            if !self.disabled_clips.contains(&frame.clip_id) {
+                carried_events.append(&mut frame.events);
+                frame.events = carried_events;
+
+                return Some((frame, progress));
+            }
+
+            // Stop if all the clips are disabled, which would otherwise skip frames forever
+
+            if self
+                .cache
+                .frames
+                .iter()
+                .all(|frame| self.disabled_clips.contains(&frame.clip_id))
+            {
+                return None;
+            }
+
+            carried_events.append(&mut frame.events);
+        }
+    }
+}
+
+impl AnimationIterator {
+    /// Returns true if an event belongs to a disabled clip
+    fn is_disabled_clip_event(
+        disabled_clips: &HashSet<ClipId>,
+        event: &AnimationIteratorEvent,
+    ) -> bool {
+        match event {
+            AnimationIteratorEvent::MarkerHit { clip_id, .. }
+            | AnimationIteratorEvent::ClipRepetitionEnd { clip_id, .. }
+            | AnimationIteratorEvent::ClipEnd { clip_id, .. }
+            | AnimationIteratorEvent::ClipStart { clip_id, .. } => disabled_clips.contains(clip_id),
+            AnimationIteratorEvent::AnimationRepetitionEnd { .. } => false,
+        }
+    }
+
+    /// Produces the next frame, including the frames of disabled clips
+    fn next_frame(&mut self) -> Option<(IteratorFrame, AnimationProgress)> {
         // Retrieve the appropriate frame set from the cache
 
         let cached_frames = if let Some(frames_pong) = &self.cache.frames_pong {
@@ -178,6 +346,12 @@ impl Iterator for AnimationIterator {
                         &cached_frame.events,
                         current_frame_progress.repetition,
                     ),
+                    label: cached_frame.label,
+                    target: cached_frame.target,
+                    clip_tag: cached_frame.clip_tag,
+                    flip_x: cached_frame.flip_x,
+                    flip_y: cached_frame.flip_y,
+                    image: cached_frame.image.clone(),
                 };
 
                 // Inject the missing end events in the returned frame
@@ -187,11 +361,13 @@ impl Iterator for AnimationIterator {
                         .events
                         .push(AnimationIteratorEvent::ClipRepetitionEnd {
                             clip_id: previous_frame.clip_id,
+                            clip_tag: previous_frame.clip_tag,
                             clip_repetition: previous_frame.clip_repetition,
                         });
 
                     frame.events.push(AnimationIteratorEvent::ClipEnd {
                         clip_id: previous_frame.clip_id,
+                        clip_tag: previous_frame.clip_tag,
                     });
 
                     frame
@@ -205,6 +381,47 @@ impl Iterator for AnimationIterator {
                     self.repetition_just_ended = None;
                 }
 
+                // Merge the following skippable frames of the same clip repetition into this one
+
+                if self.skip_frames {
+                    while let Some(skipped_frame) = cached_frames
+                        .get(self.next_frame_progress.frame + 1)
+                        .filter(|next_frame| {
+                            next_frame.skippable
+                                && next_frame.clip_id == cached_frame.clip_id
+                                && next_frame.clip_repetition == cached_frame.clip_repetition
+                        })
+                    {
+                        frame.duration += skipped_frame.duration;
+
+                        frame.events.extend(Self::promote_events(
+                            &skipped_frame.events,
+                            current_frame_progress.repetition,
+                        ));
+
+                        self.next_frame_progress.frame += 1;
+                    }
+                }
+
+                // Drop the ClipStart events if they are disabled
+
+                if !self.clip_start_events {
+                    frame
+                        .events
+                        .retain(|event| !matches!(event, AnimationIteratorEvent::ClipStart { .. }));
+                }
+
+                // Randomize the duration if the clip has some jitter
+
+                if cached_frame.duration_jitter > 0.0 {
+                    let variation =
+                        Self::jitter_variation(self.jitter_seed, current_frame_progress);
+
+                    frame.duration = frame
+                        .duration
+                        .mul_f32(1.0 + cached_frame.duration_jitter * variation);
+                }
+
                 // Increment the indices for the next iteration
 
                 self.next_frame_progress.frame += 1;
@@ -0,0 +1,131 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    clip::ClipId, components::spritesheet_animation::AnimationProgress, events::AnimationMarkerId,
+};
+
+use super::{
+    cache::AnimationCache,
+    iterator::{AnimationIterator, AnimationIteratorEvent},
+};
+
+/// A frame of an animation sampled at an arbitrary time with [AnimationLibrary::sample_animation](crate::prelude::AnimationLibrary::sample_animation)
+/// or [AnimationLibrary::sample_animation_from](crate::prelude::AnimationLibrary::sample_animation_from).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SampledFrame {
+    /// The atlas index of the frame, as defined in its clip
+    pub atlas_index: usize,
+
+    /// The progress of the animation at this frame
+    pub progress: AnimationProgress,
+
+    /// The clip that contains the frame
+    pub clip_id: ClipId,
+
+    /// The repetition of the clip that contains the frame
+    pub clip_repetition: usize,
+
+    /// The markers reached during the sampled time range, in order
+    pub markers: Vec<AnimationMarkerId>,
+}
+
+/// A frame of an animation, as listed by [AnimationLibrary::animation_frames](crate::prelude::AnimationLibrary::animation_frames).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnimationFrame {
+    /// The clip that contains the frame
+    pub clip_id: ClipId,
+
+    /// The index of the frame within the repetition of the animation
+    pub frame_index: usize,
+
+    /// The atlas index of the frame, as defined in its clip
+    pub atlas_index: usize,
+
+    /// The duration of the frame, before any random variation
+    pub duration: Duration,
+}
+
+/// Lists the frames of the first repetition of an animation from its cache.
+pub(crate) fn frames(cache: Arc<AnimationCache>) -> impl Iterator<Item = AnimationFrame> {
+    (0..cache.frames.len()).map(move |frame_index| {
+        let frame = &cache.frames[frame_index];
+
+        AnimationFrame {
+            clip_id: frame.clip_id,
+            frame_index,
+            atlas_index: frame.atlas_index,
+            duration: frame.duration,
+        }
+    })
+}
+
+/// Samples an animation from its cache.
+pub(crate) fn sample(
+    cache: Arc<AnimationCache>,
+    previous_time: Option<Duration>,
+    time: Duration,
+) -> Option<SampledFrame> {
+    sample_iterator(AnimationIterator::new(cache), previous_time, time)
+}
+
+/// Samples an animation from its cache, some time after a given progress.
+pub(crate) fn sample_from(
+    cache: Arc<AnimationCache>,
+    progress: AnimationProgress,
+    elapsed: Duration,
+) -> Result<Option<SampledFrame>, ProgressError> {
+    let mut iterator = AnimationIterator::new(cache);
+
+    iterator.to(progress)?;
+
+    // The markers of the starting frame have already been reached
+
+    Ok(sample_iterator(iterator, Some(Duration::ZERO), elapsed))
+}
+
+// Samples the frames produced by an iterator.
+//
+// A frame is displayed from its start time (included) to its end time (excluded).
+// Its markers are reached at its start time.
+fn sample_iterator(
+    iterator: AnimationIterator,
+    previous_time: Option<Duration>,
+    time: Duration,
+) -> Option<SampledFrame> {
+    let mut sampled_frame: Option<SampledFrame> = None;
+    let mut markers = Vec::new();
+
+    let mut frame_start = Duration::ZERO;
+
+    for (frame, progress) in iterator {
+        if frame_start > time {
+            break;
+        }
+
+        let after_previous_time = match previous_time {
+            Some(previous_time) => frame_start > previous_time,
+            None => true,
+        };
+
+        if after_previous_time {
+            markers.extend(frame.events.iter().filter_map(|event| match event {
+                AnimationIteratorEvent::MarkerHit { marker_id, .. } => Some(*marker_id),
+                _ => None,
+            }));
+        }
+
+        frame_start += frame.duration;
+
+        sampled_frame = Some(SampledFrame {
+            atlas_index: frame.atlas_index,
+            progress,
+            clip_id: frame.clip_id,
+            clip_repetition: frame.clip_repetition,
+            markers: Vec::new(),
+        });
+    }
+
+    // Past the end of the animation, its last frame is held
+
+    sampled_frame.map(|frame| SampledFrame { markers, ..frame })
+}
@@ -0,0 +1,141 @@
+use std::time::Duration;
+
+use bevy::reflect::prelude::*;
+
+use crate::{clip::ClipId, events::AnimationMarkerId};
+
+use super::cache::{AnimationCache, AnimationCacheEvent};
+
+/// The timeline of a repetition of an animation, as returned by [AnimationLibrary::animation_timeline](crate::prelude::AnimationLibrary::animation_timeline).
+///
+/// The timeline lists the frames, clips and markers of the animation with their times,
+/// so that editors and tools can render timeline tracks without resolving the parameters of the animation and its clips themselves.
+/// The times are relative to the start of the repetition and do not include the random variations of the durations.
+///
+/// With [AnimationDirection::PingPong](crate::prelude::AnimationDirection::PingPong), this is the timeline of the first (forward) repetition.
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+#[reflect(Debug, Default, PartialEq)]
+pub struct AnimationTimeline {
+    /// The duration of the repetition
+    pub duration: Duration,
+
+    /// The frames, in play order
+    pub frames: Vec<TimelineFrame>,
+
+    /// The clips, in play order, with one entry per clip repetition
+    pub clips: Vec<TimelineClip>,
+
+    /// The markers, in play order
+    pub markers: Vec<TimelineMarker>,
+}
+
+/// A frame of an [AnimationTimeline].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct TimelineFrame {
+    /// The atlas index of the frame, as defined in its clip
+    pub atlas_index: usize,
+
+    /// The clip that contains the frame
+    pub clip_id: ClipId,
+
+    /// When the frame is displayed
+    pub start: Duration,
+
+    /// How long the frame is displayed
+    pub duration: Duration,
+}
+
+/// A clip repetition of an [AnimationTimeline].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct TimelineClip {
+    /// The clip
+    pub clip_id: ClipId,
+
+    /// The repetition of the clip
+    pub clip_repetition: usize,
+
+    /// The index of the first frame of the clip repetition in the timeline
+    pub first_frame: usize,
+
+    /// The number of frames of the clip repetition
+    pub frame_count: usize,
+
+    /// When the clip repetition starts
+    pub start: Duration,
+
+    /// When the clip repetition ends
+    pub end: Duration,
+}
+
+/// A marker of an [AnimationTimeline].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct TimelineMarker {
+    /// The marker
+    pub marker_id: AnimationMarkerId,
+
+    /// The clip that contains the marker
+    pub clip_id: ClipId,
+
+    /// The index of the frame of the marker in the timeline
+    pub frame: usize,
+
+    /// When the marker is hit
+    pub time: Duration,
+}
+
+/// Builds the timeline of the first repetition of an animation from its cache.
+pub(crate) fn timeline(cache: &AnimationCache) -> AnimationTimeline {
+    let mut timeline = AnimationTimeline::default();
+
+    for (frame_index, frame) in cache.frames.iter().enumerate() {
+        let start = timeline.duration;
+
+        timeline.frames.push(TimelineFrame {
+            atlas_index: frame.atlas_index,
+            clip_id: frame.clip_id,
+            start,
+            duration: frame.duration,
+        });
+
+        // Extend the current clip repetition or start a new one
+
+        match timeline.clips.last_mut() {
+            Some(clip)
+                if clip.clip_id == frame.clip_id
+                    && clip.clip_repetition == frame.clip_repetition =>
+            {
+                clip.frame_count += 1;
+                clip.end += frame.duration;
+            }
+            _ => timeline.clips.push(TimelineClip {
+                clip_id: frame.clip_id,
+                clip_repetition: frame.clip_repetition,
+                first_frame: frame_index,
+                frame_count: 1,
+                start,
+                end: start + frame.duration,
+            }),
+        }
+
+        timeline
+            .markers
+            .extend(frame.events.iter().filter_map(|event| match event {
+                AnimationCacheEvent::MarkerHit {
+                    marker_id, clip_id, ..
+                } => Some(TimelineMarker {
+                    marker_id: *marker_id,
+                    clip_id: *clip_id,
+                    frame: frame_index,
+                    time: start,
+                }),
+                _ => None,
+            }));
+
+        timeline.duration += frame.duration;
+    }
+
+    timeline
+}
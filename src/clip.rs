@@ -1,28 +1,252 @@
-use std::{collections::HashMap, fmt};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    sync::Arc,
+};
 
-use bevy::reflect::prelude::*;
+use bevy::{asset::Handle, image::Image, reflect::prelude::*, sprite::TextureAtlasLayout};
 
 use crate::{
     animation::{AnimationDirection, AnimationDuration},
+    components::clip_target::ClipTarget,
     easing::Easing,
     events::AnimationMarkerId,
+    hash::{hash_bytes, FNV_OFFSET_BASIS},
+    interned::InternedStr,
 };
 
 /// An opaque identifier that references a [Clip].
 ///
 /// Returned by [AnimationLibrary::register_clip](crate::prelude::AnimationLibrary::register_clip).
+///
+/// # Stability
+///
+/// IDs returned by [AnimationLibrary::register_clip](crate::prelude::AnimationLibrary::register_clip) are allocated sequentially, in registration order.
+/// Registering the same clips in the same order always yields the same IDs, but an ID changes if the clips are registered in a different order
+/// or if other clips are inserted before it.
+///
+/// Clips registered with [AnimationLibrary::register_named_clip](crate::prelude::AnimationLibrary::register_named_clip) get an ID derived from their name instead.
+/// It does not depend on the registration order nor on the content of the clip, so it survives reloads of the game data
+/// and can be compared with the [AnimationEvent](crate::prelude::AnimationEvent)s emitted before the reload.
+///
+/// ```
+/// # use bevy_spritesheet_animation::prelude::*;
+/// let mut library = AnimationLibrary::default();
+/// let walk_id = library.register_named_clip("walk", Clip::from_frames([1, 2, 3])).unwrap();
+///
+/// assert_eq!(walk_id, ClipId::from_name("walk"));
+///
+/// // Reloading the clip with different content in a different library yields the same ID
+///
+/// let mut reloaded_library = AnimationLibrary::default();
+/// reloaded_library.register_clip(Clip::from_frames([7, 8]));
+///
+/// assert_eq!(
+///     reloaded_library.register_named_clip("walk", Clip::from_frames([1, 2, 3, 4])).unwrap(),
+///     walk_id
+/// );
+/// ```
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Reflect)]
 #[reflect(Debug, PartialEq, Hash)]
 pub struct ClipId {
     pub(crate) value: usize,
 }
 
+impl ClipId {
+    /// Returns the stable ID of the clip registered with the given name by [AnimationLibrary::register_named_clip](crate::prelude::AnimationLibrary::register_named_clip).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - the name of the clip
+    pub fn from_name(name: impl AsRef<str>) -> Self {
+        // Hashed with FNV-1a so that the IDs stay the same across Rust releases
+
+        let hash = hash_bytes(FNV_OFFSET_BASIS, name.as_ref().as_bytes());
+
+        // The highest bit is reserved for named clips so that their IDs never collide with sequential ones
+
+        Self {
+            value: (hash as usize) | NAMED_CLIP_BIT,
+        }
+    }
+}
+
+/// Bit set in the IDs of the clips registered with a name
+const NAMED_CLIP_BIT: usize = !(usize::MAX >> 1);
+
 impl fmt::Display for ClipId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "clip{}", self.value)
     }
 }
 
+/// A tag that identifies a clip in the end events of its animations, see [Clip::with_tag].
+///
+/// Tags are interned: they can be created from any string, including strings loaded from files,
+/// and are as cheap to copy and compare as IDs.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_spritesheet_animation::prelude::*;
+/// let tag_name = String::from("attack_windup");
+///
+/// let tag = ClipTag::new(&tag_name);
+///
+/// assert_eq!(tag, ClipTag::new("attack_windup"));
+/// assert_eq!(tag, "attack_windup");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct ClipTag(InternedStr);
+
+impl ClipTag {
+    /// Returns the tag with the given name.
+    ///
+    /// The names are interned and never freed, so they should come from a bounded set (such as the tags defined by the game data)
+    /// rather than be generated at runtime.
+    pub fn new(name: impl AsRef<str>) -> Self {
+        Self(InternedStr::new(name.as_ref()))
+    }
+
+    /// Returns the name of the tag.
+    pub fn name(&self) -> Arc<str> {
+        self.0.get()
+    }
+}
+
+impl From<&str> for ClipTag {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<String> for ClipTag {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl PartialEq<str> for ClipTag {
+    fn eq(&self, other: &str) -> bool {
+        *self.name() == *other
+    }
+}
+
+impl PartialEq<&str> for ClipTag {
+    fn eq(&self, other: &&str) -> bool {
+        *self.name() == **other
+    }
+}
+
+impl fmt::Debug for ClipTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ClipTag").field(&self.name()).finish()
+    }
+}
+
+impl fmt::Display for ClipTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A debug label given to a frame of a clip, see [Clip::with_frame_label].
+///
+/// Like [ClipTag], labels are interned so that they can be created from any string and copied cheaply.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct FrameLabel(InternedStr);
+
+impl FrameLabel {
+    /// Returns the label with the given name.
+    ///
+    /// The names are interned and never freed, so they should come from a bounded set (such as the labels defined by the game data)
+    /// rather than be generated at runtime.
+    pub fn new(name: impl AsRef<str>) -> Self {
+        Self(InternedStr::new(name.as_ref()))
+    }
+
+    /// Returns the name of the label.
+    pub fn name(&self) -> Arc<str> {
+        self.0.get()
+    }
+}
+
+impl From<&str> for FrameLabel {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<String> for FrameLabel {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl PartialEq<str> for FrameLabel {
+    fn eq(&self, other: &str) -> bool {
+        *self.name() == *other
+    }
+}
+
+impl PartialEq<&str> for FrameLabel {
+    fn eq(&self, other: &&str) -> bool {
+        *self.name() == **other
+    }
+}
+
+impl fmt::Debug for FrameLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FrameLabel").field(&self.name()).finish()
+    }
+}
+
+impl fmt::Display for FrameLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The repetitions of an animation during which a marker is emitted, see [Clip::with_marker_repetitions].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub enum MarkerRepetitions {
+    /// Only during a specific repetition of the animation
+    Only(usize),
+    /// Every `period` repetitions of the animation, starting with the repetition `offset`
+    Every {
+        /// The number of repetitions between two emissions
+        period: usize,
+        /// The first repetition during which the marker is emitted
+        offset: usize,
+    },
+}
+
+impl MarkerRepetitions {
+    /// Returns true if a marker is emitted during a repetition of the animation.
+    pub fn contains(&self, animation_repetition: usize) -> bool {
+        match *self {
+            MarkerRepetitions::Only(repetition) => animation_repetition == repetition,
+            MarkerRepetitions::Every { period, offset } => {
+                animation_repetition >= offset
+                    && (animation_repetition - offset) % period.max(1) == 0
+            }
+        }
+    }
+}
+
+/// The spritesheet image of a clip and its atlas layout, see [Clip::with_image].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct ClipImage {
+    /// The image of the spritesheet containing the frames of the clip
+    pub image: Handle<Image>,
+    /// The atlas layout of this image
+    pub atlas_layout: Handle<TextureAtlasLayout>,
+}
+
 /// A [Clip] is a sequence of frames.
 ///
 /// It is the most basic building block for creating animations.
@@ -95,6 +319,36 @@ pub struct Clip {
 
     /// Markers that will generate [MarkerHit](crate::prelude::AnimationEvent::MarkerHit) events when played by an animation
     markers: HashMap<usize, Vec<AnimationMarkerId>>,
+
+    /// The repetitions of the animation during which some markers are emitted, the other markers are emitted during all of them
+    marker_repetitions: HashMap<AnimationMarkerId, MarkerRepetitions>,
+
+    /// Optional durations of specific frames in milliseconds, overriding the clip's duration
+    frame_durations: HashMap<usize, u32>,
+
+    /// Frames that can be dropped when [FrameSkipping](crate::prelude::FrameSkipping) is enabled
+    skippable_frames: HashSet<usize>,
+
+    /// Optional debug labels of specific frames, included in the diagnostics and the frame hooks
+    frame_labels: HashMap<usize, FrameLabel>,
+
+    /// Maximum random variation of the frame durations, as a fraction of their duration
+    duration_jitter: f32,
+
+    /// The optional child entity animated by this clip instead of the animated entity
+    target: Option<ClipTarget>,
+
+    /// An optional tag included in the end events of this clip
+    tag: Option<ClipTag>,
+
+    /// The optional horizontal flip applied to the sprite while this clip is playing
+    flip_x: Option<bool>,
+
+    /// The optional vertical flip applied to the sprite while this clip is playing
+    flip_y: Option<bool>,
+
+    /// The optional spritesheet image displayed while this clip is playing
+    image: Option<ClipImage>,
 }
 
 impl Clip {
@@ -125,6 +379,94 @@ impl Clip {
             direction: None,
             easing: None,
             markers: HashMap::new(),
+            marker_repetitions: HashMap::new(),
+            frame_durations: HashMap::new(),
+            skippable_frames: HashSet::new(),
+            frame_labels: HashMap::new(),
+            duration_jitter: 0.0,
+            target: None,
+            tag: None,
+            flip_x: None,
+            flip_y: None,
+            image: None,
+        }
+    }
+
+    /// Creates a new clip from frame indices, each one with its own duration in milliseconds.
+    ///
+    /// This is convenient to import frames exported by external tools with explicit durations.
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// let clip = Clip::from_timed_frames([(0, 80), (1, 120), (2, 300)]);
+    ///
+    /// assert_eq!(clip.frames(), vec![0, 1, 2]);
+    /// assert_eq!(clip.frame_durations().get(&2), Some(&300));
+    /// ```
+    ///
+    /// See [Clip::with_frame_duration] for how these durations combine with the other duration parameters.
+    pub fn from_timed_frames(timed_atlas_indices: impl IntoIterator<Item = (usize, u32)>) -> Self {
+        let (atlas_indices, durations): (Vec<_>, Vec<_>) = timed_atlas_indices.into_iter().unzip();
+
+        Self {
+            frame_durations: durations.into_iter().enumerate().collect(),
+            ..Self::from_frames(atlas_indices)
+        }
+    }
+
+    /// Returns a copy of this clip with its frames in reverse order.
+    ///
+    /// Unlike [AnimationDirection::Backwards], the frame-specific parameters (markers, durations, skippable frames, labels)
+    /// follow their frames so that the copy behaves like a new clip authored in reverse.
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let marker_id = library.new_marker();
+    ///
+    /// let clip = Clip::from_frames([1, 2, 3]).with_marker(marker_id, 0);
+    ///
+    /// let reversed_clip = clip.reversed();
+    ///
+    /// assert_eq!(reversed_clip.frames(), vec![3, 2, 1]);
+    /// assert_eq!(reversed_clip.markers().get(&2), Some(&vec![marker_id]));
+    /// ```
+    pub fn reversed(&self) -> Self {
+        let frame_count = self.atlas_indices.len();
+
+        // Maps a frame index to its index in the reversed clip,
+        // frame-specific parameters on out-of-range frames are dropped
+        let reverse_index = |frame_index: &usize| frame_count.checked_sub(frame_index + 1);
+
+        Self {
+            atlas_indices: self.atlas_indices.iter().rev().copied().collect(),
+            markers: self
+                .markers
+                .iter()
+                .filter_map(|(frame_index, markers)| {
+                    reverse_index(frame_index).map(|index| (index, markers.clone()))
+                })
+                .collect(),
+            frame_durations: self
+                .frame_durations
+                .iter()
+                .filter_map(|(frame_index, duration)| {
+                    reverse_index(frame_index).map(|index| (index, *duration))
+                })
+                .collect(),
+            skippable_frames: self
+                .skippable_frames
+                .iter()
+                .filter_map(reverse_index)
+                .collect(),
+            frame_labels: self
+                .frame_labels
+                .iter()
+                .filter_map(|(frame_index, label)| {
+                    reverse_index(frame_index).map(|index| (index, *label))
+                })
+                .collect(),
+            ..self.clone()
         }
     }
 
@@ -151,6 +493,151 @@ impl Clip {
         self
     }
 
+    pub fn marker_repetitions(&self) -> &HashMap<AnimationMarkerId, MarkerRepetitions> {
+        &self.marker_repetitions
+    }
+
+    /// Only emits a marker of this clip during some repetitions of the animation.
+    ///
+    /// This is convenient to vary sounds or effects every few loops without counting the repetitions yourself.
+    /// The repetitions are the ones of the [Animation](crate::prelude::Animation) playing the clip, not of the clip itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `marker_id` - the marker to restrict, on all the frames of the clip where it is set
+    /// * `repetitions` - the repetitions of the animation during which the marker is emitted
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let footstep = library.new_marker();
+    /// let footstep_variation = library.new_marker();
+    ///
+    /// // Play a variation of the footstep sound every 4 loops
+    ///
+    /// let clip = Clip::from_frames([0, 1, 2, 3])
+    ///     .with_marker(footstep, 1)
+    ///     .with_marker(footstep_variation, 3)
+    ///     .with_marker_repetitions(footstep_variation, MarkerRepetitions::Every { period: 4, offset: 3 });
+    /// ```
+    pub fn with_marker_repetitions(
+        &self,
+        marker_id: AnimationMarkerId,
+        repetitions: MarkerRepetitions,
+    ) -> Self {
+        let mut other = self.clone();
+        other.marker_repetitions.insert(marker_id, repetitions);
+        other
+    }
+
+    pub fn set_marker_repetitions(
+        &mut self,
+        marker_id: AnimationMarkerId,
+        repetitions: MarkerRepetitions,
+    ) -> &mut Self {
+        self.marker_repetitions.insert(marker_id, repetitions);
+        self
+    }
+
+    pub fn frame_durations(&self) -> &HashMap<usize, u32> {
+        &self.frame_durations
+    }
+
+    /// Sets the duration of a specific frame in milliseconds.
+    ///
+    /// This duration takes precedence over the clip's own duration, which only applies to the other frames.
+    ///
+    /// If the [Animation](crate::prelude::Animation) that plays the clip specifies a duration:
+    /// - `AnimationDuration::PerFrame` overrides the duration of all the frames, including this one
+    /// - `AnimationDuration::PerRepetition` scales this duration proportionally with the rest of the clip
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_index` - the index of the frame in the clip
+    /// * `duration_ms` - the duration of the frame in milliseconds
+    pub fn with_frame_duration(&self, frame_index: usize, duration_ms: u32) -> Self {
+        let mut other = self.clone();
+        other.frame_durations.insert(frame_index, duration_ms);
+        other
+    }
+
+    pub fn set_frame_duration(&mut self, frame_index: usize, duration_ms: u32) -> &mut Self {
+        self.frame_durations.insert(frame_index, duration_ms);
+        self
+    }
+
+    pub fn frame_labels(&self) -> &HashMap<usize, FrameLabel> {
+        &self.frame_labels
+    }
+
+    /// Labels a specific frame for debugging purposes.
+    ///
+    /// The label is included in the warnings about the frame and in the [FrameInfo](crate::prelude::FrameInfo) given to the frame hooks,
+    /// so that the artists and designers can tell which frame of their spritesheets is concerned.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_index` - the index of the frame in the clip
+    /// * `label` - the label of the frame
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// let clip = Clip::from_frames([4, 5, 6, 7])
+    ///     .with_frame_label(2, "windup-3")
+    ///     .with_frame_label(3, "strike");
+    ///
+    /// assert_eq!(clip.frame_labels().get(&2), Some(&FrameLabel::new("windup-3")));
+    /// ```
+    pub fn with_frame_label(&self, frame_index: usize, label: impl Into<FrameLabel>) -> Self {
+        let mut other = self.clone();
+        other.frame_labels.insert(frame_index, label.into());
+        other
+    }
+
+    pub fn set_frame_label(
+        &mut self,
+        frame_index: usize,
+        label: impl Into<FrameLabel>,
+    ) -> &mut Self {
+        self.frame_labels.insert(frame_index, label.into());
+        self
+    }
+
+    pub fn skippable_frames(&self) -> &HashSet<usize> {
+        &self.skippable_frames
+    }
+
+    /// Marks some frames as unimportant in-between frames.
+    ///
+    /// When [FrameSkipping](crate::prelude::FrameSkipping) is enabled, those frames are not displayed:
+    /// the previous frame of the same clip repetition stays on screen for their duration instead,
+    /// so that the overall duration of the animation does not change.
+    ///
+    /// Markers set on skipped frames are emitted along with the frame that replaces them.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_indices` - the indices of the frames in the clip
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// // Only the first and last frames will be displayed in low-spec mode
+    /// let clip = Clip::from_frames([0, 1, 2, 3, 4]).with_skippable_frames([1, 2, 3]);
+    /// ```
+    pub fn with_skippable_frames(&self, frame_indices: impl IntoIterator<Item = usize>) -> Self {
+        let mut other = self.clone();
+        other.skippable_frames.extend(frame_indices);
+        other
+    }
+
+    pub fn add_skippable_frames(
+        &mut self,
+        frame_indices: impl IntoIterator<Item = usize>,
+    ) -> &mut Self {
+        self.skippable_frames.extend(frame_indices);
+        self
+    }
+
     pub fn duration(&self) -> &Option<AnimationDuration> {
         &self.duration
     }
@@ -167,6 +654,187 @@ impl Clip {
         self
     }
 
+    pub fn duration_jitter(&self) -> f32 {
+        self.duration_jitter
+    }
+
+    /// Randomizes the duration of each frame slightly.
+    ///
+    /// Each entity gets its own variations so that many entities looping the same animation
+    /// do not look mechanically synchronized, even when started together.
+    /// The variations are deterministic for a given entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `jitter` - the maximum variation as a fraction of the frame durations, between 0 (no variation) and 1.
+    ///   For instance, 0.1 makes frames last between 90% and 110% of their duration.
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// let clip = Clip::from_frames([0, 1, 2, 3])
+    ///     .with_duration(AnimationDuration::PerFrame(100))
+    ///     .with_duration_jitter(0.1);
+    /// ```
+    pub fn with_duration_jitter(&self, jitter: f32) -> Self {
+        Self {
+            duration_jitter: jitter.clamp(0.0, 1.0),
+            ..self.clone()
+        }
+    }
+
+    pub fn set_duration_jitter(&mut self, jitter: f32) -> &mut Self {
+        self.duration_jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn target(&self) -> &Option<ClipTarget> {
+        &self.target
+    }
+
+    /// Makes this clip animate a child entity instead of the animated entity.
+    ///
+    /// See [ClipTarget] for details.
+    pub fn with_target(&self, target: ClipTarget) -> Self {
+        Self {
+            target: Some(target),
+            ..self.clone()
+        }
+    }
+
+    pub fn set_target(&mut self, target: ClipTarget) -> &mut Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn tag(&self) -> &Option<ClipTag> {
+        &self.tag
+    }
+
+    /// Tags this clip to identify it in the [ClipRepetitionEnd](crate::prelude::AnimationEvent::ClipRepetitionEnd)
+    /// and [ClipEnd](crate::prelude::AnimationEvent::ClipEnd) events.
+    ///
+    /// This lets you react to the phases of an animation by meaning rather than by [ClipId].
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// let windup = Clip::from_frames([0, 1, 2]).with_tag("attack_windup");
+    ///
+    /// assert_eq!(windup.tag(), &Some(ClipTag::new("attack_windup")));
+    /// ```
+    pub fn with_tag(&self, tag: impl Into<ClipTag>) -> Self {
+        Self {
+            tag: Some(tag.into()),
+            ..self.clone()
+        }
+    }
+
+    pub fn set_tag(&mut self, tag: impl Into<ClipTag>) -> &mut Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    pub fn flip_x(&self) -> &Option<bool> {
+        &self.flip_x
+    }
+
+    /// Flips the sprite horizontally while this clip is playing.
+    ///
+    /// This is convenient to play a right-facing clip to the left without a separate set of frames.
+    ///
+    /// The flip is applied to the [Sprite](bevy::sprite::Sprite), [Sprite3d](crate::prelude::Sprite3d) or [ImageNode](bevy::ui::widget::ImageNode) displaying the clip.
+    /// Clips without a flip leave the current flip of the sprite untouched.
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let walk_right = Clip::from_frames([0, 1, 2, 3]).with_flip_x(false);
+    /// let walk_left = walk_right.with_flip_x(true);
+    ///
+    /// let walk_left_id = library.register_clip(walk_left);
+    /// ```
+    pub fn with_flip_x(&self, flip_x: bool) -> Self {
+        Self {
+            flip_x: Some(flip_x),
+            ..self.clone()
+        }
+    }
+
+    pub fn set_flip_x(&mut self, flip_x: bool) -> &mut Self {
+        self.flip_x = Some(flip_x);
+        self
+    }
+
+    pub fn flip_y(&self) -> &Option<bool> {
+        &self.flip_y
+    }
+
+    /// Flips the sprite vertically while this clip is playing.
+    ///
+    /// See [Clip::with_flip_x] for details.
+    pub fn with_flip_y(&self, flip_y: bool) -> Self {
+        Self {
+            flip_y: Some(flip_y),
+            ..self.clone()
+        }
+    }
+
+    pub fn set_flip_y(&mut self, flip_y: bool) -> &mut Self {
+        self.flip_y = Some(flip_y);
+        self
+    }
+
+    pub fn image(&self) -> &Option<ClipImage> {
+        &self.image
+    }
+
+    /// Displays this clip from another spritesheet image than the one of the animated entity.
+    ///
+    /// This lets an animation span several images, for instance when the attack frames of a character live in a separate file from its locomotion frames.
+    /// The image and atlas layout are assigned to the [Sprite](bevy::sprite::Sprite), [Sprite3d](crate::prelude::Sprite3d) or [ImageNode](bevy::ui::widget::ImageNode) when the clip starts playing.
+    ///
+    /// Clips without an image leave the current image of the sprite untouched,
+    /// so all the clips of an animation spanning several images should specify theirs.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// # let (locomotion_image, attack_image) = (Handle::default(), Handle::default());
+    /// # let (locomotion_layout, attack_layout) = (Handle::default(), Handle::default());
+    /// let walk = Clip::from_frames([0, 1, 2, 3]).with_image(locomotion_image, locomotion_layout);
+    /// let attack = Clip::from_frames([0, 1, 2]).with_image(attack_image, attack_layout);
+    ///
+    /// let walk_id = library.register_clip(walk);
+    /// let attack_id = library.register_clip(attack);
+    ///
+    /// let animation = Animation::from_clips([walk_id, attack_id]);
+    /// ```
+    pub fn with_image(
+        &self,
+        image: Handle<Image>,
+        atlas_layout: Handle<TextureAtlasLayout>,
+    ) -> Self {
+        Self {
+            image: Some(ClipImage {
+                image,
+                atlas_layout,
+            }),
+            ..self.clone()
+        }
+    }
+
+    pub fn set_image(
+        &mut self,
+        image: Handle<Image>,
+        atlas_layout: Handle<TextureAtlasLayout>,
+    ) -> &mut Self {
+        self.image = Some(ClipImage {
+            image,
+            atlas_layout,
+        });
+        self
+    }
+
     pub fn repetitions(&self) -> &Option<usize> {
         &self.repetitions
     }
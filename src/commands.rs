@@ -0,0 +1,92 @@
+use bevy::ecs::{
+    system::{Commands, SystemParam},
+    world::World,
+};
+
+use crate::{
+    animation::{Animation, AnimationDuration, AnimationId},
+    clip::{Clip, ClipId},
+    library::{AnimationEditGuard, AnimationLibrary},
+};
+
+/// A Bevy system parameter to edit the [AnimationLibrary] without borrowing it.
+///
+/// The edits are deferred like regular [Commands]: they are applied when the commands of the system are applied,
+/// at the latest at the end of the schedule that runs the system.
+/// Edits queued from `Update` are visible to the animations played in `PostUpdate` of the same frame.
+///
+/// The caches of the edited animations are rebuilt automatically
+/// and the entities currently playing them continue from their current frame with the new parameters
+/// (see [AnimationLibrary::edit_clip] and [AnimationLibrary::edit_animation]).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// #[derive(Resource)]
+/// struct RunClip(ClipId);
+///
+/// fn speed_up_when_tired(
+///     mut animation_commands: AnimationCommands,
+///     run_clip: Res<RunClip>,
+///     keyboard: Res<ButtonInput<KeyCode>>,
+/// ) {
+///     if keyboard.just_pressed(KeyCode::ShiftLeft) {
+///         animation_commands.set_clip_duration(run_clip.0, AnimationDuration::PerFrame(50));
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct AnimationCommands<'w, 's> {
+    commands: Commands<'w, 's>,
+}
+
+impl AnimationCommands<'_, '_> {
+    /// Edits a clip registered in the library.
+    pub fn edit_clip(&mut self, clip_id: ClipId, edit: impl FnOnce(&mut Clip) + Send + 'static) {
+        self.commands.queue(move |world: &mut World| {
+            world
+                .resource_mut::<AnimationLibrary>()
+                .edit_clip(clip_id, edit);
+        });
+    }
+
+    /// Edits an animation registered in the library.
+    pub fn edit_animation(
+        &mut self,
+        animation_id: AnimationId,
+        edit: impl FnOnce(&mut Animation) + Send + 'static,
+    ) {
+        self.commands.queue(move |world: &mut World| {
+            world
+                .resource_mut::<AnimationLibrary>()
+                .edit_animation(animation_id, edit);
+        });
+    }
+
+    /// Applies a batch of edits to the library, rebuilding each edited animation once (see [AnimationLibrary::edit_batch]).
+    pub fn edit_batch(&mut self, edit: impl FnOnce(&mut AnimationEditGuard) + Send + 'static) {
+        self.commands.queue(move |world: &mut World| {
+            edit(&mut world.resource_mut::<AnimationLibrary>().edit_batch());
+        });
+    }
+
+    /// Sets the duration of a clip registered in the library.
+    pub fn set_clip_duration(&mut self, clip_id: ClipId, duration: AnimationDuration) {
+        self.edit_clip(clip_id, move |clip| {
+            clip.set_duration(duration);
+        });
+    }
+
+    /// Sets the duration of an animation registered in the library.
+    pub fn set_animation_duration(
+        &mut self,
+        animation_id: AnimationId,
+        duration: AnimationDuration,
+    ) {
+        self.edit_animation(animation_id, move |animation| {
+            animation.set_duration(duration);
+        });
+    }
+}
@@ -1,2 +1,26 @@
+#[cfg(feature = "animation_states")]
+pub mod animation_by_state;
+pub mod animation_event_filter;
+pub mod animation_overrides;
+pub mod animation_scrubber;
+pub mod animation_state_machine;
+pub mod clip_target;
+pub mod despawn_on_animation_end;
+pub mod flip_transition;
+pub mod frame_just_changed;
+pub mod frame_trail;
+pub mod hit_pause;
+pub mod image_node_frame_size;
+pub mod image_sequence;
+pub mod marker_listener;
+#[cfg(feature = "gamepad_rumble")]
+pub mod marker_rumble;
+pub mod no_animation_culling;
+pub mod persistent_animation_key;
+pub mod phase_from_position;
+pub mod play_variant;
+pub mod playback_history;
+pub mod shake_on_marker;
 pub mod sprite3d;
+pub mod sprite_rig;
 pub mod spritesheet_animation;
@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use bevy::{ecs::prelude::*, state::state::States};
+
+use crate::animation::AnimationId;
+
+/// A Bevy component that plays an animation depending on the value of a Bevy [State](bevy::state::state::State).
+///
+/// When the state changes, the entity switches to the animation associated to the new state value.
+/// Its animation is left as is for the state values without an animation.
+/// This replaces the `OnEnter` systems that only switch animations.
+///
+/// The entity plays the animation of the current state as soon as the component is added,
+/// so it does not need a [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) component beforehand.
+///
+/// # Note
+///
+/// The state type must be registered with [SpritesheetAnimationExt::register_animation_states](crate::prelude::SpritesheetAnimationExt::register_animation_states).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+/// enum Weather {
+///     #[default]
+///     Sunny,
+///     Stormy,
+/// }
+///
+/// # fn f(mut commands: Commands, calm_id: AnimationId, windy_id: AnimationId, image: Handle<Image>, atlas: TextureAtlas) {
+/// // A tree that sways harder during storms
+///
+/// commands.spawn((
+///     Sprite::from_atlas_image(image, atlas),
+///     AnimationByState::default()
+///         .with_animation(Weather::Sunny, calm_id)
+///         .with_animation(Weather::Stormy, windy_id),
+/// ));
+/// # }
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins(SpritesheetAnimationPlugin::default())
+///     .register_animation_states::<Weather>();
+/// ```
+#[derive(Component, Debug, Clone)]
+pub struct AnimationByState<S: States> {
+    /// The animations to play for each state value
+    pub animations: HashMap<S, AnimationId>,
+}
+
+impl<S: States> Default for AnimationByState<S> {
+    fn default() -> Self {
+        Self {
+            animations: HashMap::new(),
+        }
+    }
+}
+
+impl<S: States> AnimationByState<S> {
+    /// Plays an animation when the state has a given value.
+    pub fn with_animation(mut self, state: S, animation_id: AnimationId) -> Self {
+        self.animations.insert(state, animation_id);
+        self
+    }
+
+    /// Returns the animation to play for a state value, if any.
+    pub fn animation(&self, state: &S) -> Option<AnimationId> {
+        self.animations.get(state).copied()
+    }
+}
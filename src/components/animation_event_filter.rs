@@ -0,0 +1,188 @@
+use std::ops::{BitOr, BitOrAssign};
+
+use bevy::{
+    ecs::{prelude::*, system::SystemParam},
+    reflect::prelude::*,
+};
+
+use crate::events::{AnimationEvent, AnimationMarkerId};
+
+/// A set of kinds of [AnimationEvent]s, combined with `|`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_spritesheet_animation::prelude::*;
+/// let kinds = AnimationEventKinds::MARKER_HIT | AnimationEventKinds::ANIMATION_END;
+///
+/// assert!(kinds.contains(AnimationEventKinds::MARKER_HIT));
+/// assert!(!kinds.contains(AnimationEventKinds::CLIP_END));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct AnimationEventKinds(u8);
+
+impl AnimationEventKinds {
+    /// No events
+    pub const NONE: Self = Self(0);
+    /// [AnimationEvent::AnimationStarted]
+    pub const ANIMATION_STARTED: Self = Self(1 << 0);
+    /// [AnimationEvent::MarkerHit]
+    pub const MARKER_HIT: Self = Self(1 << 1);
+    /// [AnimationEvent::ClipRepetitionEnd]
+    pub const CLIP_REPETITION_END: Self = Self(1 << 2);
+    /// [AnimationEvent::ClipEnd]
+    pub const CLIP_END: Self = Self(1 << 3);
+    /// [AnimationEvent::AnimationRepetitionEnd]
+    pub const ANIMATION_REPETITION_END: Self = Self(1 << 4);
+    /// [AnimationEvent::AnimationEnd]
+    pub const ANIMATION_END: Self = Self(1 << 5);
+    /// [AnimationEvent::ClipStart]
+    pub const CLIP_START: Self = Self(1 << 6);
+    /// [AnimationEvent::EmptyAnimation]
+    pub const EMPTY_ANIMATION: Self = Self(1 << 7);
+    /// All the events
+    pub const ALL: Self = Self(u8::MAX);
+
+    /// Returns the kind of an event.
+    pub fn of(event: &AnimationEvent) -> Self {
+        match event {
+            AnimationEvent::AnimationStarted { .. } => Self::ANIMATION_STARTED,
+            AnimationEvent::MarkerHit { .. } => Self::MARKER_HIT,
+            AnimationEvent::ClipRepetitionEnd { .. } => Self::CLIP_REPETITION_END,
+            AnimationEvent::ClipEnd { .. } => Self::CLIP_END,
+            AnimationEvent::AnimationRepetitionEnd { .. } => Self::ANIMATION_REPETITION_END,
+            AnimationEvent::AnimationEnd { .. } => Self::ANIMATION_END,
+            AnimationEvent::ClipStart { .. } => Self::CLIP_START,
+            AnimationEvent::EmptyAnimation { .. } => Self::EMPTY_ANIMATION,
+        }
+    }
+
+    /// Returns whether all the kinds of `other` are in the set.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for AnimationEventKinds {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for AnimationEventKinds {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// A Bevy component that collects the [AnimationEvent]s of its entity that match a filter.
+///
+/// Instead of every system scanning the global stream of events, the plugin routes the matching events of the entity to a buffer,
+/// which can be read with the [FilteredAnimationEvents] system parameter.
+///
+/// The buffer contains the events of the last animation update: it is filled right after the [AnimationSystemSet](crate::plugin::AnimationSystemSet)
+/// and cleared on the next update.
+/// Systems that run after this set in the same schedule, or in a later schedule, read the events of the current frame.
+///
+/// # Note
+///
+/// Events are only reported if the entity emits events (see [AnimationEventSettings](crate::prelude::AnimationEventSettings)).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn spawn_bullet(_: Entity) {}
+/// # fn f(mut commands: Commands, animation_id: AnimationId, shoot_marker: AnimationMarkerId) {
+/// commands.spawn((
+///     SpritesheetAnimation::from_id(animation_id),
+///     AnimationEventFilter::new(AnimationEventKinds::MARKER_HIT).with_markers([shoot_marker]),
+/// ));
+/// # }
+///
+/// fn shoot(events: FilteredAnimationEvents) {
+///     for (entity, _event) in events.iter() {
+///         spawn_bullet(entity);
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct AnimationEventFilter {
+    /// The kinds of events to collect
+    pub kinds: AnimationEventKinds,
+
+    /// The markers to collect, if [AnimationEventKinds::MARKER_HIT] is in the kinds
+    ///
+    /// All the markers are collected if none.
+    pub markers: Option<Vec<AnimationMarkerId>>,
+
+    /// The events collected during the last update
+    #[reflect(ignore)]
+    pub(crate) events: Vec<AnimationEvent>,
+}
+
+impl AnimationEventFilter {
+    /// Creates a filter that collects some kinds of events.
+    pub fn new(kinds: AnimationEventKinds) -> Self {
+        Self {
+            kinds,
+            markers: None,
+            events: Vec::new(),
+        }
+    }
+
+    /// Restricts the collected [AnimationEvent::MarkerHit] events to some markers.
+    pub fn with_markers(mut self, markers: impl IntoIterator<Item = AnimationMarkerId>) -> Self {
+        self.markers = Some(markers.into_iter().collect());
+        self
+    }
+
+    /// Returns whether an event passes the filter.
+    pub fn matches(&self, event: &AnimationEvent) -> bool {
+        if !self.kinds.contains(AnimationEventKinds::of(event)) {
+            return false;
+        }
+
+        match (event, &self.markers) {
+            (AnimationEvent::MarkerHit { marker_id, .. }, Some(markers)) => {
+                markers.contains(marker_id)
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns the events collected during the last update.
+    pub fn events(&self) -> &[AnimationEvent] {
+        &self.events
+    }
+}
+
+/// A Bevy system parameter to read the events collected by the [AnimationEventFilter]s.
+#[derive(SystemParam)]
+pub struct FilteredAnimationEvents<'w, 's> {
+    filters: Query<'w, 's, (Entity, &'static AnimationEventFilter)>,
+}
+
+impl FilteredAnimationEvents<'_, '_> {
+    /// Returns the events collected for an entity during the last update.
+    ///
+    /// Returns an empty slice if the entity does not have an [AnimationEventFilter].
+    pub fn read(&self, entity: Entity) -> &[AnimationEvent] {
+        self.filters
+            .get(entity)
+            .map(|(_, filter)| filter.events())
+            .unwrap_or_default()
+    }
+
+    /// Returns the events collected for all the entities during the last update.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &AnimationEvent)> {
+        self.filters
+            .iter()
+            .flat_map(|(entity, filter)| filter.events().iter().map(move |event| (entity, event)))
+    }
+}
@@ -0,0 +1,88 @@
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+use crate::animation::{Animation, AnimationDirection, AnimationDuration, AnimationRepeat};
+
+/// A Bevy component that overrides some parameters of the animation played by its entity.
+///
+/// This tweaks the playback of a single entity without registering a modified copy of the [Animation] in the library,
+/// for instance to make one enemy attack faster than the others.
+/// The overrides replace the parameters of the animation, as if they had been set with [Animation::with_duration], [Animation::with_repetitions] and [Animation::with_direction].
+///
+/// The overrides apply to all the animations played by the entity and can be changed at any time:
+/// the playback then continues from the current frame with the new parameters.
+///
+/// # Note
+///
+/// The entities that play the same animation with the same overrides share the same pre-computed frames.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(mut commands: Commands, attack_id: AnimationId, image: Handle<Image>, atlas: TextureAtlas) {
+/// // An elite enemy that attacks faster than the regular ones
+///
+/// commands.spawn((
+///     Sprite::from_atlas_image(image, atlas),
+///     SpritesheetAnimation::from_id(attack_id),
+///     AnimationOverrides::default().with_duration(AnimationDuration::PerRepetition(400)),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Debug, Default, PartialEq, Hash)]
+pub struct AnimationOverrides {
+    /// Replaces the duration of the animation, if specified
+    pub duration: Option<AnimationDuration>,
+
+    /// Replaces the repetitions of the animation, if specified
+    pub repetitions: Option<AnimationRepeat>,
+
+    /// Replaces the direction of the animation, if specified
+    pub direction: Option<AnimationDirection>,
+}
+
+impl AnimationOverrides {
+    /// Overrides the duration of the animation.
+    pub fn with_duration(mut self, duration: AnimationDuration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Overrides the repetitions of the animation.
+    pub fn with_repetitions(mut self, repetitions: AnimationRepeat) -> Self {
+        self.repetitions = Some(repetitions);
+        self
+    }
+
+    /// Overrides the direction of the animation.
+    pub fn with_direction(mut self, direction: AnimationDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Whether no parameter is overridden
+    pub(crate) fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns a copy of an animation with the overridden parameters
+    pub(crate) fn apply(&self, animation: &Animation) -> Animation {
+        let mut animation = animation.clone();
+
+        if let Some(duration) = self.duration {
+            animation.set_duration(duration);
+        }
+
+        if let Some(repetitions) = self.repetitions {
+            animation.set_repetitions(repetitions);
+        }
+
+        if let Some(direction) = self.direction {
+            animation.set_direction(direction);
+        }
+
+        animation
+    }
+}
@@ -0,0 +1,104 @@
+use bevy::{
+    color::Color,
+    ecs::prelude::*,
+    reflect::prelude::*,
+    ui::{Interaction, Node, RelativeCursorPosition},
+};
+
+use crate::animation::AnimationId;
+
+/// A Bevy UI component that displays the progress of an animated entity and lets users seek within its animation.
+///
+/// The scrubber shows the current frame of the target's [SpritesheetAnimation](crate::prelude::SpritesheetAnimation)
+/// along with the boundaries of its clips and the positions of its markers.
+/// Clicking or dragging over the scrubber moves the animation to the frame under the cursor.
+///
+/// This is mostly useful for debugging animations or building animation tools.
+///
+/// # Note
+///
+/// The scrubber is a regular UI [Node] that you must give a size to.
+/// Its children are managed by the library and rebuilt whenever the target switches to a new animation.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(mut commands: Commands, animation_id: AnimationId, image: Handle<Image>, atlas: TextureAtlas) {
+/// let character = commands
+///     .spawn((
+///         Sprite::from_atlas_image(image, atlas),
+///         SpritesheetAnimation::from_id(animation_id),
+///     ))
+///     .id();
+///
+/// commands.spawn((
+///     AnimationScrubber::new(character),
+///     Node {
+///         width: Val::Px(400.0),
+///         height: Val::Px(20.0),
+///         ..default()
+///     },
+///     BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Reflect)]
+#[require(Node, Interaction, RelativeCursorPosition)]
+#[reflect(Component, Debug)]
+pub struct AnimationScrubber {
+    /// The entity whose animation is controlled by the scrubber
+    pub target: Entity,
+
+    /// The color of the current frame
+    pub frame_color: Color,
+
+    /// The color of the clip boundaries
+    pub clip_color: Color,
+
+    /// The color of the markers
+    pub marker_color: Color,
+
+    /// The animation currently displayed and its number of frames
+    pub(crate) displayed_animation: Option<(AnimationId, usize)>,
+}
+
+impl AnimationScrubber {
+    /// Creates a scrubber for an animated entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - the entity with a [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) component
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            frame_color: Color::WHITE,
+            clip_color: Color::srgb(0.5, 0.5, 0.5),
+            marker_color: Color::srgb(1.0, 0.8, 0.0),
+            displayed_animation: None,
+        }
+    }
+
+    /// Returns the number of frames of the animation displayed by the scrubber.
+    ///
+    /// Returns None if the target is not animated yet.
+    pub fn frame_count(&self) -> Option<usize> {
+        self.displayed_animation.map(|(_, frame_count)| frame_count)
+    }
+}
+
+/// Marks the child of an [AnimationScrubber] that shows the current frame
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component, Debug)]
+pub struct ScrubberFrame;
+
+/// Marks the children of an [AnimationScrubber] that show the boundaries of clips
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component, Debug)]
+pub struct ScrubberClipBoundary;
+
+/// Marks the children of an [AnimationScrubber] that show markers
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component, Debug)]
+pub struct ScrubberMarker;
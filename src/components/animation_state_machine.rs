@@ -0,0 +1,182 @@
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+use crate::{animation::AnimationId, events::AnimationMarkerId};
+
+/// A Bevy component that switches the animation of an entity automatically, following transitions between states.
+///
+/// Each state is an animation.
+/// The state machine switches the [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) of its entity
+/// from one state to another when the [condition](TransitionCondition) of a transition is met,
+/// and emits an [AnimationStateChanged] event.
+///
+/// The state machine is updated after the animations, so the new animations start playing on the next update.
+/// At most one transition happens per update: the triggers are considered first, then the events of the current animation.
+///
+/// The entity should start with the animation of the initial state
+/// (otherwise, it switches to it on the first update of the state machine).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(
+/// #     mut commands: Commands,
+/// #     idle: AnimationId,
+/// #     attack: AnimationId,
+/// #     image: Handle<Image>,
+/// #     atlas: TextureAtlas
+/// # ) {
+/// // Attack when asked to and return to idle when done
+///
+/// let state_machine = AnimationStateMachine::new(idle)
+///     .with_transition(idle, attack, TransitionCondition::Trigger("attack"))
+///     .with_transition(attack, idle, TransitionCondition::AnimationEnd);
+///
+/// commands.spawn((
+///     Sprite::from_atlas_image(image, atlas),
+///     SpritesheetAnimation::from_id(idle),
+///     state_machine,
+/// ));
+/// # }
+///
+/// // Later, from a gameplay system
+///
+/// fn attack(mut state_machines: Query<&mut AnimationStateMachine>) {
+///     for mut state_machine in &mut state_machines {
+///         state_machine.trigger("attack");
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug)]
+pub struct AnimationStateMachine {
+    /// The current state
+    state: AnimationId,
+
+    /// The transitions between the states, in order of priority
+    transitions: Vec<AnimationTransition>,
+
+    /// The triggers to consider on the next update
+    pending_triggers: Vec<&'static str>,
+}
+
+/// A transition between two states of an [AnimationStateMachine].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct AnimationTransition {
+    /// The state to transition from
+    pub from: AnimationId,
+
+    /// The state to transition to
+    pub to: AnimationId,
+
+    /// When to transition
+    pub condition: TransitionCondition,
+}
+
+/// When to follow a transition of an [AnimationStateMachine].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub enum TransitionCondition {
+    /// When the animation of the current state ends
+    AnimationEnd,
+
+    /// When the animation of the current state hits a marker
+    Marker(AnimationMarkerId),
+
+    /// When triggered with [AnimationStateMachine::trigger]
+    Trigger(&'static str),
+}
+
+/// A Bevy event emitted when an [AnimationStateMachine] switches states.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnimationStateChanged {
+    /// The entity of the state machine
+    pub entity: Entity,
+
+    /// The previous state
+    pub from: AnimationId,
+
+    /// The new state
+    pub to: AnimationId,
+}
+
+impl AnimationStateMachine {
+    /// Creates a new state machine starting in the given state.
+    pub fn new(initial_state: AnimationId) -> Self {
+        Self {
+            state: initial_state,
+            transitions: Vec::new(),
+            pending_triggers: Vec::new(),
+        }
+    }
+
+    /// Returns the current state.
+    pub fn state(&self) -> AnimationId {
+        self.state
+    }
+
+    /// Returns the transitions between the states, in order of priority.
+    pub fn transitions(&self) -> &[AnimationTransition] {
+        &self.transitions
+    }
+
+    pub fn with_transition(
+        mut self,
+        from: AnimationId,
+        to: AnimationId,
+        condition: TransitionCondition,
+    ) -> Self {
+        self.add_transition(from, to, condition);
+        self
+    }
+
+    /// Adds a transition between two states.
+    ///
+    /// If several transitions of the current state match, the first added one is followed.
+    pub fn add_transition(
+        &mut self,
+        from: AnimationId,
+        to: AnimationId,
+        condition: TransitionCondition,
+    ) -> &mut Self {
+        self.transitions.push(AnimationTransition {
+            from,
+            to,
+            condition,
+        });
+        self
+    }
+
+    /// Triggers the transitions of the current state with a [TransitionCondition::Trigger] condition.
+    ///
+    /// The triggers are considered on the next update of the state machine and dropped afterwards,
+    /// even if they do not match any transition.
+    pub fn trigger(&mut self, trigger: &'static str) {
+        self.pending_triggers.push(trigger);
+    }
+
+    /// Whether some triggers are pending
+    pub(crate) fn has_pending_triggers(&self) -> bool {
+        !self.pending_triggers.is_empty()
+    }
+
+    /// Takes the pending triggers
+    pub(crate) fn take_triggers(&mut self) -> Vec<&'static str> {
+        std::mem::take(&mut self.pending_triggers)
+    }
+
+    /// Returns the state to transition to from the current state for a condition, if any
+    pub(crate) fn next_state(&self, condition: TransitionCondition) -> Option<AnimationId> {
+        self.transitions
+            .iter()
+            .find(|transition| transition.from == self.state && transition.condition == condition)
+            .map(|transition| transition.to)
+    }
+
+    /// Moves to a new state
+    pub(crate) fn set_state(&mut self, state: AnimationId) {
+        self.state = state;
+    }
+}
@@ -0,0 +1,46 @@
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+/// A Bevy component that marks a child entity as an alternative target for the clips of its parent's animation.
+///
+/// Clips declared with [Clip::with_target](crate::prelude::Clip::with_target) animate the child entity
+/// with the same [ClipTarget] instead of the entity that holds the [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) component.
+/// Meanwhile, the other targets keep their current frame.
+///
+/// This allows authoring composite effects as a single timeline, for instance a character animation followed by an overlay effect.
+///
+/// # Note
+///
+/// Target entities must have a sprite with a texture atlas, like regular animated entities, but no [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) component.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// const OVERLAY: ClipTarget = ClipTarget(1);
+///
+/// fn spawn_character(
+///     mut commands: Commands,
+///     mut library: ResMut<AnimationLibrary>,
+///     # image: Handle<Image>,
+///     # atlas: TextureAtlas,
+/// ) {
+///     let attack_clip_id = library.register_clip(Clip::from_frames([0, 1, 2]));
+///
+///     let sparks_clip_id = library.register_clip(Clip::from_frames([8, 9, 10]).with_target(OVERLAY));
+///
+///     let animation_id = library.register_animation(Animation::from_clips([attack_clip_id, sparks_clip_id]));
+///
+///     // ... omitted: load an image and create a texture atlas for the sprites ...
+///
+///     commands
+///         .spawn((
+///             Sprite::from_atlas_image(image.clone(), atlas.clone()),
+///             SpritesheetAnimation::from_id(animation_id),
+///         ))
+///         .with_child((Sprite::from_atlas_image(image, atlas), OVERLAY));
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Debug, PartialEq, Hash)]
+pub struct ClipTarget(pub u32);
@@ -0,0 +1,33 @@
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+/// A Bevy component that cleans up an entity when its animation ends.
+///
+/// This is convenient for one-shot effects like explosions or impacts, which only exist for the duration of their animation.
+///
+/// The cleanup happens when an [AnimationEvent::AnimationEnd](crate::prelude::AnimationEvent::AnimationEnd) event is emitted for the entity,
+/// so animations that repeat forever never trigger it.
+/// It is skipped if the entity switches to another animation at the same time,
+/// for instance to a [queued animation](crate::prelude::SpritesheetAnimation::queue) or with an [AnimationStateMachine](crate::prelude::AnimationStateMachine).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(mut commands: Commands, explosion_id: AnimationId, image: Handle<Image>, atlas: TextureAtlas) {
+/// commands.spawn((
+///     Sprite::from_atlas_image(image, atlas),
+///     SpritesheetAnimation::from_id(explosion_id),
+///     DespawnOnAnimationEnd::Despawn,
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub enum DespawnOnAnimationEnd {
+    /// Despawn the entity along with its descendants
+    #[default]
+    Despawn,
+    /// Only remove the [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) component, leaving the last frame displayed
+    RemoveAnimation,
+}
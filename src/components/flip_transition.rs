@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+use crate::animation::AnimationId;
+
+/// A Bevy component that makes sprites turn around smoothly instead of mirroring instantly.
+///
+/// Set `flip_x` to the direction that the sprite should face: the animator flips the [Sprite](bevy::sprite::Sprite),
+/// [Sprite3d](crate::prelude::Sprite3d) or [ImageNode](bevy::ui::widget::ImageNode) of the entity following the [FlipTransitionStyle].
+///
+/// The direction of a newly animated entity is applied immediately, without any transition.
+/// The flips of the clips (see [Clip::with_flip_x](crate::prelude::Clip::with_flip_x)) should not be used along with this component.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn face_movement(mut characters: Query<(&Transform, &mut FlipTransition)>) {
+///     for (transform, mut flip_transition) in &mut characters {
+///         # let velocity_x = transform.translation.x;
+///         // Face left when moving left
+///
+///         if velocity_x != 0.0 {
+///             flip_transition.flip_x = velocity_x < 0.0;
+///         }
+///     }
+/// }
+///
+/// # fn f(mut commands: Commands, animation_id: AnimationId) {
+/// commands.spawn((
+///     SpritesheetAnimation::from_id(animation_id),
+///     FlipTransition::new(FlipTransitionStyle::Hold(Duration::from_millis(60))),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct FlipTransition {
+    /// Whether the sprite should be flipped horizontally
+    pub flip_x: bool,
+
+    /// How the sprite turns around when `flip_x` changes
+    pub style: FlipTransitionStyle,
+}
+
+impl FlipTransition {
+    /// Creates a flip transition for a sprite that is not flipped.
+    pub fn new(style: FlipTransitionStyle) -> Self {
+        Self {
+            flip_x: false,
+            style,
+        }
+    }
+
+    /// Sets the initial direction of the sprite.
+    pub fn with_flip_x(mut self, flip_x: bool) -> Self {
+        self.flip_x = flip_x;
+        self
+    }
+}
+
+/// How a sprite with a [FlipTransition] turns around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub enum FlipTransitionStyle {
+    /// Hold the current frame for some time before flipping the sprite
+    ///
+    /// The hold elapses in real time, independently of the [speed factor](crate::prelude::SpritesheetAnimation::speed_factor) of the animation.
+    Hold(Duration),
+
+    /// Play a turn animation before flipping the sprite, then resume the interrupted animation from the frame where it was interrupted
+    ///
+    /// The turn animation should end, for instance with [AnimationRepeat::Times(1)](crate::prelude::AnimationRepeat::Times).
+    TurnAnimation(AnimationId),
+}
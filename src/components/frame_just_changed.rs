@@ -0,0 +1,28 @@
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+/// A marker component present on animated entities whose atlas index changed during the current frame.
+///
+/// The animator inserts it when an entity displays a new atlas index and removes it on the next update,
+/// so expensive systems that depend on the displayed frame (outline generation, collider updates, ...)
+/// can skip the entities that did not change with `With<FrameJustChanged>`.
+///
+/// Consecutive frames that use the same atlas index do not count as a change.
+/// Frames displayed by child entities with a [ClipTarget](crate::prelude::ClipTarget) are not tracked either.
+///
+/// The markers are only maintained when [SpritesheetAnimationPlugin::frame_change_markers](crate::prelude::SpritesheetAnimationPlugin::frame_change_markers) is enabled,
+/// and they are visible to the systems that run after [AnimationSystemSet](crate::plugin::AnimationSystemSet).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn update_colliders(query: Query<&Sprite, With<FrameJustChanged>>) {
+///     for sprite in &query {
+///         // Only the sprites that display a new frame
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct FrameJustChanged;
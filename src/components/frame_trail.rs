@@ -0,0 +1,84 @@
+use std::collections::VecDeque;
+
+use bevy::{ecs::prelude::*, reflect::prelude::*, transform::components::Transform};
+
+/// A frame displayed by an animation, sampled in a [FrameTrail].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct FrameTrailSample {
+    /// The atlas index that was assigned to the entity
+    pub atlas_index: usize,
+
+    /// The transform of the entity when the frame was displayed
+    pub transform: Transform,
+}
+
+/// A Bevy component that keeps the last frames displayed by the animation of an entity, along with where they were displayed.
+///
+/// This enables after-image and motion-trail effects, which render the recent frames of an animation at their previous positions.
+///
+/// A new sample is taken every time the entity displays a new frame.
+/// Frames that animate child entities with a [ClipTarget](crate::prelude::ClipTarget) are not sampled.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(mut commands: Commands, animation_id: AnimationId, image: Handle<Image>, atlas: TextureAtlas) {
+/// commands.spawn((
+///     Sprite::from_atlas_image(image, atlas),
+///     SpritesheetAnimation::from_id(animation_id),
+///     FrameTrail::new(4),
+/// ));
+/// # }
+///
+/// // Draw after-images with the samples
+///
+/// fn draw_after_images(query: Query<&FrameTrail>) {
+///     for trail in &query {
+///         for sample in trail.samples() {
+///             // ... display sample.atlas_index at sample.transform ...
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug)]
+pub struct FrameTrail {
+    /// The maximum number of samples to keep
+    pub length: usize,
+
+    /// The samples, oldest first
+    samples: VecDeque<FrameTrailSample>,
+}
+
+impl FrameTrail {
+    /// Creates an empty trail that keeps the given number of samples.
+    pub fn new(length: usize) -> Self {
+        Self {
+            length,
+            samples: VecDeque::with_capacity(length),
+        }
+    }
+
+    /// Returns the samples, oldest first.
+    pub fn samples(&self) -> impl Iterator<Item = &FrameTrailSample> {
+        self.samples.iter()
+    }
+
+    /// Clears the trail.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    pub(crate) fn sample(&mut self, sample: FrameTrailSample) {
+        self.samples.push_back(sample);
+
+        // Discard the oldest samples
+
+        while self.samples.len() > self.length {
+            self.samples.pop_front();
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+use crate::events::AnimationMarkerId;
+
+/// A Bevy component that briefly freezes animations when the animation of its entity hits a marker, for impact "hit-stop" effects.
+///
+/// The pause starts exactly at the frame of the marker: the frame is held for the duration of the pause and the playback continues as if the pause had been inserted at that frame, regardless of the framerate.
+/// The pause elapses in real time, independently of the [speed factor](crate::prelude::SpritesheetAnimation::speed_factor) of the animations.
+///
+/// The pause does not depend on the marker events: it also applies to entities that do not emit them
+/// (see [AnimationEventSettings](crate::prelude::AnimationEventSettings)).
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(mut commands: Commands, mut library: ResMut<AnimationLibrary>, image: Handle<Image>, atlas: TextureAtlas) {
+/// let impact_marker = library.new_marker();
+///
+/// let clip = Clip::from_frames([0, 1, 2, 3]).with_marker(impact_marker, 2);
+/// let clip_id = library.register_clip(clip);
+///
+/// let animation_id = library.register_animation(Animation::from_clip(clip_id));
+///
+/// // Freeze the attacker and its target for 80ms when the punch connects
+///
+/// commands.spawn((
+///     Sprite::from_atlas_image(image.clone(), atlas.clone()),
+///     SpritesheetAnimation::from_id(animation_id),
+///     HitPauseOnMarker::new(impact_marker, Duration::from_millis(80))
+///         .with_scope(HitPauseScope::Group(1)),
+///     HitPauseGroup(1),
+/// ));
+///
+/// # let idle_id = animation_id;
+/// commands.spawn((
+///     Sprite::from_atlas_image(image, atlas),
+///     SpritesheetAnimation::from_id(idle_id),
+///     HitPauseGroup(1),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct HitPauseOnMarker {
+    /// The marker that triggers the pause
+    pub marker: AnimationMarkerId,
+
+    /// How long the animations are paused
+    pub duration: Duration,
+
+    /// Which animations are paused
+    pub scope: HitPauseScope,
+}
+
+impl HitPauseOnMarker {
+    /// Creates a hit-pause that only pauses the animation of its entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `marker` - the marker that triggers the pause
+    /// * `duration` - how long the animation is paused
+    pub fn new(marker: AnimationMarkerId, duration: Duration) -> Self {
+        Self {
+            marker,
+            duration,
+            scope: HitPauseScope::Entity,
+        }
+    }
+
+    /// Sets which animations are paused.
+    pub fn with_scope(mut self, scope: HitPauseScope) -> Self {
+        self.scope = scope;
+        self
+    }
+}
+
+/// The animations paused by a [HitPauseOnMarker].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub enum HitPauseScope {
+    /// Only the animation of the entity that hits the marker
+    #[default]
+    Entity,
+    /// The animation of the entity that hits the marker and the animations of all the entities in a [HitPauseGroup]
+    ///
+    /// The other entities of the group are paused from the update following the one in which the marker is hit,
+    /// since they may already have been played during that update.
+    Group(u32),
+}
+
+/// A Bevy component that adds an entity to a group of animations paused together by a [HitPauseOnMarker] with a [HitPauseScope::Group] scope.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Debug, PartialEq, Hash)]
+pub struct HitPauseGroup(pub u32);
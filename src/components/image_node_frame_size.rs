@@ -0,0 +1,58 @@
+use bevy::{ecs::prelude::*, math::Vec2, reflect::prelude::*};
+
+/// A Bevy component that resizes an animated UI [ImageNode](bevy::prelude::ImageNode) to the size of its current frame.
+///
+/// By default, UI images keep the size of their [Node](bevy::prelude::Node), which stretches the frames of trimmed atlases whose frames have different sizes.
+/// With this component, the width and height of the node are updated every time the frame changes so that the layout reflows correctly.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(mut commands: Commands, animation_id: AnimationId, image: Handle<Image>, atlas: TextureAtlas) {
+/// commands.spawn((
+///     ImageNode::from_atlas_image(image, atlas),
+///     SpritesheetAnimation::from_id(animation_id),
+///     // Display the frames at twice their size in the atlas
+///     ImageNodeFrameSize::from_scale(2.0),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct ImageNodeFrameSize {
+    /// A scale applied to the size of the frames, in pixels, defaults to 1
+    pub scale: Vec2,
+
+    /// Rounds the scale so that each pixel of the frames covers a whole number of physical pixels, defaults to false
+    ///
+    /// Without this, pixel-art frames look blurry when the UI scale factor (from the window or [UiScale](bevy::prelude::UiScale)) is fractional.
+    /// The nodes are resized again when the scale factor changes.
+    pub pixel_perfect: bool,
+}
+
+impl ImageNodeFrameSize {
+    /// Resizes the node to the size of its frames multiplied by a uniform scale.
+    pub fn from_scale(scale: f32) -> Self {
+        Self {
+            scale: Vec2::splat(scale),
+            ..Default::default()
+        }
+    }
+
+    /// Rounds the scale to whole physical pixels, see [ImageNodeFrameSize::pixel_perfect].
+    pub fn with_pixel_perfect(mut self) -> Self {
+        self.pixel_perfect = true;
+        self
+    }
+}
+
+impl Default for ImageNodeFrameSize {
+    fn default() -> Self {
+        Self {
+            scale: Vec2::ONE,
+            pixel_perfect: false,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use bevy::{asset::LoadedFolder, ecs::prelude::*, prelude::*, reflect::prelude::*};
+
+use crate::animation::{AnimationDuration, AnimationRepeat};
+
+/// A Bevy component that animates its entity with a folder of individual frame images, like `frame_001.png`, `frame_002.png`, ...
+///
+/// Once the folder and its images are loaded, the images are stitched into a texture atlas
+/// and the entity gets a [Sprite] and a [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) that plays the frames in the order of their file names.
+/// The file names should therefore be zero-padded so that `frame_002.png` comes before `frame_010.png`.
+///
+/// This is handy to prototype with frames exported individually, before packing them into a spritesheet.
+///
+/// # Note
+///
+/// Loading folders is not supported on the web.
+/// The files of the folder that are not images are ignored.
+/// If the atlas cannot be built (for instance because the frames do not fit in it), a warning is reported and the component is removed.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn spawn_explosion(mut commands: Commands, assets: Res<AssetServer>) {
+///     commands.spawn(
+///         ImageSequence::new(assets.load_folder("explosion"))
+///             .with_duration(AnimationDuration::PerFrame(80))
+///             .with_repetitions(AnimationRepeat::Times(1)),
+///     );
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Reflect)]
+#[require(Transform, Visibility)]
+#[reflect(Component, Debug)]
+pub struct ImageSequence {
+    /// The folder that contains the frame images
+    pub folder: Handle<LoadedFolder>,
+
+    /// The duration of the animation, if not the default one of [Clip](crate::prelude::Clip)
+    pub duration: Option<AnimationDuration>,
+
+    /// The repetitions of the animation, if not the default ones of [Animation](crate::prelude::Animation)
+    pub repetitions: Option<AnimationRepeat>,
+}
+
+impl ImageSequence {
+    /// Creates an image sequence from a folder loaded with [AssetServer::load_folder].
+    pub fn new(folder: Handle<LoadedFolder>) -> Self {
+        Self {
+            folder,
+            duration: None,
+            repetitions: None,
+        }
+    }
+
+    /// Sets the duration of the animation.
+    pub fn with_duration(mut self, duration: AnimationDuration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets the repetitions of the animation.
+    pub fn with_repetitions(mut self, repetitions: AnimationRepeat) -> Self {
+        self.repetitions = Some(repetitions);
+        self
+    }
+}
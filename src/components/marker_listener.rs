@@ -0,0 +1,23 @@
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+/// A Bevy component that marks the entities around which [AnimationEvent::MarkerHit](crate::prelude::AnimationEvent::MarkerHit) events are emitted.
+///
+/// This is usually added to the camera or to the audio listener.
+/// It only has an effect when [AnimationEventSettings::marker_max_distance](crate::prelude::AnimationEventSettings::marker_max_distance) is set.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn setup(mut commands: Commands, mut event_settings: ResMut<AnimationEventSettings>) {
+///     // Ignore the footsteps of the characters that are too far to be heard
+///
+///     event_settings.marker_max_distance = Some(500.0);
+///
+///     commands.spawn((Camera2d, MarkerListener));
+/// }
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct MarkerListener;
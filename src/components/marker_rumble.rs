@@ -0,0 +1,101 @@
+use std::{collections::HashMap, time::Duration};
+
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+use crate::events::AnimationMarkerId;
+
+/// A rumble played on a gamepad when an animation hits a marker.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct RumblePattern {
+    /// The intensity of the strong (low-frequency) motor, from 0 to 1
+    pub strong_motor: f32,
+
+    /// The intensity of the weak (high-frequency) motor, from 0 to 1
+    pub weak_motor: f32,
+
+    /// How long the rumble lasts
+    pub duration: Duration,
+}
+
+impl RumblePattern {
+    /// Creates a rumble pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `strong_motor` - the intensity of the strong (low-frequency) motor, from 0 to 1
+    /// * `weak_motor` - the intensity of the weak (high-frequency) motor, from 0 to 1
+    /// * `duration` - how long the rumble lasts
+    pub fn new(strong_motor: f32, weak_motor: f32, duration: Duration) -> Self {
+        Self {
+            strong_motor,
+            weak_motor,
+            duration,
+        }
+    }
+}
+
+/// A Bevy component that rumbles a gamepad when the animation of its entity hits markers.
+///
+/// Rumbles are requested in the same frame as the matching [AnimationEvent::MarkerHit](crate::prelude::AnimationEvent::MarkerHit) events,
+/// so impact feedback stays in sync with the animation like sounds would.
+///
+/// # Note
+///
+/// This requires the `gamepad_rumble` feature and Bevy's input plugin.
+/// Markers are only reported if the entity emits events (see [AnimationEventSettings](crate::prelude::AnimationEventSettings)).
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(mut commands: Commands, mut library: ResMut<AnimationLibrary>, gamepad: Entity, image: Handle<Image>, atlas: TextureAtlas) {
+/// let impact_marker = library.new_marker();
+///
+/// let clip = Clip::from_frames([0, 1, 2, 3]).with_marker(impact_marker, 2);
+/// let clip_id = library.register_clip(clip);
+///
+/// let animation_id = library.register_animation(Animation::from_clip(clip_id));
+///
+/// commands.spawn((
+///     Sprite::from_atlas_image(image, atlas),
+///     SpritesheetAnimation::from_id(animation_id),
+///     MarkerRumble::new(gamepad).with_rumble(
+///         impact_marker,
+///         RumblePattern::new(0.8, 0.4, Duration::from_millis(150)),
+///     ),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug)]
+pub struct MarkerRumble {
+    /// The gamepad to rumble
+    pub gamepad: Entity,
+
+    /// The rumble to play for each marker
+    pub patterns: HashMap<AnimationMarkerId, RumblePattern>,
+}
+
+impl MarkerRumble {
+    /// Creates a component that rumbles a gamepad, without any pattern yet.
+    pub fn new(gamepad: Entity) -> Self {
+        Self {
+            gamepad,
+            patterns: HashMap::new(),
+        }
+    }
+
+    /// Plays a rumble when a marker is hit.
+    ///
+    /// # Arguments
+    ///
+    /// * `marker_id` - the marker that triggers the rumble
+    /// * `pattern` - the rumble to play
+    pub fn with_rumble(mut self, marker_id: AnimationMarkerId, pattern: RumblePattern) -> Self {
+        self.patterns.insert(marker_id, pattern);
+        self
+    }
+}
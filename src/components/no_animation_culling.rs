@@ -0,0 +1,23 @@
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+/// A marker component that opts an animated entity out of the [AnimationCulling](crate::prelude::AnimationCulling) of the plugin.
+///
+/// The animation of the entity is always updated, even when it is offscreen or far from the cameras,
+/// for instance because its events drive some gameplay.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(mut commands: Commands, boss_id: AnimationId, image: Handle<Image>, atlas: TextureAtlas) {
+/// commands.spawn((
+///     Sprite::from_atlas_image(image, atlas),
+///     SpritesheetAnimation::from_id(boss_id),
+///     NoAnimationCulling,
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct NoAnimationCulling;
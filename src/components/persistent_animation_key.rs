@@ -0,0 +1,31 @@
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+/// A Bevy component that preserves the progress of an animation when its entity is despawned.
+///
+/// When an entity with this component stops being animated (it is despawned or its [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) component is removed),
+/// the progress of its animation is stored under the given key.
+/// The next entity spawned with the same key and the same animation then resumes from that progress instead of restarting from the beginning.
+///
+/// This keeps world props visually continuous when they are streamed in and out with their chunks for instance.
+///
+/// # Note
+///
+/// The stored progress is consumed by the first entity that picks it up.
+/// It is ignored if the new entity plays a different animation or explicitly sets its starting progress.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(mut commands: Commands, animation_id: AnimationId, image: Handle<Image>, atlas: TextureAtlas) {
+/// commands.spawn((
+///     Sprite::from_atlas_image(image, atlas),
+///     SpritesheetAnimation::from_id(animation_id),
+///     PersistentAnimationKey("chunk_3_4_torch_2".to_string()),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Debug, PartialEq, Hash)]
+pub struct PersistentAnimationKey(pub String);
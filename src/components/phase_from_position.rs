@@ -0,0 +1,43 @@
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+/// A Bevy component that makes an entity start its animation at a frame derived from its position.
+///
+/// This decorrelates environmental animations tiled across the world (grass, water, foliage...)
+/// without configuring each entity: neighbouring tiles start at different frames instead of playing in unison.
+///
+/// The starting frame is picked when the component or the [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) is added,
+/// from the global translation of the entity, so that tiles parented to a moving or offset chunk are decorrelated by their world position.
+/// Animations that already specify a starting progress are left untouched.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(mut commands: Commands, animation_id: AnimationId, image: Handle<Image>, atlas: TextureAtlas) {
+/// // Grass tiles of 16x16 pixels
+/// for x in 0..10 {
+///     commands.spawn((
+///         Sprite::from_atlas_image(image.clone(), atlas.clone()),
+///         SpritesheetAnimation::from_id(animation_id),
+///         Transform::from_xyz(x as f32 * 16.0, 0.0, 0.0),
+///         PhaseFromPosition { scale: 1.0 / 16.0 },
+///     ));
+/// }
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct PhaseFromPosition {
+    /// A scale applied to the position before deriving the starting frame, defaults to 1.
+    ///
+    /// The scaled positions are rounded down so entities in the same cell start at the same frame.
+    /// For tiles, use the inverse of the size of a tile.
+    pub scale: f32,
+}
+
+impl Default for PhaseFromPosition {
+    fn default() -> Self {
+        Self { scale: 1.0 }
+    }
+}
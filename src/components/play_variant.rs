@@ -0,0 +1,100 @@
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+use crate::{animation::AnimationId, hash::hash_u64};
+
+/// A set of interchangeable animations with weights, from which [PlayVariant] picks one.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # let mut library = AnimationLibrary::default();
+/// # let clip_id = library.register_clip(Clip::from_frames([0, 1]));
+/// # let common_idle_id = library.register_animation(Animation::from_clip(clip_id));
+/// # let rare_idle_id = library.register_animation(Animation::from_clip(clip_id));
+/// let idles = VariantSet::default()
+///     .with_variant(common_idle_id, 9)
+///     .with_variant(rare_idle_id, 1);
+///
+/// // The same seed always picks the same variant
+///
+/// assert_eq!(idles.pick(42), idles.pick(42));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Default, PartialEq)]
+pub struct VariantSet {
+    /// The animations of the set with their weights
+    pub variants: Vec<(AnimationId, u32)>,
+}
+
+impl VariantSet {
+    /// Adds an animation to the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `animation_id` - the animation to add
+    /// * `weight` - the relative chance for the animation to be picked
+    pub fn with_variant(mut self, animation_id: AnimationId, weight: u32) -> Self {
+        self.variants.push((animation_id, weight));
+        self
+    }
+
+    /// Picks an animation of the set from a seed.
+    ///
+    /// The pick only depends on the seed and on the variants of the set,
+    /// so peers that share the seed pick the same animation regardless of their platform.
+    ///
+    /// Returns None if the set is empty or if all the weights are zero.
+    pub fn pick(&self, seed: u64) -> Option<AnimationId> {
+        let total_weight: u64 = self.variants.iter().map(|(_, weight)| *weight as u64).sum();
+
+        if total_weight == 0 {
+            return None;
+        }
+
+        let mut remaining = hash_u64(seed) % total_weight;
+
+        for (animation_id, weight) in &self.variants {
+            if remaining < *weight as u64 {
+                return Some(*animation_id);
+            }
+
+            remaining -= *weight as u64;
+        }
+
+        None
+    }
+}
+
+/// A Bevy component that plays an animation picked from a [VariantSet] with a seed.
+///
+/// This brings cosmetic variety to crowds of similar entities.
+/// In multiplayer games, peers that replicate the seed (for instance a network ID) display the same variants.
+///
+/// The [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) of the entity is inserted or switched
+/// when this component is added or changed.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(mut commands: Commands, idles: VariantSet, network_id: u64, image: Handle<Image>, atlas: TextureAtlas) {
+/// commands.spawn((
+///     Sprite::from_atlas_image(image, atlas),
+///     PlayVariant {
+///         set: idles,
+///         seed: network_id,
+///     },
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default, PartialEq)]
+pub struct PlayVariant {
+    /// The animations to pick from
+    pub set: VariantSet,
+
+    /// The seed of the pick
+    pub seed: u64,
+}
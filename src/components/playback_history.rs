@@ -0,0 +1,111 @@
+use std::{collections::VecDeque, time::Duration};
+
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+use crate::{
+    animation::AnimationId, components::spritesheet_animation::AnimationProgress,
+    events::AnimationEvent,
+};
+
+/// A frame displayed by an animation, recorded in a [PlaybackHistory].
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct PlaybackRecord {
+    /// The time at which the frame was displayed, as given by [Time::elapsed](bevy::time::Time::elapsed)
+    pub time: Duration,
+
+    /// The animation that displayed the frame
+    pub animation_id: AnimationId,
+
+    /// The progress of the animation when the frame was displayed
+    pub progress: AnimationProgress,
+
+    /// The atlas index that was assigned to the entity
+    pub atlas_index: usize,
+
+    /// The events emitted by the frame
+    #[reflect(ignore)]
+    pub events: Vec<AnimationEvent>,
+}
+
+/// A Bevy component that records the frames recently displayed by the animation of an entity.
+///
+/// This is useful for replays and kill-cams, or to debug desyncs by knowing exactly which frames were displayed.
+///
+/// The records older than the history's `duration` are discarded as new frames are recorded.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(mut commands: Commands, animation_id: AnimationId, image: Handle<Image>, atlas: TextureAtlas) {
+/// // Record the last 5 seconds of playback
+///
+/// commands.spawn((
+///     Sprite::from_atlas_image(image, atlas),
+///     SpritesheetAnimation::from_id(animation_id),
+///     PlaybackHistory::new(Duration::from_secs(5)),
+/// ));
+/// # }
+///
+/// // Extract the history later on
+///
+/// fn save_killcam(mut query: Query<&mut PlaybackHistory>) {
+///     for mut history in &mut query {
+///         let records = history.take_records();
+///         // ...
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug)]
+pub struct PlaybackHistory {
+    /// How long the records are kept
+    pub duration: Duration,
+
+    /// The records, oldest first
+    records: VecDeque<PlaybackRecord>,
+}
+
+impl PlaybackHistory {
+    /// Creates an empty history that keeps the records of the given duration.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            records: VecDeque::new(),
+        }
+    }
+
+    /// Returns the records, oldest first.
+    pub fn records(&self) -> impl Iterator<Item = &PlaybackRecord> {
+        self.records.iter()
+    }
+
+    /// Extracts all the records, oldest first, and clears the history.
+    pub fn take_records(&mut self) -> Vec<PlaybackRecord> {
+        self.records.drain(..).collect()
+    }
+
+    /// Clears the history.
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+
+    pub(crate) fn record(&mut self, record: PlaybackRecord) {
+        // Discard the outdated records
+
+        let oldest_time = record.time.saturating_sub(self.duration);
+
+        while self
+            .records
+            .front()
+            .is_some_and(|record| record.time < oldest_time)
+        {
+            self.records.pop_front();
+        }
+
+        self.records.push_back(record);
+    }
+}
@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use bevy::{ecs::prelude::*, reflect::prelude::*};
+
+use crate::events::AnimationMarkerId;
+
+/// A Bevy component that requests a camera shake when the animation of its entity hits a marker.
+///
+/// A [CameraShakeRequest] event is emitted in the same frame as the matching [AnimationEvent::MarkerHit](crate::prelude::AnimationEvent::MarkerHit) event,
+/// which is also the frame in which the marker frame is displayed,
+/// so the impact feedback stays in sync with the art.
+/// The library does not move the cameras itself: the requests are meant to be applied by the camera shake system of the app.
+///
+/// # Note
+///
+/// Markers are only reported if the entity emits events (see [AnimationEventSettings](crate::prelude::AnimationEventSettings)).
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(mut commands: Commands, mut library: ResMut<AnimationLibrary>, image: Handle<Image>, atlas: TextureAtlas) {
+/// let impact_marker = library.new_marker();
+///
+/// let clip = Clip::from_frames([0, 1, 2, 3]).with_marker(impact_marker, 2);
+/// let clip_id = library.register_clip(clip);
+///
+/// let animation_id = library.register_animation(Animation::from_clip(clip_id));
+///
+/// commands.spawn((
+///     Sprite::from_atlas_image(image, atlas),
+///     SpritesheetAnimation::from_id(animation_id),
+///     ShakeOnMarker::new(impact_marker, 6.0, Duration::from_millis(200)),
+/// ));
+/// # }
+///
+/// fn shake_cameras(mut shake_requests: EventReader<CameraShakeRequest>) {
+///     for request in shake_requests.read() {
+///         // Shake the cameras by request.amplitude for request.duration
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct ShakeOnMarker {
+    /// The marker that triggers the shake
+    pub marker: AnimationMarkerId,
+
+    /// The strength of the shake, in the units of the app's camera shake system
+    pub amplitude: f32,
+
+    /// How long the shake lasts
+    pub duration: Duration,
+}
+
+impl ShakeOnMarker {
+    /// Creates a camera shake triggered by a marker.
+    ///
+    /// # Arguments
+    ///
+    /// * `marker` - the marker that triggers the shake
+    /// * `amplitude` - the strength of the shake
+    /// * `duration` - how long the shake lasts
+    pub fn new(marker: AnimationMarkerId, amplitude: f32, duration: Duration) -> Self {
+        Self {
+            marker,
+            amplitude,
+            duration,
+        }
+    }
+}
+
+/// A Bevy event emitted when an animation with a [ShakeOnMarker] hits its marker.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct CameraShakeRequest {
+    /// The entity whose animation hit the marker
+    pub entity: Entity,
+
+    /// The strength of the shake
+    pub amplitude: f32,
+
+    /// How long the shake lasts
+    pub duration: Duration,
+}
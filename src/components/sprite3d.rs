@@ -2,6 +2,7 @@ use bevy::{
     asset::Handle,
     color::Color,
     ecs::prelude::*,
+    image::ImageSamplerDescriptor,
     math::Vec2,
     prelude::*,
     render::view::Visibility,
@@ -20,6 +21,8 @@ use bevy::{
 /// The library requires the sprite's texture to be loaded before setting everything up.
 /// If the texture has already been loaded (for example, in a loading stage), the sprite will appear on the next update.
 /// Otherwise, the actual rendering will be delayed and the sprite will not be visible during a few frames.
+/// A [placeholder](Sprite3d::placeholder) can be displayed in the meantime
+/// and a [Sprite3dReady] event is emitted when the sprite is rendered with its actual image.
 #[derive(Component, Debug, Reflect)]
 #[require(Transform, Visibility)]
 #[reflect(Component, Debug)]
@@ -64,6 +67,252 @@ pub struct Sprite3d {
     /// An emissive colour, if the sprite should emit light.
     /// `LinearRgba::Black` (default) does nothing.
     pub emissive: LinearRgba,
+
+    /// The roughness of the sprite when it is lit, from 0 (glossy) to 1 (rough).
+    /// `0.5` by default.
+    pub perceptual_roughness: f32,
+
+    /// The specular intensity of the sprite when it is lit, from 0 to 1.
+    /// `0.15` by default.
+    pub reflectance: f32,
+
+    /// How metallic the sprite is when it is lit, from 0 (dielectric) to 1 (metal).
+    /// `0.0` by default.
+    pub metallic: f32,
+
+    /// A bias applied to the depth of the sprite, to resolve z-fighting with other geometry.
+    /// `0.0` by default.
+    ///
+    /// See [StandardMaterial::depth_bias].
+    pub depth_bias: f32,
+
+    /// An (optional) material whose properties are used for the parameters that the sprite does not define,
+    /// like normal maps or fog settings.
+    ///
+    /// The image, color, alpha mode, lighting, emissive and PBR parameters of the sprite are always applied on top of the template.
+    /// The template must be loaded when the sprite's material is created and later changes to the template are not reflected.
+    pub material_template: Option<Handle<StandardMaterial>>,
+
+    /// An (optional) mesh to use instead of the default flat quad.
+    ///
+    /// The UVs of this mesh (in the 0-1 range) are remapped to the current frame of the texture atlas.
+    /// Its vertices are used as-is: `custom_size` and `anchor` do not apply.
+    ///
+    /// The mesh must have UVs and be kept in the main world
+    /// (its [RenderAssetUsages](bevy::render::render_asset::RenderAssetUsages) must include `MAIN_WORLD`)
+    /// for the library to read it.
+    pub base_mesh: Option<Handle<Mesh>>,
+
+    /// How much the UVs of the current frame are moved inward, to prevent the neighboring frames from bleeding into it.
+    /// No inset by default.
+    ///
+    /// With linear filtering, mipmaps or at some camera angles and zoom levels, the texels on the edges of a frame can be blended with the ones of the neighboring frames.
+    /// An inset of [half a texel](Sprite3dUvInset::HalfTexel) usually prevents this bleeding, at the cost of slightly cropping the frames.
+    pub uv_inset: Sprite3dUvInset,
+
+    /// An (optional) placeholder to display while the sprite's image is not loaded yet.
+    ///
+    /// Requires a texture atlas.
+    /// Not supported when [batching](crate::prelude::SpritesheetAnimationPlugin::batch_3d) is enabled.
+    pub placeholder: Option<Sprite3dPlaceholder>,
+
+    /// An (optional) sampler that overrides the one of the sprite's image.
+    ///
+    /// This allows filtering some sprites differently from the rest of the app
+    /// without changing the default sampler of the [ImagePlugin](bevy::prelude::ImagePlugin).
+    ///
+    /// The sprite is rendered with a copy of its image that uses this sampler,
+    /// which is shared by all the sprites with the same image and sampler.
+    /// The copy is made once the image is loaded, so later changes to the image are not reflected.
+    pub sampler: Option<Sprite3dSampler>,
+}
+
+/// What to display instead of a 3D sprite while its image is loading.
+///
+/// The placeholder is a flat quad with the size of the sprite.
+#[derive(Debug, Clone, Reflect)]
+#[reflect(Debug)]
+pub enum Sprite3dPlaceholder {
+    /// A solid color
+    Color(Color),
+
+    /// A proxy image, usually a low-res version of the spritesheet.
+    ///
+    /// The current frame is selected with the sprite's texture atlas,
+    /// so the proxy image must have the same layout as the actual spritesheet (in proportion).
+    ///
+    /// Nothing will be displayed until the proxy image itself is loaded.
+    Image(Handle<Image>),
+}
+
+/// The texture sampling of a 3D sprite, see [Sprite3d::sampler].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(image: Handle<Image>) {
+/// // Crisp pixel art
+/// let pixel_sprite = Sprite3d::from_image(image.clone()).with_sampler(Sprite3dSampler::nearest());
+///
+/// // Smooth sprite seen at grazing angles
+/// let smooth_sprite =
+///     Sprite3d::from_image(image).with_sampler(Sprite3dSampler::linear().with_anisotropy(16));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct Sprite3dSampler {
+    /// How the texels of the image are filtered
+    pub filter: Sprite3dFilter,
+
+    /// The maximum anisotropy level, defaults to 1 (no anisotropic filtering)
+    ///
+    /// Only applies to the [Linear](Sprite3dFilter::Linear) filter.
+    /// Valid values are 1, 2, 4, 8 and 16.
+    pub anisotropy: u16,
+}
+
+/// The filtering of a [Sprite3dSampler].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub enum Sprite3dFilter {
+    /// Use the nearest texel, for pixel art
+    #[default]
+    Nearest,
+    /// Interpolate between texels, for smooth images
+    Linear,
+}
+
+impl Sprite3dSampler {
+    /// Creates a sampler that uses the nearest texels.
+    pub fn nearest() -> Self {
+        Self {
+            filter: Sprite3dFilter::Nearest,
+            anisotropy: 1,
+        }
+    }
+
+    /// Creates a sampler that interpolates between texels.
+    pub fn linear() -> Self {
+        Self {
+            filter: Sprite3dFilter::Linear,
+            anisotropy: 1,
+        }
+    }
+
+    /// Sets the maximum anisotropy level of the sampler.
+    pub fn with_anisotropy(mut self, anisotropy: u16) -> Self {
+        self.anisotropy = anisotropy;
+        self
+    }
+
+    /// Returns the Bevy sampler descriptor corresponding to this sampler.
+    pub fn descriptor(&self) -> ImageSamplerDescriptor {
+        match self.filter {
+            Sprite3dFilter::Nearest => ImageSamplerDescriptor::nearest(),
+            Sprite3dFilter::Linear => ImageSamplerDescriptor {
+                anisotropy_clamp: self.anisotropy.max(1),
+                ..ImageSamplerDescriptor::linear()
+            },
+        }
+    }
+}
+
+/// How much the UVs of a 3D sprite are moved inward, see [Sprite3d::uv_inset].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(image: Handle<Image>, atlas: TextureAtlas) {
+/// let sprite = Sprite3d::from_atlas_image(image, atlas)
+///     .with_sampler(Sprite3dSampler::linear())
+///     .with_uv_inset(Sprite3dUvInset::HalfTexel);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Debug, Default, PartialEq)]
+pub enum Sprite3dUvInset {
+    /// The UVs are exactly on the edges of the frame
+    #[default]
+    None,
+    /// The UVs are moved inward by half a texel of the sprite's image
+    HalfTexel,
+    /// The UVs are moved inward by a distance in pixels of the atlas layout
+    Pixels(f32),
+}
+
+/// A Bevy event emitted when a 3D sprite starts being rendered with its actual image.
+///
+/// This is useful to hide sprites until they are ready or to get notified when a [placeholder](Sprite3d::placeholder) gets replaced.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct Sprite3dReady {
+    /// The entity of the 3D sprite
+    pub entity: Entity,
+}
+
+/// A Bevy component that sizes a 3D sprite so that its texels map to screen pixels through an orthographic camera.
+///
+/// This is common for HD-2D scenes where pixel-art sprites are placed in a 3D world.
+/// The [custom_size](Sprite3d::custom_size) of the sprite is computed from the size of its current frame
+/// and from the projection and viewport of the camera, and recomputed when they change (for instance when the window is resized).
+///
+/// For the mapping to be exact, the sprite should not be scaled and should face the camera.
+/// Sprites with a [base mesh](Sprite3d::base_mesh) are not resized.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # fn f(mut commands: Commands, image: Handle<Image>, atlas: TextureAtlas) {
+/// let camera = commands
+///     .spawn((
+///         Camera3d::default(),
+///         Projection::Orthographic(OrthographicProjection::default_3d()),
+///     ))
+///     .id();
+///
+/// // Each texel covers 3x3 screen pixels
+///
+/// commands.spawn((
+///     Sprite3d::from_atlas_image(image, atlas),
+///     Sprite3dPixelPerfect::new(camera).with_pixels_per_texel(3),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct Sprite3dPixelPerfect {
+    /// The camera with an orthographic projection through which the sprite is displayed
+    pub camera: Entity,
+
+    /// The number of screen pixels covered by a texel along each axis, defaults to 1
+    pub pixels_per_texel: u32,
+}
+
+impl Sprite3dPixelPerfect {
+    /// Sizes a sprite for a camera.
+    ///
+    /// # Arguments
+    ///
+    /// * `camera` - the camera with an orthographic projection
+    pub fn new(camera: Entity) -> Self {
+        Self {
+            camera,
+            pixels_per_texel: 1,
+        }
+    }
+
+    /// Sets the number of screen pixels covered by a texel along each axis.
+    pub fn with_pixels_per_texel(mut self, pixels_per_texel: u32) -> Self {
+        self.pixels_per_texel = pixels_per_texel;
+        self
+    }
 }
 
 impl Default for Sprite3d {
@@ -79,6 +328,15 @@ impl Default for Sprite3d {
             alpha_mode: AlphaMode::Mask(0.5),
             unlit: true,
             emissive: LinearRgba::BLACK,
+            perceptual_roughness: 0.5,
+            reflectance: 0.15,
+            metallic: 0.0,
+            depth_bias: 0.0,
+            material_template: None,
+            base_mesh: None,
+            uv_inset: Sprite3dUvInset::None,
+            placeholder: None,
+            sampler: None,
         }
     }
 }
@@ -114,6 +372,31 @@ impl Sprite3d {
         self
     }
 
+    pub fn with_perceptual_roughness(mut self, perceptual_roughness: f32) -> Self {
+        self.perceptual_roughness = perceptual_roughness;
+        self
+    }
+
+    pub fn with_reflectance(mut self, reflectance: f32) -> Self {
+        self.reflectance = reflectance;
+        self
+    }
+
+    pub fn with_metallic(mut self, metallic: f32) -> Self {
+        self.metallic = metallic;
+        self
+    }
+
+    pub fn with_depth_bias(mut self, depth_bias: f32) -> Self {
+        self.depth_bias = depth_bias;
+        self
+    }
+
+    pub fn with_material_template(mut self, material_template: Handle<StandardMaterial>) -> Self {
+        self.material_template = Some(material_template);
+        self
+    }
+
     pub fn with_color(mut self, color: impl Into<Color>) -> Self {
         self.color = color.into();
         self
@@ -134,4 +417,24 @@ impl Sprite3d {
         self.anchor = anchor.into();
         self
     }
+
+    pub fn with_base_mesh(mut self, base_mesh: Handle<Mesh>) -> Self {
+        self.base_mesh = Some(base_mesh);
+        self
+    }
+
+    pub fn with_uv_inset(mut self, uv_inset: Sprite3dUvInset) -> Self {
+        self.uv_inset = uv_inset;
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: Sprite3dPlaceholder) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    pub fn with_sampler(mut self, sampler: Sprite3dSampler) -> Self {
+        self.sampler = Some(sampler);
+        self
+    }
 }
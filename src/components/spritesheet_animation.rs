@@ -1,6 +1,18 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+    ops::Range,
+    time::Duration,
+};
+
 use bevy::{ecs::prelude::*, reflect::prelude::*};
 
-use crate::animation::AnimationId;
+use crate::{
+    animation::AnimationId,
+    clip::ClipId,
+    hash::{hash_to_unit, hash_u64},
+    library::AnimationLibrary,
+};
 
 // The progress of an animation being played.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
@@ -20,6 +32,113 @@ pub struct AnimationProgress {
     pub repetition: usize,
 }
 
+/// Error type returned when setting an invalid [AnimationProgress].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressError {
+    /// The frame is out of the animation
+    InvalidFrame {
+        /// The requested frame
+        frame: usize,
+        /// The number of frames in the animation
+        frame_count: usize,
+    },
+    /// The repetition is out of the animation
+    InvalidRepetition {
+        /// The requested repetition
+        repetition: usize,
+        /// The number of repetitions of the animation
+        repetitions: usize,
+    },
+}
+
+impl fmt::Display for ProgressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgressError::InvalidFrame { frame, frame_count } => {
+                write!(f, "invalid frame {frame} in {frame_count}-frame animation")
+            }
+            ProgressError::InvalidRepetition {
+                repetition,
+                repetitions,
+            } => write!(
+                f,
+                "invalid repetition {repetition} in {repetitions}-repetition animation"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ProgressError {}
+
+/// A remapping of the atlas indices of an animation, applied right before they are assigned to an entity.
+///
+/// This makes it possible to display variants of an animation laid out elsewhere in the spritesheet without registering new animations.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// #[derive(Component)]
+/// struct Damaged;
+///
+/// // The damaged variants of the frames are one row below in an 8-column spritesheet
+///
+/// fn show_damage(mut query: Query<(&mut SpritesheetAnimation, Has<Damaged>)>) {
+///     for (mut animation, damaged) in &mut query {
+///         animation.frame_remap = if damaged {
+///             FrameRemap::from_offset(8)
+///         } else {
+///             FrameRemap::default()
+///         };
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Default, PartialEq)]
+pub struct FrameRemap {
+    /// Replacements for specific atlas indices
+    pub indices: HashMap<usize, usize>,
+
+    /// An offset added to all the atlas indices, after the replacements
+    pub offset: isize,
+}
+
+impl FrameRemap {
+    /// Creates a remapping that offsets all the atlas indices.
+    pub fn from_offset(offset: isize) -> Self {
+        Self {
+            offset,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a remapping that replaces specific atlas indices.
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// let remap = FrameRemap::from_indices([(3, 10), (4, 11)]);
+    ///
+    /// assert_eq!(remap.apply(3), 10);
+    /// assert_eq!(remap.apply(5), 5);
+    /// ```
+    pub fn from_indices(indices: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        Self {
+            indices: indices.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Remaps an atlas index.
+    pub fn apply(&self, atlas_index: usize) -> usize {
+        self.indices
+            .get(&atlas_index)
+            .copied()
+            .unwrap_or(atlas_index)
+            .saturating_add_signed(self.offset)
+    }
+}
+
 /// A Bevy component that enables spritesheet animations.
 ///
 /// It contains an [AnimationId] that references an [Animation](crate::prelude::Animation) obtained with [AnimationLibrary::register_animation](crate::prelude::AnimationLibrary::register_animation).
@@ -80,7 +199,43 @@ pub struct SpritesheetAnimation {
     pub playing: bool,
 
     /// A speed multiplier for the animation, defaults to 1
+    ///
+    /// A negative factor plays the animation backwards from the current frame, for instance to scrub a replay.
+    /// The animation stops at its first frame and no [AnimationEvent](crate::prelude::AnimationEvent)s are emitted while playing backwards.
     pub speed_factor: f32,
+
+    /// A delay before the animation starts playing, defaults to zero
+    ///
+    /// The first frame is displayed but held until the delay elapses.
+    /// The delay counts down as time passes and an [AnimationEvent::AnimationStarted](crate::prelude::AnimationEvent::AnimationStarted) event is emitted when the animation actually starts.
+    ///
+    /// The delay is not affected by `speed_factor` and does not count down while the animation is paused.
+    pub delay: Duration,
+
+    /// Does the animation emit [AnimationEvent](crate::prelude::AnimationEvent)s? Defaults to true
+    ///
+    /// Events can also be disabled for whole kinds of entities with the [AnimationEventSettings](crate::prelude::AnimationEventSettings) resource.
+    pub emit_events: bool,
+
+    /// A remapping applied to the atlas indices of the animation, defaults to none
+    ///
+    /// Changes take effect immediately, without waiting for the next frame of the animation.
+    pub frame_remap: FrameRemap,
+
+    /// The clips of the animation that are skipped for this entity, defaults to none
+    ///
+    /// See [SpritesheetAnimation::set_clip_enabled] for details.
+    pub disabled_clips: HashSet<ClipId>,
+
+    /// The animations to play after the current one ends, in order, defaults to none
+    ///
+    /// See [SpritesheetAnimation::queue] for details.
+    pub queued_animations: VecDeque<AnimationId>,
+
+    /// A range in which a random multiplier of `speed_factor` is picked when the component is inserted, defaults to none
+    ///
+    /// See [SpritesheetAnimation::with_speed_jitter] for details.
+    pub speed_jitter: Option<Range<f32>>,
 }
 
 impl SpritesheetAnimation {
@@ -98,9 +253,262 @@ impl SpritesheetAnimation {
             },
             playing: true,
             speed_factor: 1.0,
+            delay: Duration::ZERO,
+            emit_events: true,
+            frame_remap: FrameRemap::default(),
+            disabled_clips: HashSet::new(),
+            queued_animations: VecDeque::new(),
+            speed_jitter: None,
         }
     }
 
+    /// Delays the start of the animation.
+    ///
+    /// This is convenient to stagger the animations of a crowd of entities spawned at the same time.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - the time to wait before playing the animation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// # let clip_id = library.register_clip(Clip::from_frames([1, 2, 3]));
+    /// # let animation_id = library.register_animation(Animation::from_clip(clip_id));
+    /// let animation = SpritesheetAnimation::from_id(animation_id)
+    ///     .with_delay(Duration::from_millis(500));
+    /// ```
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Multiplies the speed of the animation by a random factor picked in a range when the component is inserted.
+    ///
+    /// This is a cheap way to desynchronize crowds of entities, complementary to [SpritesheetAnimation::with_delay].
+    ///
+    /// The factor is derived from the entity, so that it is deterministic for a given entity.
+    /// It is picked once, when the entity starts playing animations: `speed_jitter` is then cleared and `speed_factor` holds the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - the range of the speed multiplier
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// # let clip_id = library.register_clip(Clip::from_frames([1, 2, 3]));
+    /// # let animation_id = library.register_animation(Animation::from_clip(clip_id));
+    /// // Each member of the crowd walks between 10% slower and 10% faster
+    ///
+    /// let animation = SpritesheetAnimation::from_id(animation_id).with_speed_jitter(0.9..1.1);
+    /// ```
+    pub fn with_speed_jitter(mut self, range: Range<f32>) -> Self {
+        self.speed_jitter = Some(range);
+        self
+    }
+
+    /// Applies the speed jitter, if any, with a seed
+    pub(crate) fn apply_speed_jitter(&mut self, seed: u64) {
+        let Some(range) = self.speed_jitter.take() else {
+            return;
+        };
+
+        let t = hash_to_unit(hash_u64(seed));
+
+        self.speed_factor *= range.start + (range.end - range.start) * t;
+    }
+
+    /// Disables the [AnimationEvent](crate::prelude::AnimationEvent)s for this animation.
+    ///
+    /// This is convenient for purely cosmetic animations that no system reacts to.
+    pub fn without_events(mut self) -> Self {
+        self.emit_events = false;
+        self
+    }
+
+    /// Remaps the atlas indices of the animation before they are assigned to the entity.
+    ///
+    /// See [FrameRemap] for details.
+    pub fn with_frame_remap(mut self, frame_remap: FrameRemap) -> Self {
+        self.frame_remap = frame_remap;
+        self
+    }
+
+    /// Jumps to a specific frame and repetition of the animation.
+    ///
+    /// Unlike setting `progress` directly, which only logs a warning when the progress is out of the animation,
+    /// this checks the progress against the library so that invalid seeks can be handled.
+    ///
+    /// # Arguments
+    ///
+    /// * `progress` - the new progress of the animation
+    /// * `library` - the library where the animation is registered
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ProgressError] if the frame or the repetition is out of the animation.
+    /// The progress is left unchanged in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let clip_id = library.register_clip(Clip::from_frames([1, 2, 3]));
+    /// let animation_id = library.register_animation(Animation::from_clip(clip_id));
+    ///
+    /// let mut animation = SpritesheetAnimation::from_id(animation_id);
+    ///
+    /// assert!(animation
+    ///     .set_progress(AnimationProgress { frame: 2, repetition: 0 }, &library)
+    ///     .is_ok());
+    ///
+    /// assert_eq!(
+    ///     animation.set_progress(AnimationProgress { frame: 3, repetition: 0 }, &library),
+    ///     Err(ProgressError::InvalidFrame { frame: 3, frame_count: 3 })
+    /// );
+    /// ```
+    pub fn set_progress(
+        &mut self,
+        progress: AnimationProgress,
+        library: &AnimationLibrary,
+    ) -> Result<(), ProgressError> {
+        library.validate_progress(self.animation_id, progress)?;
+
+        self.progress = progress;
+
+        Ok(())
+    }
+
+    /// Jumps to a frame of the current repetition of the animation.
+    ///
+    /// Frames are indexed in playing order, so the first frame of a backwards animation is the last one of its clips.
+    /// See [SpritesheetAnimation::set_progress] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - the index of the frame in the animation
+    /// * `library` - the library where the animation is registered
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ProgressError] if the frame is out of the animation.
+    pub fn seek_to_frame(
+        &mut self,
+        frame: usize,
+        library: &AnimationLibrary,
+    ) -> Result<(), ProgressError> {
+        self.set_progress(
+            AnimationProgress {
+                frame,
+                repetition: self.progress.repetition,
+            },
+            library,
+        )
+    }
+
+    /// Jumps to the frame displayed some time after the start of the animation.
+    ///
+    /// The playback continues from the start of that frame, following the repetitions and directions of the animation.
+    /// Past the end of the animation, its last frame is selected.
+    /// This also works after the animation ended, to replay it from some point.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - the time since the start of the animation
+    /// * `library` - the library where the animation is registered
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ProgressError::InvalidFrame] error if the animation has no frames.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let clip = Clip::from_frames([1, 2, 3]).with_duration(AnimationDuration::PerFrame(100));
+    /// let clip_id = library.register_clip(clip);
+    ///
+    /// let animation_id = library.register_animation(Animation::from_clip(clip_id));
+    ///
+    /// let mut animation = SpritesheetAnimation::from_id(animation_id);
+    ///
+    /// // Halfway through the second frame of the second repetition
+    ///
+    /// animation.seek_to_time(Duration::from_millis(450), &library).unwrap();
+    ///
+    /// assert_eq!(animation.progress, AnimationProgress { frame: 1, repetition: 1 });
+    /// ```
+    pub fn seek_to_time(
+        &mut self,
+        time: Duration,
+        library: &AnimationLibrary,
+    ) -> Result<(), ProgressError> {
+        let sampled_frame = library
+            .sample_animation(self.animation_id, None, time)
+            .ok_or(ProgressError::InvalidFrame {
+                frame: 0,
+                frame_count: 0,
+            })?;
+
+        self.progress = sampled_frame.progress;
+
+        Ok(())
+    }
+
+    /// Enables or disables a clip of the animation for this entity.
+    ///
+    /// The frames of a disabled clip are skipped without taking any time and its events are not emitted,
+    /// as if the clip were not part of the animation.
+    /// This makes it possible to reuse a composite animation for entities that should not display some of its parts.
+    ///
+    /// Changes take effect from the next frame of the animation.
+    ///
+    /// # Arguments
+    ///
+    /// * `clip_id` - the clip to enable or disable
+    /// * `enabled` - whether the clip should be played
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let attack_clip_id = library.register_clip(Clip::from_frames([0, 1, 2]));
+    /// let recoil_clip_id = library.register_clip(Clip::from_frames([3, 4]));
+    ///
+    /// let animation_id =
+    ///     library.register_animation(Animation::from_clips([attack_clip_id, recoil_clip_id]));
+    ///
+    /// // Unarmed characters do not have any recoil
+    ///
+    /// let mut animation = SpritesheetAnimation::from_id(animation_id);
+    ///
+    /// animation.set_clip_enabled(recoil_clip_id, false);
+    ///
+    /// assert!(!animation.is_clip_enabled(recoil_clip_id));
+    /// ```
+    pub fn set_clip_enabled(&mut self, clip_id: ClipId, enabled: bool) {
+        if enabled {
+            self.disabled_clips.remove(&clip_id);
+        } else {
+            self.disabled_clips.insert(clip_id);
+        }
+    }
+
+    /// Returns true if a clip of the animation is played for this entity.
+    pub fn is_clip_enabled(&self, clip_id: ClipId) -> bool {
+        !self.disabled_clips.contains(&clip_id)
+    }
+
     /// Switches to a different animation.
     ///
     /// # Note
@@ -111,7 +519,60 @@ impl SpritesheetAnimation {
         self.reset();
     }
 
+    /// Queues an animation to play after the current one and the previously queued ones end.
+    ///
+    /// This is convenient to chain animations, for instance to return to an idle animation after an attack,
+    /// without a system that listens for [AnimationEvent::AnimationEnd](crate::prelude::AnimationEvent::AnimationEnd) events.
+    ///
+    /// When the current animation ends, the next queued animation starts on the following update as with [SpritesheetAnimation::switch].
+    /// Animations that repeat forever never end, so the animations queued after them never play.
+    ///
+    /// The pending animations can be inspected and edited with the `queued_animations` field.
+    ///
+    /// # Arguments
+    ///
+    /// * `animation_id` - the animation to play after the queued ones
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// # let clip_id = library.register_clip(Clip::from_frames([1, 2, 3]));
+    /// let attack_animation_id = library.register_animation(
+    ///     Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(1)),
+    /// );
+    /// let idle_animation_id = library.register_animation(Animation::from_clip(clip_id));
+    ///
+    /// let mut animation = SpritesheetAnimation::from_id(attack_animation_id);
+    ///
+    /// // Return to idle after the attack
+    ///
+    /// animation.queue(idle_animation_id);
+    ///
+    /// assert_eq!(
+    ///     animation.queued_animations.iter().copied().collect::<Vec<_>>(),
+    ///     vec![idle_animation_id]
+    /// );
+    /// ```
+    pub fn queue(&mut self, animation_id: AnimationId) {
+        self.queued_animations.push_back(animation_id);
+    }
+
+    /// Same as [SpritesheetAnimation::queue] but for building the component.
+    pub fn with_queued(mut self, animation_id: AnimationId) -> Self {
+        self.queue(animation_id);
+        self
+    }
+
+    /// Removes all the queued animations.
+    pub fn clear_queue(&mut self) {
+        self.queued_animations.clear();
+    }
+
     /// Resets the animation to its initial state.
+    ///
+    /// To replay the animation from scratch, including the events of its start, see [Animator::restart](crate::prelude::Animator::restart).
     pub fn reset(&mut self) {
         self.progress.frame = 0;
         self.progress.repetition = 0;
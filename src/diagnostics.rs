@@ -0,0 +1,136 @@
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Mutex,
+    },
+};
+
+use bevy::{
+    ecs::{
+        change_detection::DetectChanges,
+        event::{Event, EventWriter},
+        reflect::*,
+        system::{Res, Resource},
+    },
+    log::{info, warn},
+    reflect::prelude::*,
+};
+
+use crate::CRATE_NAME;
+
+/// A Bevy resource to configure how the library reports the issues that it detects.
+///
+/// Issues are usually data bugs that the library recovers from, like frames out of a spritesheet or invalid progresses.
+/// They are logged by default, which may be unwanted in shipped games with known benign cases,
+/// while tools may prefer to present them in their own interface.
+///
+/// The mode applies to the whole process: apps running side by side (for instance in tests) share it.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn setup(mut diagnostics: ResMut<AnimationDiagnostics>) {
+///     diagnostics.mode = DiagnosticsMode::Collect;
+/// }
+///
+/// fn show_issues(mut diagnostics: EventReader<AnimationDiagnostic>) {
+///     for diagnostic in diagnostics.read() {
+///         // Display in an editor panel instead of the logs
+///         println!("{:?}: {}", diagnostic.level, diagnostic.message);
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Resource, Debug, Default, PartialEq)]
+pub struct AnimationDiagnostics {
+    /// How the issues are reported
+    pub mode: DiagnosticsMode,
+}
+
+/// How the issues detected by the library are reported, see [AnimationDiagnostics].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub enum DiagnosticsMode {
+    /// Log the issues with Bevy's logger
+    #[default]
+    Log = 0,
+    /// Emit the issues as [AnimationDiagnostic] events instead of logging them
+    Collect = 1,
+    /// Ignore the issues
+    Silent = 2,
+}
+
+/// The severity of an [AnimationDiagnostic].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub enum DiagnosticLevel {
+    /// Informational messages, like caches being evicted
+    Info,
+    /// Issues that the library recovered from
+    Warning,
+}
+
+/// A Bevy event emitted for each issue detected by the library when [DiagnosticsMode::Collect] is enabled.
+///
+/// The issues are emitted at the start of the frame following the one in which they were detected.
+#[derive(Event, Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct AnimationDiagnostic {
+    /// The severity of the issue
+    pub level: DiagnosticLevel,
+
+    /// A description of the issue
+    pub message: String,
+}
+
+/// The current mode, mirrored from the resource so that code without access to the world can report issues
+static MODE: AtomicU8 = AtomicU8::new(DiagnosticsMode::Log as u8);
+
+/// The issues collected since the last time they were emitted as events
+static COLLECTED: Mutex<Vec<AnimationDiagnostic>> = Mutex::new(Vec::new());
+
+/// Reports an issue according to the current mode.
+fn report(level: DiagnosticLevel, message: fmt::Arguments) {
+    let mode = MODE.load(Ordering::Relaxed);
+
+    if mode == DiagnosticsMode::Log as u8 {
+        match level {
+            DiagnosticLevel::Info => info!("{CRATE_NAME}: {message}"),
+            DiagnosticLevel::Warning => warn!("{CRATE_NAME}: {message}"),
+        }
+    } else if mode == DiagnosticsMode::Collect as u8 {
+        if let Ok(mut collected) = COLLECTED.lock() {
+            collected.push(AnimationDiagnostic {
+                level,
+                message: message.to_string(),
+            });
+        }
+    }
+}
+
+/// Reports a warning according to the current mode.
+pub(crate) fn warning(message: fmt::Arguments) {
+    report(DiagnosticLevel::Warning, message);
+}
+
+/// Reports an informational message according to the current mode.
+pub(crate) fn info(message: fmt::Arguments) {
+    report(DiagnosticLevel::Info, message);
+}
+
+/// Applies the mode of the [AnimationDiagnostics] resource and emits the collected issues.
+pub(crate) fn flush_diagnostics(
+    diagnostics: Res<AnimationDiagnostics>,
+    mut diagnostic_events: EventWriter<AnimationDiagnostic>,
+) {
+    if diagnostics.is_changed() {
+        MODE.store(diagnostics.mode as u8, Ordering::Relaxed);
+    }
+
+    if let Ok(mut collected) = COLLECTED.lock() {
+        diagnostic_events.send_batch(collected.drain(..));
+    }
+}
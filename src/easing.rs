@@ -5,6 +5,7 @@ use bevy::reflect::prelude::*;
 /// Variety to associate with [Easing]s to tune the acceleration.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 #[reflect(Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "animation_files", derive(serde::Deserialize))]
 pub enum EasingVariety {
     Quadratic,
     Cubic,
@@ -44,6 +45,7 @@ pub enum EasingVariety {
 /// - <http://robertpenner.com/easing/penner_chapter7_tweening.pdf>
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 #[reflect(Debug, Default, PartialEq, Hash)]
+#[cfg_attr(feature = "animation_files", derive(serde::Deserialize))]
 pub enum Easing {
     /// Linear interpolation
     #[default]
@@ -1,11 +1,14 @@
-use std::fmt;
+use std::{fmt, time::Duration};
 
 use bevy::{
-    ecs::{entity::Entity, event::Event},
+    ecs::{entity::Entity, event::Event, reflect::*, system::Resource},
     reflect::prelude::*,
 };
 
-use crate::{animation::AnimationId, clip::ClipId};
+use crate::{
+    animation::AnimationId,
+    clip::{ClipId, ClipTag},
+};
 
 /// An opaque identifier that references an animation marker.
 ///
@@ -22,13 +25,31 @@ impl fmt::Display for AnimationMarkerId {
     }
 }
 
+/// An opaque identifier that references a group of animation markers.
+///
+/// Returned by [AnimationLibrary::new_marker_group](crate::prelude::AnimationLibrary::new_marker_group).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct MarkerGroup {
+    pub(crate) value: usize,
+}
+
+impl fmt::Display for MarkerGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "group{}", self.value)
+    }
+}
+
 /// A Bevy event emitted when an animation reaches a point of interest
 ///
+/// * when an animation starts playing (when first played, after switching animations or after a [delay](crate::prelude::SpritesheetAnimation::with_delay))
 /// * when a clip repetition ends
 /// * when a clip ends (if the clip repeats multiple times, only occurs at the end of the last repetition)
 /// * when an animation repetition ends
 /// * when an animation ends (if the animation repeats multiple times, only occurs at the end of the last repetition)
 /// * when an [animation marker](crate::prelude::Clip::add_marker) is hit
+/// * when a clip starts, if enabled with [AnimationEventSettings::clip_starts]
+/// * when an animation without any frame is assigned to an entity (see [EmptyAnimationBehavior](crate::prelude::EmptyAnimationBehavior))
 ///
 /// # Example
 ///
@@ -106,8 +127,36 @@ impl fmt::Display for AnimationMarkerId {
 ///     }
 /// }
 /// ```
+///
+/// # Reading events
+///
+/// The events are written when the animations are played, in the [AnimationSystemSet](crate::plugin::AnimationSystemSet)
+/// of the [schedule configured in the plugin](crate::prelude::SpritesheetAnimationPlugin::animation_schedule) (`PostUpdate` by default).
+///
+/// - Systems that run after this set in the same schedule, or in a later schedule, read the events of the current frame.
+/// - Systems that run before it, for instance in `Update` with the default schedule, read them on the next frame.
+///
+/// In both cases, each [EventReader] has its own cursor and reads every event exactly once,
+/// as long as its system runs at least once every two frames (older events are dropped by Bevy).
+/// Systems with run conditions that skip frames may miss events.
+///
+/// Avoid iterating the `Events<AnimationEvent>` resource directly as it contains the events of the last two frames.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::{plugin::AnimationSystemSet, prelude::*};
+/// # fn react_to_animations(_: EventReader<AnimationEvent>) {}
+/// # let mut app = App::new();
+/// // React to the events on the same frame they are written
+/// app.add_systems(PostUpdate, react_to_animations.after(AnimationSystemSet));
+/// ```
 #[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AnimationEvent {
+    /// An animation has started playing
+    AnimationStarted {
+        entity: Entity,
+        animation_id: AnimationId,
+    },
     /// An animation marker has been hit
     MarkerHit {
         entity: Entity,
@@ -118,17 +167,23 @@ pub enum AnimationEvent {
         clip_repetition: usize,
     },
     /// A repetition of a clip has ended
+    ///
+    /// `clip_tag` is the [tag](crate::prelude::Clip::with_tag) of the clip, if any.
     ClipRepetitionEnd {
         entity: Entity,
         animation_id: AnimationId,
         clip_id: ClipId,
+        clip_tag: Option<ClipTag>,
         clip_repetition: usize,
     },
     /// An clip ended
+    ///
+    /// `clip_tag` is the [tag](crate::prelude::Clip::with_tag) of the clip, if any.
     ClipEnd {
         entity: Entity,
         animation_id: AnimationId,
         clip_id: ClipId,
+        clip_tag: Option<ClipTag>,
     },
     /// A repetition of an animation has ended
     AnimationRepetitionEnd {
@@ -141,4 +196,95 @@ pub enum AnimationEvent {
         entity: Entity,
         animation_id: AnimationId,
     },
+    /// A clip has started
+    ///
+    /// Only emitted if enabled with [AnimationEventSettings::clip_starts].
+    ///
+    /// As animations advance frame by frame, the event is usually emitted a bit after the clip actually started:
+    /// `elapsed` is the time already spent in the clip, which can be used to synchronize other timelines like tweens precisely.
+    ClipStart {
+        entity: Entity,
+        animation_id: AnimationId,
+        clip_id: ClipId,
+        animation_repetition: usize,
+        elapsed: Duration,
+    },
+    /// An animation without any frame has been assigned to an entity
+    ///
+    /// This usually denotes a data bug such as empty clips.
+    EmptyAnimation {
+        entity: Entity,
+        animation_id: AnimationId,
+    },
+}
+
+impl AnimationEvent {
+    /// Returns the animated entity that emitted the event.
+    pub fn entity(&self) -> Entity {
+        match self {
+            AnimationEvent::AnimationStarted { entity, .. }
+            | AnimationEvent::MarkerHit { entity, .. }
+            | AnimationEvent::ClipRepetitionEnd { entity, .. }
+            | AnimationEvent::ClipEnd { entity, .. }
+            | AnimationEvent::AnimationRepetitionEnd { entity, .. }
+            | AnimationEvent::AnimationEnd { entity, .. }
+            | AnimationEvent::ClipStart { entity, .. }
+            | AnimationEvent::EmptyAnimation { entity, .. } => *entity,
+        }
+    }
+}
+
+/// A Bevy resource to enable/disable the emission of [AnimationEvent]s for each kind of animated entity.
+///
+/// All the events are emitted by default.
+///
+/// UI animations for instance often don't need events: disabling them saves some work for the animator and clears up the event queue.
+///
+/// Events can also be disabled for individual entities with [SpritesheetAnimation::without_events](crate::prelude::SpritesheetAnimation::without_events).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// fn disable_ui_events(mut event_settings: ResMut<AnimationEventSettings>) {
+///     event_settings.image_node = false;
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource, Debug, Default)]
+pub struct AnimationEventSettings {
+    /// Emit events for entities with a [Sprite](bevy::sprite::Sprite)
+    pub sprite: bool,
+
+    /// Emit events for entities with a [Sprite3d](crate::prelude::Sprite3d)
+    pub sprite3d: bool,
+
+    /// Emit events for entities with an [ImageNode](bevy::ui::widget::ImageNode)
+    pub image_node: bool,
+
+    /// Emit [AnimationEvent::ClipStart] events, disabled by default
+    ///
+    /// They are convenient to trigger other effects exactly when a clip begins,
+    /// for instance a transform tween when the second clip of an attack animation starts.
+    pub clip_starts: bool,
+
+    /// Only emit [AnimationEvent::MarkerHit] events for entities within this distance of a [MarkerListener](crate::prelude::MarkerListener), defaults to none
+    ///
+    /// This avoids flooding the event queue with the markers of distant crowds when they only trigger sound effects.
+    /// The distance is measured between the global translations of the entities.
+    /// The markers of all the entities are emitted if there is no listener.
+    pub marker_max_distance: Option<f32>,
+}
+
+impl Default for AnimationEventSettings {
+    fn default() -> Self {
+        Self {
+            sprite: true,
+            sprite3d: true,
+            image_node: true,
+            clip_starts: false,
+            marker_max_distance: None,
+        }
+    }
 }
@@ -0,0 +1,157 @@
+use bevy::{
+    app::{App, PostUpdate},
+    ecs::{
+        component::Component,
+        entity::Entity,
+        event::EventReader,
+        query::With,
+        system::{Commands, Query, Res},
+    },
+    prelude::IntoSystemConfigs,
+};
+
+use crate::{
+    animator::Animator,
+    components::spritesheet_animation::SpritesheetAnimation,
+    events::AnimationEvent,
+    hooks::{FrameHook, FrameHooks},
+    plugin::AnimationSystemSet,
+};
+
+#[cfg(feature = "animation_states")]
+use crate::systems::animation_by_state::switch_animations_by_state;
+
+/// A component that can display the frames of an animation, in addition to the ones supported out of the box.
+///
+/// Implement this trait for custom rendering components (a custom material, a mesh-based sprite...)
+/// and register them with [SpritesheetAnimationExt::register_animation_target].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// #[derive(Component)]
+/// struct TileSprite {
+///     tile: usize,
+/// }
+///
+/// impl AnimationTarget for TileSprite {
+///     fn atlas_index(&self) -> usize {
+///         self.tile
+///     }
+///
+///     fn set_atlas_index(&mut self, atlas_index: usize) {
+///         self.tile = atlas_index;
+///     }
+/// }
+/// ```
+pub trait AnimationTarget: Component {
+    /// Returns the atlas index currently displayed.
+    fn atlas_index(&self) -> usize;
+
+    /// Displays a new atlas index.
+    fn set_atlas_index(&mut self, atlas_index: usize);
+}
+
+/// An extension trait for Bevy's [App] to plug into the animations.
+///
+/// This is the stable way for third-party crates to extend the library:
+/// it does not depend on the internal systems and modules, which may change between versions.
+///
+/// The [SpritesheetAnimationPlugin](crate::prelude::SpritesheetAnimationPlugin) must be added to the app for the registered extensions to take effect.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # #[derive(Component)]
+/// # struct TileSprite { tile: usize }
+/// # impl AnimationTarget for TileSprite {
+/// #     fn atlas_index(&self) -> usize { self.tile }
+/// #     fn set_atlas_index(&mut self, atlas_index: usize) { self.tile = atlas_index; }
+/// # }
+/// fn log_events(_commands: &mut Commands, event: &AnimationEvent) {
+///     info!("{event:?}");
+/// }
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins(SpritesheetAnimationPlugin::default())
+///     .register_animation_target::<TileSprite>()
+///     .add_animation_event_hook(log_events);
+/// ```
+pub trait SpritesheetAnimationExt {
+    /// Makes the animations update the atlas index of a custom component.
+    ///
+    /// Entities with both a [SpritesheetAnimation] and a `T` component get the atlas index of their current frame assigned to `T`,
+    /// after the frame remapping, just like built-in sprites.
+    fn register_animation_target<T: AnimationTarget>(&mut self) -> &mut Self;
+
+    /// Calls a function for every [AnimationEvent], right after the animations are updated.
+    fn add_animation_event_hook(&mut self, hook: fn(&mut Commands, &AnimationEvent)) -> &mut Self;
+
+    /// Calls a function for every frame transition.
+    ///
+    /// This is a shortcut for adding a hook to the [FrameHooks] resource.
+    fn add_frame_hook(&mut self, hook: FrameHook) -> &mut Self;
+
+    /// Makes the entities with an [AnimationByState<S>](crate::prelude::AnimationByState) component switch animations when the state `S` changes.
+    ///
+    /// Requires the `animation_states` feature.
+    #[cfg(feature = "animation_states")]
+    fn register_animation_states<S: bevy::state::state::States>(&mut self) -> &mut Self;
+}
+
+impl SpritesheetAnimationExt for App {
+    fn register_animation_target<T: AnimationTarget>(&mut self) -> &mut Self {
+        self.add_systems(
+            PostUpdate,
+            update_animation_targets::<T>.after(AnimationSystemSet),
+        )
+    }
+
+    fn add_animation_event_hook(&mut self, hook: fn(&mut Commands, &AnimationEvent)) -> &mut Self {
+        self.add_systems(
+            PostUpdate,
+            (move |mut commands: Commands, mut events: EventReader<AnimationEvent>| {
+                for event in events.read() {
+                    hook(&mut commands, event);
+                }
+            })
+            .after(AnimationSystemSet),
+        )
+    }
+
+    fn add_frame_hook(&mut self, hook: FrameHook) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with(FrameHooks::default)
+            .add(hook);
+
+        self
+    }
+
+    #[cfg(feature = "animation_states")]
+    fn register_animation_states<S: bevy::state::state::States>(&mut self) -> &mut Self {
+        self.add_systems(
+            PostUpdate,
+            switch_animations_by_state::<S>.before(AnimationSystemSet),
+        )
+    }
+}
+
+fn update_animation_targets<T: AnimationTarget>(
+    animator: Res<Animator>,
+    mut targets: Query<(Entity, &mut T), With<SpritesheetAnimation>>,
+) {
+    for (entity, mut target) in &mut targets {
+        if let Some(atlas_index) = animator.atlas_index(entity) {
+            // We compare the indices to prevent needless "Changed" events
+
+            if target.atlas_index() != atlas_index {
+                target.set_atlas_index(atlas_index);
+            }
+        }
+    }
+}
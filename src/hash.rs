@@ -0,0 +1,26 @@
+/// Hashes a value with a SplitMix64 finalizer so that close values (consecutive seeds, neighbouring cells...) get unrelated hashes.
+///
+/// The hash is stable across runs and platforms, unlike the hashers of the standard library.
+pub(crate) fn hash_u64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Maps the upper 24 bits of a hash to [0, 1)
+pub(crate) fn hash_to_unit(hash: u64) -> f32 {
+    (hash >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// Initial value of [hash_bytes]
+pub(crate) const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Folds bytes into a hash with FNV-1a, which unlike the hashers of the standard library is guaranteed to stay the same across releases
+pub(crate) fn hash_bytes(hash: u64, bytes: &[u8]) -> u64 {
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
@@ -0,0 +1,103 @@
+use bevy::ecs::{entity::Entity, system::Commands, system::Resource};
+
+use crate::{
+    animation::AnimationId,
+    clip::{ClipId, FrameLabel},
+    components::spritesheet_animation::AnimationProgress,
+    events::AnimationEvent,
+};
+
+/// A frame of animation, as seen by a [FrameHook].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrameInfo {
+    /// The atlas index of the frame, as defined in its clip
+    pub atlas_index: usize,
+
+    /// The progress of the animation at this frame
+    pub progress: AnimationProgress,
+
+    /// The clip that contains the frame
+    pub clip_id: ClipId,
+
+    /// The repetition of the clip that contains the frame
+    pub clip_repetition: usize,
+
+    /// The debug label of the frame, see [Clip::with_frame_label](crate::prelude::Clip::with_frame_label)
+    pub label: Option<FrameLabel>,
+}
+
+/// The context of a frame transition, given to the [FrameHook]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameContext {
+    /// The animated entity
+    pub entity: Entity,
+
+    /// The animation being played
+    pub animation_id: AnimationId,
+
+    /// The frame displayed before the transition, if any
+    pub previous_frame: Option<FrameInfo>,
+
+    /// The frame displayed after the transition
+    pub frame: FrameInfo,
+
+    /// The events of the new frame
+    ///
+    /// They are provided even if the entity does not emit [AnimationEvent]s.
+    pub events: Vec<AnimationEvent>,
+}
+
+/// A function called for every frame transition of every animation.
+pub type FrameHook = fn(&mut Commands, &FrameContext);
+
+/// A Bevy resource that stores the [FrameHook]s called for every frame transition.
+///
+/// Hooks are an extension point for building higher-level layers on top of animations (audio, visual effects, hitboxes...).
+/// Unlike [AnimationEvent]s, they receive the full context of each transition and can immediately queue commands.
+///
+/// Hooks are called after the animations are updated, in the order they were added.
+/// When no hooks are registered, the animator does not collect any transition.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// #[derive(Component)]
+/// struct Hitbox;
+///
+/// fn spawn_hitboxes(commands: &mut Commands, context: &FrameContext) {
+///     // The attack frames of the spritesheet
+///     if (10..15).contains(&context.frame.atlas_index) {
+///         commands.entity(context.entity).insert(Hitbox);
+///     }
+/// }
+///
+/// fn setup(mut frame_hooks: ResMut<FrameHooks>) {
+///     frame_hooks.add(spawn_hitboxes);
+/// }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct FrameHooks {
+    hooks: Vec<FrameHook>,
+}
+
+impl FrameHooks {
+    /// Adds a hook.
+    pub fn add(&mut self, hook: FrameHook) -> &mut Self {
+        self.hooks.push(hook);
+        self
+    }
+
+    /// Returns true if no hooks are registered.
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// Calls all the hooks for a frame transition.
+    pub(crate) fn call(&self, commands: &mut Commands, context: &FrameContext) {
+        for hook in &self.hooks {
+            hook(commands, context);
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use bevy::reflect::prelude::*;
+
+/// A string interned for the lifetime of the app, cheap to copy and compare.
+///
+/// Equal strings get the same index, so they can be created from data (files, user inputs...) and still be stored in `Copy` types.
+///
+/// The index is only valid in the current process, so the string is reflected as an opaque value that is (de)serialized through its text.
+/// Interned strings are never freed.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(opaque)]
+#[reflect(Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "animation_files", reflect(Serialize, Deserialize))]
+pub(crate) struct InternedStr {
+    index: u32,
+}
+
+#[derive(Default)]
+struct Interner {
+    strings: Vec<Arc<str>>,
+    indices: HashMap<Arc<str>, u32>,
+}
+
+fn interner() -> &'static RwLock<Interner> {
+    static INTERNER: OnceLock<RwLock<Interner>> = OnceLock::new();
+
+    INTERNER.get_or_init(Default::default)
+}
+
+impl InternedStr {
+    pub(crate) fn new(string: &str) -> Self {
+        if let Some(index) = interner().read().unwrap().indices.get(string) {
+            return Self { index: *index };
+        }
+
+        let mut interner = interner().write().unwrap();
+
+        // Another thread may have interned the string in the meantime
+
+        if let Some(index) = interner.indices.get(string) {
+            return Self { index: *index };
+        }
+
+        let string: Arc<str> = Arc::from(string);
+        let index = interner.strings.len() as u32;
+
+        interner.strings.push(string.clone());
+        interner.indices.insert(string, index);
+
+        Self { index }
+    }
+
+    pub(crate) fn get(&self) -> Arc<str> {
+        interner().read().unwrap().strings[self.index as usize].clone()
+    }
+}
+
+impl fmt::Debug for InternedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.get())
+    }
+}
+
+#[cfg(feature = "animation_files")]
+impl serde::Serialize for InternedStr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.get())
+    }
+}
+
+#[cfg(feature = "animation_files")]
+impl<'de> serde::Deserialize<'de> for InternedStr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+
+        Ok(Self::new(&string))
+    }
+}
@@ -74,13 +74,24 @@
 //! ```
 
 pub mod animation;
+#[cfg(feature = "animation_files")]
+pub mod animation_set;
 pub mod animator;
 pub mod clip;
+pub mod commands;
 pub mod components;
+pub mod diagnostics;
 pub mod easing;
 pub mod events;
+pub mod extension;
+mod hash;
+pub mod hooks;
+mod interned;
 pub mod library;
+mod macros;
 pub mod plugin;
+#[cfg(feature = "animation_prewarm")]
+pub mod prewarm;
 pub mod spritesheet;
 
 mod systems;
@@ -90,14 +101,78 @@ pub mod prelude {
         animation::{
             Animation, AnimationDirection, AnimationDuration, AnimationId, AnimationRepeat,
         },
-        clip::{Clip, ClipId},
-        components::{sprite3d::Sprite3d, spritesheet_animation::SpritesheetAnimation},
+        animator::{
+            cache::{AnimationCache, AnimationCacheEvent, CacheFrame},
+            sampling::{AnimationFrame, SampledFrame},
+            timeline::{AnimationTimeline, TimelineClip, TimelineFrame, TimelineMarker},
+            Animator, FrameSkipping,
+        },
+        clip::{Clip, ClipId, ClipImage, ClipTag, FrameLabel, MarkerRepetitions},
+        commands::AnimationCommands,
+        components::{
+            animation_event_filter::{
+                AnimationEventFilter, AnimationEventKinds, FilteredAnimationEvents,
+            },
+            animation_overrides::AnimationOverrides,
+            animation_scrubber::AnimationScrubber,
+            animation_state_machine::{
+                AnimationStateChanged, AnimationStateMachine, AnimationTransition,
+                TransitionCondition,
+            },
+            clip_target::ClipTarget,
+            despawn_on_animation_end::DespawnOnAnimationEnd,
+            flip_transition::{FlipTransition, FlipTransitionStyle},
+            frame_just_changed::FrameJustChanged,
+            frame_trail::{FrameTrail, FrameTrailSample},
+            hit_pause::{HitPauseGroup, HitPauseOnMarker, HitPauseScope},
+            image_node_frame_size::ImageNodeFrameSize,
+            image_sequence::ImageSequence,
+            marker_listener::MarkerListener,
+            no_animation_culling::NoAnimationCulling,
+            persistent_animation_key::PersistentAnimationKey,
+            phase_from_position::PhaseFromPosition,
+            play_variant::{PlayVariant, VariantSet},
+            playback_history::{PlaybackHistory, PlaybackRecord},
+            shake_on_marker::{CameraShakeRequest, ShakeOnMarker},
+            sprite3d::{
+                Sprite3d, Sprite3dFilter, Sprite3dPixelPerfect, Sprite3dPlaceholder, Sprite3dReady,
+                Sprite3dSampler, Sprite3dUvInset,
+            },
+            sprite_rig::SpriteRig,
+            spritesheet_animation::{
+                AnimationProgress, FrameRemap, ProgressError, SpritesheetAnimation,
+            },
+        },
+        diagnostics::{
+            AnimationDiagnostic, AnimationDiagnostics, DiagnosticLevel, DiagnosticsMode,
+        },
         easing::{Easing, EasingVariety},
-        events::{AnimationEvent, AnimationMarkerId},
-        library::{AnimationLibrary, LibraryError},
-        plugin::SpritesheetAnimationPlugin,
-        spritesheet::Spritesheet,
+        events::{AnimationEvent, AnimationEventSettings, AnimationMarkerId, MarkerGroup},
+        extension::{AnimationTarget, SpritesheetAnimationExt},
+        hooks::{FrameContext, FrameHook, FrameHooks, FrameInfo},
+        library::{AnimationEditGuard, AnimationLibrary, LibraryError, ValidationError},
+        plugin::{
+            AnimationCulling, EmptyAnimationBehavior, FocusLossBehavior, SpritesheetAnimationPlugin,
+        },
+        spritesheet::{
+            NamedSpritesheet, RectSpritesheet, Spritesheet, SpritesheetError, TraversalOrder,
+        },
     };
+
+    #[cfg(feature = "animation_files")]
+    pub use super::animation_set::{
+        AnimationDefinition, AnimationSet, AnimationSetError, AnimationSetLoader,
+        AnimationSetLoaderError, ClipDefinition,
+    };
+
+    #[cfg(feature = "gamepad_rumble")]
+    pub use super::components::marker_rumble::{MarkerRumble, RumblePattern};
+
+    #[cfg(feature = "animation_prewarm")]
+    pub use super::prewarm::{AnimationPrewarmPlugin, AnimationPrewarmProgress};
+
+    #[cfg(feature = "animation_states")]
+    pub use super::components::animation_by_state::AnimationByState;
 }
 
 const CRATE_NAME: &str = "bevy_spritesheet_animation";
@@ -1,15 +1,32 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    fmt,
+    hash::{Hash, Hasher},
     sync::Arc,
+    time::Duration,
 };
 
-use bevy::{ecs::reflect::*, prelude::Resource, reflect::prelude::*};
+use bevy::{
+    ecs::reflect::*, image::Image, prelude::Resource, reflect::prelude::*,
+    sprite::TextureAtlasLayout,
+};
 
 use crate::{
-    animator::cache::AnimationCache,
+    animator::{
+        cache::AnimationCache,
+        sampling::{self, AnimationFrame, SampledFrame},
+        timeline::{self, AnimationTimeline},
+    },
     clip::{Clip, ClipId},
-    events::AnimationMarkerId,
-    prelude::{Animation, AnimationId},
+    components::{
+        animation_overrides::AnimationOverrides,
+        spritesheet_animation::{AnimationProgress, ProgressError},
+    },
+    diagnostics,
+    events::{AnimationMarkerId, MarkerGroup},
+    hash::{hash_bytes, FNV_OFFSET_BASIS},
+    prelude::{Animation, AnimationDuration, AnimationId, AnimationRepeat},
+    spritesheet::Spritesheet,
 };
 
 /// Error type returned by some [AnimationLibrary] methods.
@@ -19,6 +36,70 @@ pub enum LibraryError {
     NameAlreadyTaken,
 }
 
+/// A problem detected in an animation by [AnimationLibrary::validate_animation].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The animation has no clips
+    NoClips,
+    /// A clip of the animation is not registered in the library
+    UnknownClip(ClipId),
+    /// A clip of the animation has no frames
+    EmptyClip(ClipId),
+    /// A frame of a clip references an atlas index beyond the frames of the spritesheet
+    InvalidAtlasIndex {
+        /// The clip with the invalid frame
+        clip_id: ClipId,
+        /// The index of the frame in the clip
+        frame_index: usize,
+        /// The atlas index of the frame
+        atlas_index: usize,
+        /// The number of frames in the spritesheet
+        frame_count: usize,
+    },
+    /// The duration of the animation is zero
+    ZeroAnimationDuration,
+    /// The duration of a clip is zero
+    ZeroClipDuration(ClipId),
+    /// The duration of a specific frame of a clip is zero
+    ZeroFrameDuration {
+        /// The clip with the invalid frame
+        clip_id: ClipId,
+        /// The index of the frame in the clip
+        frame_index: usize,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::NoClips => write!(f, "the animation has no clips"),
+            ValidationError::UnknownClip(clip_id) => write!(f, "{clip_id} is not registered"),
+            ValidationError::EmptyClip(clip_id) => write!(f, "{clip_id} has no frames"),
+            ValidationError::InvalidAtlasIndex {
+                clip_id,
+                frame_index,
+                atlas_index,
+                frame_count,
+            } => write!(
+                f,
+                "frame {frame_index} of {clip_id} has atlas index {atlas_index} but the spritesheet has {frame_count} frames"
+            ),
+            ValidationError::ZeroAnimationDuration => {
+                write!(f, "the duration of the animation is zero")
+            }
+            ValidationError::ZeroClipDuration(clip_id) => {
+                write!(f, "the duration of {clip_id} is zero")
+            }
+            ValidationError::ZeroFrameDuration {
+                clip_id,
+                frame_index,
+            } => write!(f, "the duration of frame {frame_index} of {clip_id} is zero"),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
 /// The animation library is the global store for clips and animations.
 ///
 /// When the [SpritesheetAnimationPlugin](crate::prelude::SpritesheetAnimationPlugin) is added to the app, the [AnimationLibrary] becomes available as a resource.
@@ -60,21 +141,114 @@ pub struct AnimationLibrary {
     /// Optional clip names
     clip_names: HashMap<ClipId, String>,
 
+    /// The number of clips registered with sequential IDs so far (clips registered with a name are not counted)
+    clip_count: usize,
+
     /// All the animations
     animations: HashMap<AnimationId, Animation>,
 
     /// Optional animation names
     animation_names: HashMap<AnimationId, String>,
 
+    /// IDs of the animations with the same content hash, for deduplication
+    animation_hashes: HashMap<u64, Vec<AnimationId>>,
+
     /// All the markers
     markers: HashSet<AnimationMarkerId>,
 
     /// Optional marker names
     marker_names: HashMap<AnimationMarkerId, String>,
 
+    /// The number of marker groups created so far
+    marker_group_count: usize,
+
+    /// The groups of each marker
+    marker_groups: HashMap<AnimationMarkerId, HashSet<MarkerGroup>>,
+
     /// Animation caches, one for each animation.
     /// They contain all the data required to play an animation.
     animation_caches: HashMap<AnimationId, Arc<AnimationCache>>,
+
+    /// Caches of the animations played with [AnimationOverrides], shared by the entities with the same overrides
+    #[reflect(ignore)]
+    overridden_animation_caches: HashMap<(AnimationId, AnimationOverrides), Arc<AnimationCache>>,
+
+    /// Optional memory budget for the animation caches, in bytes
+    cache_budget: Option<usize>,
+
+    /// The memory used by the animation caches, in bytes, updated as they are inserted and removed
+    cache_memory_size: usize,
+
+    /// Whether the caches are built on first use instead of when the animations are registered or edited
+    deferred_cache_builds: bool,
+
+    /// Last use of each animation cache, for evicting the least recently used ones
+    cache_last_use: HashMap<AnimationId, u64>,
+
+    /// Incremented every time an animation cache is used
+    cache_use_counter: u64,
+
+    /// Animations edited since the last update, whose playback must follow the changes
+    edited_animations: HashSet<AnimationId>,
+}
+
+/// A batch of edits of the [AnimationLibrary], returned by [AnimationLibrary::edit_batch].
+///
+/// The caches of the edited animations are rebuilt when the guard is dropped.
+pub struct AnimationEditGuard<'a> {
+    library: &'a mut AnimationLibrary,
+
+    /// The animations to rebuild when the guard is dropped
+    edited_animations: HashSet<AnimationId>,
+}
+
+impl AnimationEditGuard<'_> {
+    /// Edits a clip registered in the library, see [AnimationLibrary::edit_clip].
+    pub fn edit_clip(&mut self, clip_id: ClipId, edit: impl FnOnce(&mut Clip)) -> &mut Self {
+        let animation_ids = self.library.apply_clip_edit(clip_id, edit);
+
+        self.edited_animations.extend(animation_ids);
+        self
+    }
+
+    /// Edits an animation registered in the library, see [AnimationLibrary::edit_animation].
+    pub fn edit_animation(
+        &mut self,
+        animation_id: AnimationId,
+        edit: impl FnOnce(&mut Animation),
+    ) -> &mut Self {
+        if self.library.apply_animation_edit(animation_id, edit) {
+            self.edited_animations.insert(animation_id);
+        }
+        self
+    }
+
+    /// Returns the library being edited.
+    pub fn library(&self) -> &AnimationLibrary {
+        self.library
+    }
+}
+
+impl Drop for AnimationEditGuard<'_> {
+    fn drop(&mut self) {
+        for animation_id in std::mem::take(&mut self.edited_animations) {
+            // Catch the edits that broke the animation as early as possible
+
+            if cfg!(debug_assertions) {
+                let errors = self
+                    .library
+                    .validate_animation(self.library.get_animation(animation_id), None);
+
+                for error in errors {
+                    diagnostics::warning(format_args!(
+                        "animation {animation_id} is invalid after a batch of edits: {error}"
+                    ));
+                }
+            }
+
+            self.library.rebuild_animation_cache(animation_id);
+        }
+    }
 }
 
 impl AnimationLibrary {
@@ -97,14 +271,136 @@ impl AnimationLibrary {
     /// ```
     pub fn register_clip(&mut self, clip: Clip) -> ClipId {
         let id = ClipId {
-            value: self.clips.len(),
+            value: self.clip_count,
         };
 
+        self.clip_count += 1;
+
         self.clips.insert(id, clip);
 
         id
     }
 
+    /// Registers a [Clip] with a name and returns its ID.
+    ///
+    /// Unlike [AnimationLibrary::register_clip], the ID is derived from the name (see [ClipId::from_name]):
+    /// it stays the same across reloads, regardless of the registration order.
+    ///
+    /// Registering a clip with a name that was already registered this way replaces the previous clip,
+    /// as when hot-reloading game data: the caches of the animations that use it are rebuilt.
+    ///
+    /// Returns a [LibraryError::NameAlreadyTaken] error if the name was given to another clip with [AnimationLibrary::name_clip].
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - the name of the clip
+    /// * `clip` - the clip to register
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let clip_id = library.register_named_clip("run", Clip::from_frames([4, 5, 6])).unwrap();
+    ///
+    /// assert_eq!(clip_id, ClipId::from_name("run"));
+    /// assert_eq!(library.clip_with_name("run"), Some(clip_id));
+    /// ```
+    pub fn register_named_clip(
+        &mut self,
+        name: impl Into<String>,
+        clip: Clip,
+    ) -> Result<ClipId, LibraryError> {
+        let name = name.into();
+        let id = ClipId::from_name(&name);
+
+        if self
+            .clip_with_name(&name)
+            .is_some_and(|existing_clip_id| existing_clip_id != id)
+        {
+            return Err(LibraryError::NameAlreadyTaken);
+        }
+
+        if self.clips.contains_key(&id) {
+            self.edit_clip(id, |existing_clip| *existing_clip = clip);
+        } else {
+            self.clips.insert(id, clip);
+        }
+
+        self.clip_names.insert(id, name);
+
+        Ok(id)
+    }
+
+    /// Registers a reversed copy of an existing clip and returns its ID.
+    ///
+    /// This is convenient for turn-around or rewind sequences that reuse the frames of another clip.
+    /// See [Clip::reversed] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let turn_clip_id = library.register_clip(Clip::from_frames([4, 5, 6]));
+    ///
+    /// let turn_back_clip_id = library.copy_clip_reversed(turn_clip_id);
+    ///
+    /// assert_eq!(library.get_clip(turn_back_clip_id).frames(), vec![6, 5, 4]);
+    /// ```
+    pub fn copy_clip_reversed(&mut self, clip_id: ClipId) -> ClipId {
+        let reversed_clip = self.get_clip(clip_id).reversed();
+
+        self.register_clip(reversed_clip)
+    }
+
+    /// Registers a looping animation that plays all the frames of a [strip](Spritesheet::strip) and returns its ID.
+    ///
+    /// This is a shorthand for the common case of spritesheets with a single row of frames.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_count` - the number of frames in the strip
+    /// * `duration` - the duration of the animation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// fn setup(
+    ///     mut commands: Commands,
+    ///     mut library: ResMut<AnimationLibrary>,
+    ///     mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    ///     assets: Res<AssetServer>,
+    /// ) {
+    ///     let animation_id = library.create_strip_animation(8, AnimationDuration::PerFrame(100));
+    ///
+    ///     commands.spawn(Spritesheet::new(8, 1).animated_sprite(
+    ///         assets.load("coin.png"),
+    ///         16,
+    ///         16,
+    ///         &mut atlas_layouts,
+    ///         animation_id,
+    ///     ));
+    /// }
+    /// ```
+    pub fn create_strip_animation(
+        &mut self,
+        frame_count: usize,
+        duration: AnimationDuration,
+    ) -> AnimationId {
+        let clip = Clip::from_frames(Spritesheet::new(frame_count, 1).all());
+
+        let clip_id = self.register_clip(clip);
+
+        let animation = Animation::from_clip(clip_id)
+            .with_duration(duration)
+            .with_repetitions(AnimationRepeat::Loop);
+
+        self.register_animation(animation)
+    }
+
     /// Associates a unique name to a clip.
     ///
     /// The clip ID can then later be queried from that name with [AnimationLibrary::clip_with_name].
@@ -149,6 +445,17 @@ impl AnimationLibrary {
         }
     }
 
+    /// Removes the name of a clip, if it has one, and returns it.
+    ///
+    /// The clip stays registered and the name can be given to another clip.
+    ///
+    /// # Arguments
+    ///
+    /// * `clip_id` - the ID of the clip to unname
+    pub fn remove_clip_name(&mut self, clip_id: ClipId) -> Option<String> {
+        self.clip_names.remove(&clip_id)
+    }
+
     /// Returns all the clip names registered in the library.
     pub fn clip_names(&self) -> &HashMap<ClipId, String> {
         &self.clip_names
@@ -208,6 +515,52 @@ impl AnimationLibrary {
         self.clips.get(&clip_id).unwrap()
     }
 
+    /// Edits a clip registered in the library.
+    ///
+    /// The caches of the animations that use this clip are rebuilt
+    /// and the entities currently playing them continue from their current frame with the new parameters.
+    ///
+    /// From systems, [AnimationCommands](crate::prelude::AnimationCommands) defers the edits to avoid borrowing the library.
+    /// [AnimationLibrary::edit_batch] defers the cache rebuilds to apply many edits at once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let clip_id = library.register_clip(Clip::from_frames([1, 2, 3]));
+    ///
+    /// library.edit_clip(clip_id, |clip| {
+    ///     clip.set_duration(AnimationDuration::PerFrame(50));
+    /// });
+    /// ```
+    pub fn edit_clip(&mut self, clip_id: ClipId, edit: impl FnOnce(&mut Clip)) {
+        for animation_id in self.apply_clip_edit(clip_id, edit) {
+            self.rebuild_animation_cache(animation_id);
+        }
+    }
+
+    /// Edits a clip without rebuilding the caches, returning the animations that use it
+    fn apply_clip_edit(
+        &mut self,
+        clip_id: ClipId,
+        edit: impl FnOnce(&mut Clip),
+    ) -> Vec<AnimationId> {
+        // In practice, the clip always exists as the library is the sole creator of IDs
+
+        let Some(clip) = self.clips.get_mut(&clip_id) else {
+            return Vec::new();
+        };
+
+        edit(clip);
+
+        self.animations
+            .iter()
+            .filter(|(_, animation)| animation.clip_ids().contains(&clip_id))
+            .map(|(animation_id, _)| *animation_id)
+            .collect()
+    }
+
     /// Registers an new [Animation] and returns its ID.
     ///
     /// The animation can then be referenced in [SpritesheetAnimation](crate::prelude::SpritesheetAnimation) components.
@@ -248,14 +601,206 @@ impl AnimationLibrary {
             value: self.animations.len(),
         };
 
+        self.animation_hashes
+            .entry(Self::animation_hash(&animation))
+            .or_default()
+            .push(id);
+
         self.animations.insert(id, animation);
 
-        self.animation_caches
-            .insert(id, Arc::new(AnimationCache::new(id, self)));
+        if !self.deferred_cache_builds {
+            self.insert_animation_cache(id, Arc::new(AnimationCache::new(id, self)));
+
+            self.mark_cache_use(id);
+            self.evict_caches(None);
+        }
 
         id
     }
 
+    /// Registers an new [Animation] and returns its ID, after checking that it is valid.
+    ///
+    /// Unlike [AnimationLibrary::register_animation], which accepts anything and only reports issues at runtime (if at all),
+    /// the animation is not registered if [AnimationLibrary::validate_animation] finds problems: all of them are returned instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `animation` - the animation to register
+    /// * `frame_count` - the (optional) number of frames in the spritesheet, to check the atlas indices of the clips
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let spritesheet = Spritesheet::new(4, 2);
+    ///
+    /// let clip_id = library.register_clip(Clip::from_frames([6, 7, 8]));
+    ///
+    /// let result = library.try_register_animation(
+    ///     Animation::from_clip(clip_id),
+    ///     Some(spritesheet.columns() * spritesheet.rows()),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     result,
+    ///     Err(vec![ValidationError::InvalidAtlasIndex {
+    ///         clip_id,
+    ///         frame_index: 2,
+    ///         atlas_index: 8,
+    ///         frame_count: 8
+    ///     }])
+    /// );
+    /// ```
+    pub fn try_register_animation(
+        &mut self,
+        animation: Animation,
+        frame_count: Option<usize>,
+    ) -> Result<AnimationId, Vec<ValidationError>> {
+        let errors = self.validate_animation(&animation, frame_count);
+
+        if errors.is_empty() {
+            Ok(self.register_animation(animation))
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Checks an [Animation] and its clips for problems that would make it play incorrectly.
+    ///
+    /// Returns all the problems found, which is empty if the animation is valid:
+    /// - the animation has no clips, or references clips that are not registered
+    /// - some clips have no frames
+    /// - some frames have atlas indices beyond the frames of the spritesheet, if `frame_count` is provided
+    /// - the animation, some clips or some frames have a zero duration
+    ///
+    /// # Arguments
+    ///
+    /// * `animation` - the animation to check
+    /// * `frame_count` - the (optional) number of frames in the spritesheet, to check the atlas indices of the clips
+    pub fn validate_animation(
+        &self,
+        animation: &Animation,
+        frame_count: Option<usize>,
+    ) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        if animation.clip_ids().is_empty() {
+            errors.push(ValidationError::NoClips);
+        }
+
+        if matches!(
+            animation.duration(),
+            Some(AnimationDuration::PerFrame(0) | AnimationDuration::PerRepetition(0))
+        ) || *animation.retimed_duration() == Some(0)
+        {
+            errors.push(ValidationError::ZeroAnimationDuration);
+        }
+
+        // Clips used several times by the animation are only checked once
+
+        let mut checked_clip_ids = HashSet::new();
+
+        for clip_id in animation.clip_ids() {
+            if !checked_clip_ids.insert(*clip_id) {
+                continue;
+            }
+
+            let Some(clip) = self.clips.get(clip_id) else {
+                errors.push(ValidationError::UnknownClip(*clip_id));
+                continue;
+            };
+
+            if clip.frames().is_empty() {
+                errors.push(ValidationError::EmptyClip(*clip_id));
+            }
+
+            if let Some(frame_count) = frame_count {
+                for (frame_index, atlas_index) in clip.frames().iter().enumerate() {
+                    if *atlas_index >= frame_count {
+                        errors.push(ValidationError::InvalidAtlasIndex {
+                            clip_id: *clip_id,
+                            frame_index,
+                            atlas_index: *atlas_index,
+                            frame_count,
+                        });
+                    }
+                }
+            }
+
+            if matches!(
+                clip.duration(),
+                Some(AnimationDuration::PerFrame(0) | AnimationDuration::PerRepetition(0))
+            ) {
+                errors.push(ValidationError::ZeroClipDuration(*clip_id));
+            }
+
+            let mut zero_duration_frames: Vec<usize> = clip
+                .frame_durations()
+                .iter()
+                .filter(|(frame_index, duration)| {
+                    **duration == 0 && **frame_index < clip.frames().len()
+                })
+                .map(|(frame_index, _)| *frame_index)
+                .collect();
+
+            zero_duration_frames.sort();
+
+            errors.extend(zero_duration_frames.into_iter().map(|frame_index| {
+                ValidationError::ZeroFrameDuration {
+                    clip_id: *clip_id,
+                    frame_index,
+                }
+            }));
+        }
+
+        errors
+    }
+
+    /// Registers an [Animation] and returns its ID, unless an identical animation has already been registered.
+    ///
+    /// In that case, the ID of the existing animation is returned instead,
+    /// which avoids storing and caching the same animation several times.
+    /// This is convenient when generating many animations programmatically, such as variants for several directions that end up being the same.
+    ///
+    /// Animations are identical if they have the same clips and parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `animation` - the animation to register
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let clip_id = library.register_clip(Clip::from_frames([1, 2, 3]));
+    ///
+    /// let animation_id = library.register_animation(Animation::from_clip(clip_id));
+    ///
+    /// let same_animation_id = library.register_animation_deduplicated(Animation::from_clip(clip_id));
+    ///
+    /// assert_eq!(animation_id, same_animation_id);
+    /// ```
+    pub fn register_animation_deduplicated(&mut self, animation: Animation) -> AnimationId {
+        let existing_id = self
+            .animation_hashes
+            .get(&Self::animation_hash(&animation))
+            .and_then(|ids| {
+                ids.iter()
+                    .find(|id| self.animations.get(*id) == Some(&animation))
+            })
+            .copied();
+
+        existing_id.unwrap_or_else(|| self.register_animation(animation))
+    }
+
+    fn animation_hash(animation: &Animation) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        animation.hash(&mut hasher);
+        hasher.finish()
+    }
+
     /// Associates a unique name to an animation.
     ///
     /// The animation ID can then later be queried from that name with [AnimationLibrary::animation_with_name].
@@ -303,6 +848,17 @@ impl AnimationLibrary {
         }
     }
 
+    /// Removes the name of an animation, if it has one, and returns it.
+    ///
+    /// The animation stays registered and the name can be given to another animation.
+    ///
+    /// # Arguments
+    ///
+    /// * `animation_id` - the ID of the animation to unname
+    pub fn remove_animation_name(&mut self, animation_id: AnimationId) -> Option<String> {
+        self.animation_names.remove(&animation_id)
+    }
+
     /// Returns all the animation names registered in the library.
     pub fn animation_names(&self) -> &HashMap<AnimationId, String> {
         &self.animation_names
@@ -364,60 +920,420 @@ impl AnimationLibrary {
         self.animations.get(&animation_id).unwrap()
     }
 
-    /// Creates a new animation marker and returns a unique ID to refer to it.
+    /// Edits an animation registered in the library.
     ///
-    /// The marker can then be inserted into [Clip]s and an [AnimationEvent::MarkerHit](crate::prelude::AnimationEvent::MarkerHit) event
-    /// will be emitted whenever an animation reaches it.
+    /// The cache of the animation is rebuilt
+    /// and the entities currently playing it continue from their current frame with the new parameters.
     ///
-    /// For more details, see the documentation of [AnimationEvent](crate::prelude::AnimationEvent).
+    /// From systems, [AnimationCommands](crate::prelude::AnimationCommands) defers the edits to avoid borrowing the library.
+    /// [AnimationLibrary::edit_batch] defers the cache rebuilds to apply many edits at once.
     ///
     /// # Example
     ///
     /// ```
     /// # use bevy_spritesheet_animation::prelude::*;
     /// # let mut library = AnimationLibrary::default();
-    /// let marker = library.new_marker();
+    /// # let clip_id = library.register_clip(Clip::from_frames([1, 2, 3]));
+    /// let animation_id = library.register_animation(Animation::from_clip(clip_id));
     ///
-    /// let clip = Clip::from_frames([7, 8, 9, 10, 11, 12])
-    ///     .with_marker(marker, 3);
+    /// library.edit_animation(animation_id, |animation| {
+    ///     animation.set_repetitions(AnimationRepeat::Loop);
+    /// });
     /// ```
-    pub fn new_marker(&mut self) -> AnimationMarkerId {
-        let id = AnimationMarkerId {
-            value: self.markers.len(),
+    pub fn edit_animation(&mut self, animation_id: AnimationId, edit: impl FnOnce(&mut Animation)) {
+        if self.apply_animation_edit(animation_id, edit) {
+            self.rebuild_animation_cache(animation_id);
+        }
+    }
+
+    /// Edits an animation without rebuilding its cache, returning whether it exists
+    fn apply_animation_edit(
+        &mut self,
+        animation_id: AnimationId,
+        edit: impl FnOnce(&mut Animation),
+    ) -> bool {
+        // In practice, the animation always exists as the library is the sole creator of IDs
+
+        let Some(animation) = self.animations.get_mut(&animation_id) else {
+            return false;
         };
 
-        self.markers.insert(id);
+        let previous_hash = Self::animation_hash(animation);
 
-        id
+        edit(animation);
+
+        let hash = Self::animation_hash(animation);
+
+        // Keep the deduplication up to date
+
+        if hash != previous_hash {
+            if let Some(ids) = self.animation_hashes.get_mut(&previous_hash) {
+                ids.retain(|id| *id != animation_id);
+            }
+
+            self.animation_hashes
+                .entry(hash)
+                .or_default()
+                .push(animation_id);
+        }
+
+        true
     }
 
-    /// Associates a unique name to an animation marker.
-    ///
-    /// The marker ID can then later be queried from that name with [AnimationLibrary::marker_with_name].
+    /// Starts a batch of edits whose cache rebuilds are deferred until the returned guard is dropped.
     ///
-    /// Returns a [LibraryError::NameAlreadyTaken] error if the name is already in use.
+    /// [AnimationLibrary::edit_clip] and [AnimationLibrary::edit_animation] rebuild the caches of the edited animations immediately,
+    /// which is wasteful when editing many clips shared by the same animations,
+    /// and which exposes the animations in intermediate states if the edits are spread over time.
+    /// With a guard, each edited animation is rebuilt once, when the guard is dropped.
     ///
-    /// # Arguments
+    /// This is the supported way to edit many animations at once while they are played:
+    /// the guard borrows the library mutably, so the animations cannot be played before all the edits are applied.
+    /// From systems, use [AnimationCommands::edit_batch](crate::prelude::AnimationCommands::edit_batch).
     ///
-    /// * `marker_id` - the ID of the marker to name
-    /// * `name` - the name to assign
+    /// In debug builds, the edited animations are validated when the guard is dropped
+    /// and the problems are reported as warnings (see [AnimationLibrary::validate_animation]).
     ///
     /// # Example
     ///
     /// ```
     /// # use bevy_spritesheet_animation::prelude::*;
     /// # let mut library = AnimationLibrary::default();
-    /// let marker_id = library.new_marker();
+    /// let run_clip_id = library.register_clip(Clip::from_frames([1, 2, 3]));
+    /// let stop_clip_id = library.register_clip(Clip::from_frames([4, 5]));
     ///
-    /// library.name_marker(marker_id, "raise sword");
+    /// let animation_id = library.register_animation(Animation::from_clips([run_clip_id, stop_clip_id]));
     ///
-    /// assert_eq!(library.marker_with_name("raise sword"), Some(marker_id));
-    /// assert!(library.is_marker_name(marker_id, "raise sword"));
+    /// {
+    ///     let mut edits = library.edit_batch();
+    ///
+    ///     edits
+    ///         .edit_clip(run_clip_id, |clip| {
+    ///             clip.set_duration(AnimationDuration::PerFrame(50));
+    ///         })
+    ///         .edit_clip(stop_clip_id, |clip| {
+    ///             clip.set_duration(AnimationDuration::PerFrame(200));
+    ///         });
+    ///
+    ///     // The cache of the animation is rebuilt once, here
+    /// }
     /// ```
-    pub fn name_marker(
-        &mut self,
-        marker_id: AnimationMarkerId,
-        name: impl Into<String>,
+    pub fn edit_batch(&mut self) -> AnimationEditGuard<'_> {
+        AnimationEditGuard {
+            library: self,
+            edited_animations: HashSet::new(),
+        }
+    }
+
+    /// Samples an animation at an arbitrary time, without playing it on an entity.
+    ///
+    /// Returns the frame displayed at `time` along with the markers reached in the time range `(previous_time, time]`
+    /// (or `[0, time]` if `previous_time` is `None`).
+    /// Past the end of the animation, its last frame is returned.
+    ///
+    /// Returns `None` if the animation has no frames.
+    ///
+    /// This is useful to validate gameplay on a server or to inspect animations in tools.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let hit = library.new_marker();
+    ///
+    /// let clip = Clip::from_frames([1, 2, 3])
+    ///     .with_duration(AnimationDuration::PerFrame(100))
+    ///     .with_marker(hit, 1);
+    ///
+    /// let clip_id = library.register_clip(clip);
+    ///
+    /// let animation_id = library.register_animation(Animation::from_clip(clip_id));
+    ///
+    /// let frame = library
+    ///     .sample_animation(
+    ///         animation_id,
+    ///         Some(Duration::from_millis(50)),
+    ///         Duration::from_millis(150),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(frame.atlas_index, 2);
+    /// assert_eq!(frame.markers, vec![hit]);
+    /// ```
+    pub fn sample_animation(
+        &self,
+        animation_id: AnimationId,
+        previous_time: Option<Duration>,
+        time: Duration,
+    ) -> Option<SampledFrame> {
+        sampling::sample(self.peek_animation_cache(animation_id), previous_time, time)
+    }
+
+    /// Samples an animation some time after a given progress, without playing it on an entity.
+    ///
+    /// Returns the frame displayed `elapsed` after the start of the frame at `progress`,
+    /// along with the markers reached in the meantime (the markers of the starting frame are not included).
+    /// The repetitions of the animation are followed and, past its end, its last frame is returned.
+    ///
+    /// Returns an error if the progress is invalid for this animation and `None` if the animation has no frames.
+    ///
+    /// This is useful for networked sprites: clients receive authoritative progresses that are slightly delayed
+    /// and can animate smoothly between them by sampling from the last progress with the local time elapsed since it was captured.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// struct Snapshot {
+    ///     progress: AnimationProgress,
+    ///     // When the progress was captured, on a clock shared with the server
+    ///     time: Duration,
+    /// }
+    ///
+    /// fn display_frame(
+    ///     library: &AnimationLibrary,
+    ///     animation_id: AnimationId,
+    ///     snapshot: &Snapshot,
+    ///     render_time: Duration,
+    ///     sprite: &mut Sprite,
+    /// ) {
+    ///     let elapsed = render_time.saturating_sub(snapshot.time);
+    ///
+    ///     if let Ok(Some(frame)) =
+    ///         library.sample_animation_from(animation_id, snapshot.progress, elapsed)
+    ///     {
+    ///         if let Some(atlas) = &mut sprite.texture_atlas {
+    ///             atlas.index = frame.atlas_index;
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn sample_animation_from(
+        &self,
+        animation_id: AnimationId,
+        progress: AnimationProgress,
+        elapsed: Duration,
+    ) -> Result<Option<SampledFrame>, ProgressError> {
+        sampling::sample_from(self.peek_animation_cache(animation_id), progress, elapsed)
+    }
+
+    /// Lists the frames of an animation in play order, without playing it on an entity.
+    ///
+    /// The frames of the first repetition of the animation are listed, with the parameters of the animation and its clips applied.
+    ///
+    /// This is useful to check animations in tests without running an app.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let spritesheet = Spritesheet::new(8, 8);
+    ///
+    /// let clip = Clip::from_frames(spritesheet.row(5))
+    ///     .with_duration(AnimationDuration::PerRepetition(1000));
+    ///
+    /// let clip_id = library.register_clip(clip);
+    ///
+    /// let animation_id = library.register_animation(Animation::from_clip(clip_id));
+    ///
+    /// for frame in library.animation_frames(animation_id) {
+    ///     assert!(frame.duration <= Duration::from_millis(200));
+    ///     assert_eq!(frame.atlas_index / 8, 5);
+    /// }
+    /// ```
+    pub fn animation_frames(
+        &self,
+        animation_id: AnimationId,
+    ) -> impl Iterator<Item = AnimationFrame> {
+        sampling::frames(self.peek_animation_cache(animation_id))
+    }
+
+    /// Computes a hash of the pixels displayed by each frame of an animation, in play order.
+    ///
+    /// The frames are listed as with [AnimationLibrary::animation_frames] and the pixels of each frame are read on the CPU
+    /// from the area of the image given by the atlas layout.
+    /// The hashes are stable across platforms and versions, so that tests can store them and detect when a new export of the art
+    /// shifted the frames of an animation even though their indices are still valid.
+    ///
+    /// Only the first mip level of the image is hashed.
+    ///
+    /// A frame has no hash if its atlas index is not in the atlas layout or if its area exceeds the image.
+    /// No frame has a hash if the image uses a compressed or depth-stencil texture format.
+    ///
+    /// # Arguments
+    ///
+    /// * `animation_id` - the animation to hash the frames of
+    /// * `image` - the image of the spritesheet, with its data on the CPU
+    /// * `atlas_layout` - the atlas layout of the spritesheet
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// fn check_walk_frames(
+    ///     library: &AnimationLibrary,
+    ///     walk_id: AnimationId,
+    ///     image: &Image,
+    ///     atlas_layout: &TextureAtlasLayout,
+    /// ) {
+    ///     let hashes = library.animation_frame_hashes(walk_id, image, atlas_layout);
+    ///
+    ///     // Compare with the hashes stored when the animation was validated...
+    /// }
+    /// ```
+    pub fn animation_frame_hashes(
+        &self,
+        animation_id: AnimationId,
+        image: &Image,
+        atlas_layout: &TextureAtlasLayout,
+    ) -> Vec<Option<u64>> {
+        let image_size = image.size();
+        let format = image.texture_descriptor.format;
+
+        // Compressed formats store blocks of pixels that cannot be sliced along the frame areas
+
+        let bytes_per_pixel = if format.is_compressed() {
+            None
+        } else {
+            format
+                .block_copy_size(None)
+                .map(|block_size| block_size as usize)
+        };
+
+        self.animation_frames(animation_id)
+            .map(|frame| {
+                let bytes_per_pixel = bytes_per_pixel?;
+                let rect = atlas_layout.textures.get(frame.atlas_index)?;
+
+                // Only the first mip level is read, which comes first in the data of the image
+
+                let mip_size = image_size.x as usize * image_size.y as usize * bytes_per_pixel;
+
+                if rect.max.x > image_size.x
+                    || rect.max.y > image_size.y
+                    || image.data.len() < mip_size
+                {
+                    return None;
+                }
+
+                // Include the size of the frame so that different areas with the same pixels do not match
+
+                let mut hash = hash_bytes(FNV_OFFSET_BASIS, &rect.width().to_le_bytes());
+                hash = hash_bytes(hash, &rect.height().to_le_bytes());
+
+                for y in rect.min.y..rect.max.y {
+                    let start = (y as usize * image_size.x as usize + rect.min.x as usize)
+                        * bytes_per_pixel;
+                    let end = start + rect.width() as usize * bytes_per_pixel;
+
+                    hash = hash_bytes(hash, &image.data[start..end]);
+                }
+
+                Some(hash)
+            })
+            .collect()
+    }
+
+    /// Returns the timeline of an animation, with the start and end times of its clips and the times of its markers.
+    ///
+    /// This is intended for editors and tools that display timeline tracks.
+    /// The timeline is computed from the parameters of the animation and its clips, so it should be retrieved again after they are edited.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let marker = library.new_marker();
+    ///
+    /// let clip = Clip::from_frames([0, 1, 2, 3])
+    ///     .with_duration(AnimationDuration::PerFrame(100))
+    ///     .with_marker(marker, 2);
+    ///
+    /// let clip_id = library.register_clip(clip);
+    ///
+    /// let animation_id = library.register_animation(Animation::from_clip(clip_id));
+    ///
+    /// let timeline = library.animation_timeline(animation_id);
+    ///
+    /// assert_eq!(timeline.duration, Duration::from_millis(400));
+    /// assert_eq!(timeline.markers[0].time, Duration::from_millis(200));
+    /// ```
+    pub fn animation_timeline(&self, animation_id: AnimationId) -> AnimationTimeline {
+        timeline::timeline(&self.peek_animation_cache(animation_id))
+    }
+
+    /// Returns the pre-computed frames of an animation, with their durations and events.
+    ///
+    /// This is useful for systems that schedule things ahead of the playback,
+    /// like spawning pooled effects a few frames before a marker is hit (see [AnimationCache::frame_events]),
+    /// or for tools that display the timeline of an animation (see [AnimationCache::repetition_frames]).
+    pub fn animation_cache(&self, animation_id: AnimationId) -> Arc<AnimationCache> {
+        self.peek_animation_cache(animation_id)
+    }
+
+    /// Creates a new animation marker and returns a unique ID to refer to it.
+    ///
+    /// The marker can then be inserted into [Clip]s and an [AnimationEvent::MarkerHit](crate::prelude::AnimationEvent::MarkerHit) event
+    /// will be emitted whenever an animation reaches it.
+    ///
+    /// For more details, see the documentation of [AnimationEvent](crate::prelude::AnimationEvent).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let marker = library.new_marker();
+    ///
+    /// let clip = Clip::from_frames([7, 8, 9, 10, 11, 12])
+    ///     .with_marker(marker, 3);
+    /// ```
+    pub fn new_marker(&mut self) -> AnimationMarkerId {
+        let id = AnimationMarkerId {
+            value: self.markers.len(),
+        };
+
+        self.markers.insert(id);
+
+        id
+    }
+
+    /// Associates a unique name to an animation marker.
+    ///
+    /// The marker ID can then later be queried from that name with [AnimationLibrary::marker_with_name].
+    ///
+    /// Returns a [LibraryError::NameAlreadyTaken] error if the name is already in use.
+    ///
+    /// # Arguments
+    ///
+    /// * `marker_id` - the ID of the marker to name
+    /// * `name` - the name to assign
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let marker_id = library.new_marker();
+    ///
+    /// library.name_marker(marker_id, "raise sword");
+    ///
+    /// assert_eq!(library.marker_with_name("raise sword"), Some(marker_id));
+    /// assert!(library.is_marker_name(marker_id, "raise sword"));
+    /// ```
+    pub fn name_marker(
+        &mut self,
+        marker_id: AnimationMarkerId,
+        name: impl Into<String>,
     ) -> Result<(), LibraryError> {
         let name = name.into();
 
@@ -487,10 +1403,388 @@ impl AnimationLibrary {
         &self.markers
     }
 
-    /// Returns the cache for an animation registered in the library
-    pub(crate) fn get_animation_cache(&self, animation_id: AnimationId) -> Arc<AnimationCache> {
-        // In practice, this cannot fail as the library is the sole creator of IDs
-        // and the cache is created when registering the animation
-        self.animation_caches.get(&animation_id).unwrap().clone()
+    /// Creates a new group of animation markers.
+    ///
+    /// Groups gather markers with the same meaning across many animations, like all the footsteps,
+    /// so that the systems reacting to [AnimationEvent::MarkerHit](crate::prelude::AnimationEvent::MarkerHit) events
+    /// can match them by group instead of keeping track of every single marker.
+    ///
+    /// A marker can belong to several groups.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Footsteps(MarkerGroup);
+    ///
+    /// fn setup(mut commands: Commands, mut library: ResMut<AnimationLibrary>) {
+    ///     let footsteps = library.new_marker_group();
+    ///
+    ///     let walk_step = library.new_marker();
+    ///     let run_step = library.new_marker();
+    ///
+    ///     library.add_marker_to_group(walk_step, footsteps);
+    ///     library.add_marker_to_group(run_step, footsteps);
+    ///
+    ///     commands.insert_resource(Footsteps(footsteps));
+    /// }
+    ///
+    /// fn play_footstep_sounds(
+    ///     mut events: EventReader<AnimationEvent>,
+    ///     library: Res<AnimationLibrary>,
+    ///     footsteps: Res<Footsteps>,
+    /// ) {
+    ///     for event in events.read() {
+    ///         if let AnimationEvent::MarkerHit { marker_id, .. } = event {
+    ///             if library.is_marker_in_group(*marker_id, footsteps.0) {
+    ///                 // Play a footstep sound...
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn new_marker_group(&mut self) -> MarkerGroup {
+        let group = MarkerGroup {
+            value: self.marker_group_count,
+        };
+
+        self.marker_group_count += 1;
+
+        group
+    }
+
+    /// Adds an animation marker to a group, see [AnimationLibrary::new_marker_group].
+    ///
+    /// # Arguments
+    ///
+    /// * `marker_id` - the marker to add to the group
+    /// * `group` - the group
+    pub fn add_marker_to_group(&mut self, marker_id: AnimationMarkerId, group: MarkerGroup) {
+        self.marker_groups
+            .entry(marker_id)
+            .or_default()
+            .insert(group);
+    }
+
+    /// Removes an animation marker from a group.
+    ///
+    /// # Arguments
+    ///
+    /// * `marker_id` - the marker to remove from the group
+    /// * `group` - the group
+    pub fn remove_marker_from_group(&mut self, marker_id: AnimationMarkerId, group: MarkerGroup) {
+        if let Some(groups) = self.marker_groups.get_mut(&marker_id) {
+            groups.remove(&group);
+        }
+    }
+
+    /// Returns true if an animation marker belongs to a group.
+    ///
+    /// # Arguments
+    ///
+    /// * `marker_id` - the marker to check
+    /// * `group` - the group
+    pub fn is_marker_in_group(&self, marker_id: AnimationMarkerId, group: MarkerGroup) -> bool {
+        self.marker_groups
+            .get(&marker_id)
+            .is_some_and(|groups| groups.contains(&group))
+    }
+
+    /// Returns the animation markers that belong to a group.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - the group
+    pub fn markers_in_group(&self, group: MarkerGroup) -> HashSet<AnimationMarkerId> {
+        self.marker_groups
+            .iter()
+            .filter(|(_, groups)| groups.contains(&group))
+            .map(|(marker_id, _)| *marker_id)
+            .collect()
+    }
+
+    /// Sets a memory budget for the animation caches, in bytes.
+    ///
+    /// The library pre-computes a cache for each animation with all the data required to play it.
+    /// Applications that cycle through many animations during long sessions can set a budget to bound the memory used by those caches:
+    /// once the budget is exceeded, the least recently played caches are evicted and they will be rebuilt the next time their animation is played.
+    ///
+    /// The caches of the animations currently played by entities are never evicted.
+    ///
+    /// There is no budget by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// library.set_cache_budget(Some(16 * 1024 * 1024));
+    /// ```
+    pub fn set_cache_budget(&mut self, budget_bytes: Option<usize>) {
+        self.cache_budget = budget_bytes;
+        self.evict_caches(None);
+    }
+
+    /// Defers the creation of the animation caches.
+    ///
+    /// By default, the cache of an animation is built as soon as it is registered or edited.
+    /// When deferred, it is built the first time the animation is played instead,
+    /// or ahead of time on the task pool during a loading state with [AnimationPrewarmPlugin](crate::prelude::AnimationPrewarmPlugin).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// library.set_deferred_cache_builds(true);
+    ///
+    /// let clip_id = library.register_clip(Clip::from_frames([0, 1, 2]));
+    /// library.register_animation(Animation::from_clip(clip_id));
+    ///
+    /// assert_eq!(library.cache_memory_size(), 0);
+    /// ```
+    pub fn set_deferred_cache_builds(&mut self, deferred: bool) {
+        self.deferred_cache_builds = deferred;
+    }
+
+    /// Returns true if the creation of the animation caches is deferred, see [AnimationLibrary::set_deferred_cache_builds].
+    pub fn deferred_cache_builds(&self) -> bool {
+        self.deferred_cache_builds
+    }
+
+    /// Returns the memory budget for the animation caches, in bytes.
+    pub fn cache_budget(&self) -> Option<usize> {
+        self.cache_budget
+    }
+
+    /// Returns an estimation of the memory used by the animation caches, in bytes.
+    pub fn cache_memory_size(&self) -> usize {
+        self.cache_memory_size
+    }
+
+    /// Checks that a progress is valid for an animation registered in the library
+    pub(crate) fn validate_progress(
+        &self,
+        animation_id: AnimationId,
+        progress: AnimationProgress,
+    ) -> Result<(), ProgressError> {
+        match self.animation_caches.get(&animation_id) {
+            Some(cache) => cache.validate_progress(progress),
+            // The cache may have been evicted
+            None => AnimationCache::new(animation_id, self).validate_progress(progress),
+        }
+    }
+
+    /// Returns the cache for an animation registered in the library, rebuilding it if it has been evicted
+    pub(crate) fn get_animation_cache(&mut self, animation_id: AnimationId) -> Arc<AnimationCache> {
+        // In practice, the animation always exists as the library is the sole creator of IDs
+
+        let cache = match self.animation_caches.get(&animation_id) {
+            Some(cache) => cache.clone(),
+            None => {
+                let cache = Arc::new(AnimationCache::new(animation_id, self));
+
+                self.insert_animation_cache(animation_id, cache.clone());
+
+                cache
+            }
+        };
+
+        self.mark_cache_use(animation_id);
+        self.evict_caches(Some(animation_id));
+
+        cache
+    }
+
+    /// Returns the cache for an animation registered in the library with the overrides of an entity, if any
+    pub(crate) fn get_animation_cache_with_overrides(
+        &mut self,
+        animation_id: AnimationId,
+        overrides: Option<&AnimationOverrides>,
+    ) -> Arc<AnimationCache> {
+        let Some(overrides) = overrides.filter(|overrides| !overrides.is_empty()) else {
+            return self.get_animation_cache(animation_id);
+        };
+
+        let key = (animation_id, *overrides);
+
+        if let Some(cache) = self.overridden_animation_caches.get(&key) {
+            return cache.clone();
+        }
+
+        // Free the overridden caches that are not played by any entity anymore
+
+        self.overridden_animation_caches
+            .retain(|_, cache| Arc::strong_count(cache) > 1);
+
+        let animation = overrides.apply(self.get_animation(animation_id));
+
+        let cache = Arc::new(AnimationCache::from_animation(&animation, self));
+
+        self.overridden_animation_caches.insert(key, cache.clone());
+
+        cache
+    }
+
+    /// Returns the cache for an animation registered in the library, building a temporary one if it has been evicted
+    fn peek_animation_cache(&self, animation_id: AnimationId) -> Arc<AnimationCache> {
+        match self.animation_caches.get(&animation_id) {
+            Some(cache) => cache.clone(),
+            None => Arc::new(AnimationCache::new(animation_id, self)),
+        }
+    }
+
+    /// Returns the animations edited since the last call, whose playback must follow the changes
+    pub(crate) fn take_edited_animations(&mut self) -> HashSet<AnimationId> {
+        std::mem::take(&mut self.edited_animations)
+    }
+
+    fn rebuild_animation_cache(&mut self, animation_id: AnimationId) {
+        if self.deferred_cache_builds {
+            self.remove_animation_cache(animation_id);
+        } else {
+            self.insert_animation_cache(
+                animation_id,
+                Arc::new(AnimationCache::new(animation_id, self)),
+            );
+        }
+
+        // The overridden caches are rebuilt when the entities that play them follow the edit
+
+        self.overridden_animation_caches
+            .retain(|(overridden_animation_id, _), _| *overridden_animation_id != animation_id);
+
+        self.edited_animations.insert(animation_id);
+
+        if !self.deferred_cache_builds {
+            self.mark_cache_use(animation_id);
+            self.evict_caches(None);
+        }
+    }
+
+    /// Returns the animations without a cache, the most recently used first
+    pub(crate) fn animations_without_cache(&self) -> Vec<AnimationId> {
+        let mut animation_ids: Vec<_> = self
+            .animations
+            .keys()
+            .filter(|animation_id| !self.animation_caches.contains_key(animation_id))
+            .copied()
+            .collect();
+
+        animation_ids.sort_by_key(|animation_id| {
+            std::cmp::Reverse(self.cache_last_use.get(animation_id).copied().unwrap_or(0))
+        });
+
+        animation_ids
+    }
+
+    /// Returns the number of caches left to build ahead of time, 0 if the cache budget is full
+    pub(crate) fn prewarm_remaining(&self) -> usize {
+        if self
+            .cache_budget
+            .is_some_and(|budget| self.cache_memory_size >= budget)
+        {
+            0
+        } else {
+            self.animations_without_cache().len()
+        }
+    }
+
+    /// Inserts caches built ahead of time, skipping the ones that have been built in the meantime.
+    ///
+    /// The caches are not inserted beyond the cache budget, if any.
+    pub(crate) fn insert_prewarmed_caches(
+        &mut self,
+        caches: impl IntoIterator<Item = (AnimationId, AnimationCache)>,
+    ) {
+        for (animation_id, cache) in caches {
+            if !self.animations.contains_key(&animation_id)
+                || self.animation_caches.contains_key(&animation_id)
+            {
+                continue;
+            }
+
+            if self
+                .cache_budget
+                .is_some_and(|budget| self.cache_memory_size + cache.memory_size() > budget)
+            {
+                break;
+            }
+
+            self.insert_animation_cache(animation_id, Arc::new(cache));
+        }
+    }
+
+    fn insert_animation_cache(&mut self, animation_id: AnimationId, cache: Arc<AnimationCache>) {
+        self.cache_memory_size += cache.memory_size();
+
+        if let Some(previous_cache) = self.animation_caches.insert(animation_id, cache) {
+            self.cache_memory_size -= previous_cache.memory_size();
+        }
+    }
+
+    fn remove_animation_cache(&mut self, animation_id: AnimationId) {
+        if let Some(cache) = self.animation_caches.remove(&animation_id) {
+            self.cache_memory_size -= cache.memory_size();
+        }
+    }
+
+    fn mark_cache_use(&mut self, animation_id: AnimationId) {
+        self.cache_use_counter += 1;
+
+        self.cache_last_use
+            .insert(animation_id, self.cache_use_counter);
+    }
+
+    /// Evicts the least recently used caches until their memory fits in the budget.
+    ///
+    /// The cache of `used_animation_id` is kept as it is about to be used.
+    fn evict_caches(&mut self, used_animation_id: Option<AnimationId>) {
+        let Some(budget) = self.cache_budget else {
+            return;
+        };
+
+        if self.cache_memory_size <= budget {
+            return;
+        }
+
+        // Only the caches that are not played by any entity can be freed
+
+        let mut evictable_caches: Vec<_> = self
+            .animation_caches
+            .iter()
+            .filter(|(animation_id, cache)| {
+                Some(**animation_id) != used_animation_id && Arc::strong_count(cache) == 1
+            })
+            .map(|(animation_id, _)| {
+                (
+                    *animation_id,
+                    self.cache_last_use.get(animation_id).copied().unwrap_or(0),
+                )
+            })
+            .collect();
+
+        evictable_caches.sort_by_key(|(_, last_use)| *last_use);
+
+        let mut evicted_count = 0;
+
+        for (animation_id, _) in evictable_caches {
+            if self.cache_memory_size <= budget {
+                break;
+            }
+
+            self.remove_animation_cache(animation_id);
+
+            evicted_count += 1;
+        }
+
+        if evicted_count > 0 {
+            diagnostics::info(format_args!(
+                "evicted {evicted_count} animation cache(s) to fit in the {budget}-byte budget ({} bytes used)",
+                self.cache_memory_size
+            ));
+        }
     }
 }
@@ -0,0 +1,100 @@
+/// Registers a single-clip animation from a compact description.
+///
+/// This is a shorthand for the usual sequence of [Clip](crate::prelude::Clip), [Animation](crate::prelude::Animation)
+/// and [AnimationLibrary](crate::prelude::AnimationLibrary) calls, convenient to create many simple animations in a setup system.
+/// The macro registers the clip and the animation in the library and evaluates to the [AnimationId](crate::prelude::AnimationId) of the animation.
+///
+/// The description starts with the frames of the clip, selected in a [Spritesheet](crate::prelude::Spritesheet):
+/// - `row N`, `column N`: the frames of a row or column of the spritesheet
+/// - `all`: all the frames of the spritesheet
+/// - `frames EXPR`: explicit atlas indices
+///
+/// Then come optional parameters, in any order:
+/// - `fps N`: the number of frames per second
+/// - `frame_ms N`: the duration of each frame, in milliseconds
+/// - `duration_ms N`: the duration of each repetition, in milliseconds
+/// - `repeat N`: the number of repetitions of the animation, which loops forever by default
+/// - `backwards`, `ping_pong`: the direction of the animation
+/// - `easing EXPR`: the [Easing](crate::prelude::Easing) of the animation
+/// - `max_fps N`: the maximum rate at which the animation updates
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// use bevy_spritesheet_animation::animation;
+///
+/// fn setup(mut library: ResMut<AnimationLibrary>) {
+///     let spritesheet = Spritesheet::new(8, 8);
+///
+///     let idle_id = animation!(library, spritesheet, { row 0, fps 6 });
+///     let run_id = animation!(library, spritesheet, { row 3, fps 12, repeat 4 });
+///     let blink_id = animation!(library, spritesheet, { frames [40, 41, 40], frame_ms 80, ping_pong });
+/// }
+/// ```
+#[macro_export]
+macro_rules! animation {
+    // Frames
+
+    (@frames $spritesheet:ident, row, $row:expr) => {
+        $spritesheet.row($row)
+    };
+    (@frames $spritesheet:ident, column, $column:expr) => {
+        $spritesheet.column($column)
+    };
+    (@frames $spritesheet:ident, all) => {
+        $spritesheet.all()
+    };
+    (@frames $spritesheet:ident, frames, $frames:expr) => {
+        $frames
+    };
+
+    // Parameters
+
+    (@option $animation:ident, fps, $fps:expr) => {
+        $animation.set_duration($crate::prelude::AnimationDuration::PerFrame(1000 / $fps));
+    };
+    (@option $animation:ident, frame_ms, $duration:expr) => {
+        $animation.set_duration($crate::prelude::AnimationDuration::PerFrame($duration));
+    };
+    (@option $animation:ident, duration_ms, $duration:expr) => {
+        $animation.set_duration($crate::prelude::AnimationDuration::PerRepetition($duration));
+    };
+    (@option $animation:ident, repeat, $repetitions:expr) => {
+        $animation.set_repetitions($crate::prelude::AnimationRepeat::Times($repetitions));
+    };
+    (@option $animation:ident, backwards) => {
+        $animation.set_direction($crate::prelude::AnimationDirection::Backwards);
+    };
+    (@option $animation:ident, ping_pong) => {
+        $animation.set_direction($crate::prelude::AnimationDirection::PingPong);
+    };
+    (@option $animation:ident, easing, $easing:expr) => {
+        $animation.set_easing($easing);
+    };
+    (@option $animation:ident, max_fps, $max_fps:expr) => {
+        $animation.set_max_fps($max_fps);
+    };
+
+    // Entry point
+
+    ($library:expr, $spritesheet:expr, { $selector:ident $($selector_arg:expr)? $(, $option:ident $($value:expr)?)* $(,)? }) => {{
+        let spritesheet = &$spritesheet;
+
+        let clip = $crate::prelude::Clip::from_frames(
+            $crate::animation!(@frames spritesheet, $selector $(, $selector_arg)?)
+        );
+
+        let clip_id = $library.register_clip(clip);
+
+        #[allow(unused_mut)]
+        let mut animation = $crate::prelude::Animation::from_clip(clip_id);
+
+        $(
+            $crate::animation!(@option animation, $option $(, $value)?);
+        )*
+
+        $library.register_animation(animation)
+    }};
+}
@@ -1,16 +1,78 @@
+use std::time::Duration;
+
 use bevy::{
-    app::{App, Plugin, PostUpdate},
-    prelude::{IntoSystemConfigs, SystemSet},
+    app::{
+        App, First, FixedPostUpdate, FixedPreUpdate, FixedUpdate, Plugin, PostUpdate, PreStartup,
+        PreUpdate, Update,
+    },
+    ecs::{
+        event::EventUpdates,
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+    },
+    prelude::{IntoSystemConfigs, IntoSystemSetConfigs, SystemSet},
+    reflect::prelude::*,
+    render::{camera::CameraUpdateSystem, view::VisibilitySystems},
+    transform::TransformSystem,
+    ui::UiSystem,
+    window::WindowFocused,
 };
 
 use crate::{
-    animator::Animator,
-    components::{sprite3d::Sprite3d, spritesheet_animation::SpritesheetAnimation},
-    events::AnimationEvent,
+    animator::{
+        timeline::{AnimationTimeline, TimelineClip, TimelineFrame, TimelineMarker},
+        Animator, FrameSkipping,
+    },
+    components::{
+        animation_event_filter::{AnimationEventFilter, AnimationEventKinds},
+        animation_overrides::AnimationOverrides,
+        animation_scrubber::{
+            AnimationScrubber, ScrubberClipBoundary, ScrubberFrame, ScrubberMarker,
+        },
+        animation_state_machine::{AnimationStateChanged, AnimationStateMachine},
+        clip_target::ClipTarget,
+        despawn_on_animation_end::DespawnOnAnimationEnd,
+        flip_transition::{FlipTransition, FlipTransitionStyle},
+        frame_just_changed::FrameJustChanged,
+        frame_trail::FrameTrail,
+        hit_pause::{HitPauseGroup, HitPauseOnMarker, HitPauseScope},
+        image_node_frame_size::ImageNodeFrameSize,
+        image_sequence::ImageSequence,
+        marker_listener::MarkerListener,
+        no_animation_culling::NoAnimationCulling,
+        persistent_animation_key::PersistentAnimationKey,
+        phase_from_position::PhaseFromPosition,
+        play_variant::{PlayVariant, VariantSet},
+        playback_history::PlaybackHistory,
+        shake_on_marker::{CameraShakeRequest, ShakeOnMarker},
+        sprite3d::{
+            Sprite3d, Sprite3dFilter, Sprite3dPixelPerfect, Sprite3dPlaceholder, Sprite3dReady,
+            Sprite3dSampler,
+        },
+        sprite_rig::SpriteRig,
+        spritesheet_animation::SpritesheetAnimation,
+    },
+    diagnostics::{
+        flush_diagnostics, AnimationDiagnostic, AnimationDiagnostics, DiagnosticLevel,
+        DiagnosticsMode,
+    },
+    events::{AnimationEvent, AnimationEventSettings},
+    hooks::FrameHooks,
     library::AnimationLibrary,
-    systems::{sprite3d, spritesheet_animation},
+    systems::{
+        animation_event_filter, animation_scrubber, animation_state_machine,
+        despawn_on_animation_end, image_node, image_sequence, phase_from_position, play_variant,
+        shake_on_marker, sprite3d, sprite_rig, spritesheet_animation, window_focus,
+    },
 };
 
+#[cfg(feature = "gamepad_rumble")]
+use crate::{components::marker_rumble::MarkerRumble, systems::marker_rumble};
+
+#[cfg(feature = "animation_files")]
+use crate::animation_set::{register_animation_sets, AnimationSet, AnimationSetLoader};
+#[cfg(feature = "animation_files")]
+use bevy::asset::AssetApp;
+
 /// Set for systems that update the animation state.
 #[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
 pub struct AnimationSystemSet;
@@ -56,26 +118,356 @@ pub struct SpritesheetAnimationPlugin {
     ///
     /// This allows using the plugin without `bevy_render`, for example in a headless environment with `MinimalPlugin`.
     pub enable_3d: bool,
+
+    /// Determines whether 3D sprites sharing the same material are rendered as a single merged mesh.
+    ///
+    /// This reduces the number of draw calls in scenes with many 3D sprites.
+    /// The merged mesh of a material is rebuilt when 3D sprites join or leave it (when they appear, disappear, change material or visibility)
+    /// and it is updated in place when its 3D sprites change frame or move, so this is only worth it for large amounts of sprites. Defaults to false.
+    ///
+    /// When enabled, the 3D sprites do not get their own `Mesh3d` and `MeshMaterial3d` components.
+    pub batch_3d: bool,
+
+    /// Determines what happens to entities that play an animation without any frame.
+    pub empty_animation_behavior: EmptyAnimationBehavior,
+
+    /// The schedule in which the animations are played and their [AnimationEvent]s are written.
+    ///
+    /// Defaults to `PostUpdate`.
+    /// See [AnimationEvent] for when the events can be read depending on this schedule.
+    ///
+    /// The other systems of the plugin (3D sprites, UI, gamepad rumbles...) always run in `PostUpdate`, after the animations,
+    /// because they depend on the transforms, the layout and the visibility computed in this schedule.
+    /// For this reason, the animation schedule is restricted to `PreUpdate`, `FixedPreUpdate`, `FixedUpdate`, `FixedPostUpdate`, `Update` and `PostUpdate`:
+    /// the plugin panics with any other schedule.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::schedule::ScheduleLabel;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// // Play the animations before the gameplay systems that read their events
+    /// let plugin = SpritesheetAnimationPlugin {
+    ///     animation_schedule: PreUpdate.intern(),
+    ///     ..default()
+    /// };
+    /// ```
+    pub animation_schedule: InternedScheduleLabel,
+
+    /// Determines whether the animated entities get a [FrameJustChanged] marker when their atlas index changes.
+    ///
+    /// The markers are inserted and removed as the frames change, which moves the entities between archetypes,
+    /// so this is disabled by default. Defaults to false.
+    pub frame_change_markers: bool,
+
+    /// Determines which animations are updated less often because they are not on screen.
+    ///
+    /// Disabled by default.
+    pub culling: AnimationCulling,
+
+    /// Determines whether the animations are paused while the window is not focused.
+    ///
+    /// Defaults to [FocusLossBehavior::KeepRunning].
+    pub focus_loss_behavior: FocusLossBehavior,
+}
+
+/// What to do with entities that play an animation without any frame.
+///
+/// Such animations usually come from data bugs, like clips created from empty frame ranges.
+/// In any case, an [AnimationEvent::EmptyAnimation] event is emitted when the animation is assigned.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// // Make data bugs stand out in development builds
+/// let plugin = SpritesheetAnimationPlugin {
+///     empty_animation_behavior: if cfg!(debug_assertions) {
+///         EmptyAnimationBehavior::Hide
+///     } else {
+///         EmptyAnimationBehavior::KeepFrame
+///     },
+///     ..default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Default, PartialEq)]
+pub enum EmptyAnimationBehavior {
+    /// Keep the current atlas index of the entity
+    #[default]
+    KeepFrame,
+    /// Hide the entity until it plays an animation with frames
+    Hide,
+}
+
+/// Which animations are paused while the window is not focused, see [SpritesheetAnimationPlugin::focus_loss_behavior].
+///
+/// Browser and mobile users usually expect animations to stop when they switch to another tab or app.
+/// The paused animations resume from where they were when the window is focused again, without catching up with the time spent unfocused.
+///
+/// The focus is tracked with the [WindowFocused] events.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// // Keep the menus animated but freeze the game world
+/// let plugin = SpritesheetAnimationPlugin {
+///     focus_loss_behavior: FocusLossBehavior::PauseExceptUi,
+///     ..default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Debug, Default, PartialEq)]
+pub enum FocusLossBehavior {
+    /// Keep playing all the animations
+    #[default]
+    KeepRunning,
+    /// Pause all the animations
+    PauseAll,
+    /// Pause all the animations except the ones of UI images
+    PauseExceptUi,
+}
+
+impl FocusLossBehavior {
+    /// Returns whether the animation of an entity should be paused while the window is not focused.
+    pub(crate) fn pauses(&self, is_ui: bool) -> bool {
+        match self {
+            FocusLossBehavior::KeepRunning => false,
+            FocusLossBehavior::PauseAll => true,
+            FocusLossBehavior::PauseExceptUi => !is_ui,
+        }
+    }
+}
+
+/// Which animations are culled, see [SpritesheetAnimationPlugin::culling].
+///
+/// With thousands of animated entities, updating the ones that cannot be seen has a noticeable cost.
+/// The animations of the culled entities are only updated at the `culled_update_interval`,
+/// with all the time elapsed since their previous update, and immediately once they are not culled anymore.
+/// Their progress thus stays approximately correct, but their frames and events lag behind while they are culled.
+///
+/// Entities can opt out with a [NoAnimationCulling] component.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// // Throttle the crowds outside of the view or farther than 2000 units from the cameras
+/// let plugin = SpritesheetAnimationPlugin {
+///     culling: AnimationCulling {
+///         offscreen: true,
+///         max_distance: Some(2000.0),
+///         ..default()
+///     },
+///     ..default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Debug, Default, PartialEq)]
+pub struct AnimationCulling {
+    /// Whether the entities that were not visible from any camera during the previous frame are culled. Defaults to false
+    ///
+    /// Only the entities with a `ViewVisibility` component, like sprites and 3D sprites, can be culled this way.
+    pub offscreen: bool,
+
+    /// The distance from all the cameras beyond which the entities are culled, if any. Defaults to none
+    pub max_distance: Option<f32>,
+
+    /// How often the animations of the culled entities are updated. Defaults to 500ms
+    pub culled_update_interval: Duration,
+}
+
+impl Default for AnimationCulling {
+    fn default() -> Self {
+        Self {
+            offscreen: false,
+            max_distance: None,
+            culled_update_interval: Duration::from_millis(500),
+        }
+    }
 }
 
 impl Plugin for SpritesheetAnimationPlugin {
     fn build(&self, app: &mut App) {
+        // The systems that run in PostUpdate after the animations rely on the animation schedule running before them
+
+        assert!(
+            [
+                PreUpdate.intern(),
+                FixedPreUpdate.intern(),
+                FixedUpdate.intern(),
+                FixedPostUpdate.intern(),
+                Update.intern(),
+                PostUpdate.intern(),
+            ]
+            .contains(&self.animation_schedule),
+            "the animation schedule {:?} is not supported, the animations must be played in PostUpdate or in a schedule that runs before it",
+            self.animation_schedule
+        );
+
         app
             // The animation library, for creating clips, animations and markers
             .init_resource::<AnimationLibrary>()
             .register_type::<AnimationLibrary>()
             // The animator responsible for running animations
-            .init_resource::<Animator>()
+            .insert_resource(Animator::new(
+                self.empty_animation_behavior,
+                self.frame_change_markers,
+                self.culling,
+                self.focus_loss_behavior,
+            ))
             .register_type::<Animator>()
             .register_type::<SpritesheetAnimation>()
+            .register_type::<ClipTarget>()
+            .register_type::<PersistentAnimationKey>()
+            .register_type::<PlaybackHistory>()
+            .register_type::<FrameTrail>()
+            .register_type::<FrameJustChanged>()
+            .register_type::<ImageNodeFrameSize>()
+            .register_type::<PhaseFromPosition>()
+            .register_type::<PlayVariant>()
+            .register_type::<VariantSet>()
+            .register_type::<SpriteRig>()
+            .register_type::<DespawnOnAnimationEnd>()
+            .register_type::<HitPauseOnMarker>()
+            .register_type::<HitPauseScope>()
+            .register_type::<HitPauseGroup>()
+            .register_type::<FlipTransition>()
+            .register_type::<FlipTransitionStyle>()
+            .register_type::<AnimationOverrides>()
+            .register_type::<ImageSequence>()
+            .register_type::<AnimationCulling>()
+            .register_type::<FocusLossBehavior>()
+            .register_type::<NoAnimationCulling>()
+            .register_type::<ShakeOnMarker>()
+            .add_event::<CameraShakeRequest>()
+            .register_type::<CameraShakeRequest>()
+            // Timelines for editors
+            .register_type::<AnimationTimeline>()
+            .register_type::<TimelineFrame>()
+            .register_type::<TimelineClip>()
+            .register_type::<TimelineMarker>()
+            // Global frame skipping for low-spec modes
+            .init_resource::<FrameSkipping>()
+            .register_type::<FrameSkipping>()
             // Animations events
             .add_event::<AnimationEvent>()
-            // Systems
+            .init_resource::<AnimationEventSettings>()
+            .register_type::<AnimationEventSettings>()
+            .register_type::<MarkerListener>()
+            .register_type::<AnimationEventFilter>()
+            .register_type::<AnimationEventKinds>()
+            // Diagnostics, applied before the startup systems that may build clips
+            .init_resource::<AnimationDiagnostics>()
+            .register_type::<AnimationDiagnostics>()
+            .register_type::<DiagnosticsMode>()
+            .register_type::<DiagnosticLevel>()
+            .add_event::<AnimationDiagnostic>()
+            .register_type::<AnimationDiagnostic>()
+            .add_systems(PreStartup, flush_diagnostics)
+            .add_systems(First, flush_diagnostics.after(EventUpdates))
+            // Frame hooks
+            .init_resource::<FrameHooks>()
+            // State machines
+            .register_type::<AnimationStateMachine>()
+            .add_event::<AnimationStateChanged>()
+            // Scrubber widgets
+            .register_type::<AnimationScrubber>()
+            .register_type::<ScrubberFrame>()
+            .register_type::<ScrubberClipBoundary>()
+            .register_type::<ScrubberMarker>()
+            // Main animation system
+            .add_systems(
+                self.animation_schedule,
+                (
+                    spritesheet_animation::play_animations.in_set(AnimationSystemSet),
+                    // Animations picked from variant sets
+                    play_variant::play_variants.before(AnimationSystemSet),
+                    // Animations built from folders of frame images
+                    image_sequence::build_image_sequences.before(AnimationSystemSet),
+                    // Composite characters, spawned and swapped before their slots are animated
+                    (
+                        sprite_rig::build_sprite_rigs,
+                        sprite_rig::swap_sprite_rig_slots,
+                    )
+                        .chain()
+                        .before(AnimationSystemSet),
+                    // Starting frames derived from positions
+                    phase_from_position::offset_phases_from_positions.before(AnimationSystemSet),
+                    // Events routed to the entities that filter them
+                    animation_event_filter::route_animation_events.after(AnimationSystemSet),
+                    // Camera shakes requested in the frame in which the markers are hit
+                    shake_on_marker::request_camera_shakes.after(AnimationSystemSet),
+                    // State machines switching animations after reading the events of the frame
+                    animation_state_machine::drive_state_machines.after(AnimationSystemSet),
+                    // One-shot entities cleaned up after the state machines had a chance to switch animations
+                    despawn_on_animation_end::despawn_on_animation_end
+                        .after(AnimationSystemSet)
+                        .after(animation_state_machine::drive_state_machines),
+                ),
+            )
+            // Scrubbers seeking before the animations are played
+            .add_systems(
+                self.animation_schedule,
+                (
+                    animation_scrubber::build_scrubbers,
+                    animation_scrubber::seek_with_scrubbers,
+                )
+                    .chain()
+                    .before(AnimationSystemSet),
+            )
+            // Other systems
+            //
+            // When the animations are played in an earlier schedule, they already run after them
+            // and the ordering constraints only apply to the default PostUpdate schedule
             .add_systems(
                 PostUpdate,
-                // Main animation system
-                spritesheet_animation::play_animations.in_set(AnimationSystemSet),
+                (
+                    animation_scrubber::sync_scrubbers
+                        .after(AnimationSystemSet)
+                        .before(UiSystem::Layout),
+                    // Frame-dependent sizes of UI images
+                    image_node::resize_image_nodes
+                        .after(AnimationSystemSet)
+                        .before(UiSystem::Layout),
+                ),
+            );
+
+        // Read the visibility of the entities before it is computed again for this frame
+
+        if self.culling.offscreen {
+            app.configure_sets(
+                PostUpdate,
+                AnimationSystemSet.before(VisibilitySystems::CheckVisibility),
             );
+        }
+
+        // Pause the animations while the window is not focused
+
+        if self.focus_loss_behavior != FocusLossBehavior::KeepRunning {
+            app.add_event::<WindowFocused>().add_systems(
+                self.animation_schedule,
+                window_focus::track_window_focus.before(AnimationSystemSet),
+            );
+        }
+
+        #[cfg(feature = "animation_files")]
+        app.init_asset::<AnimationSet>()
+            .init_asset_loader::<AnimationSetLoader>()
+            .add_systems(
+                self.animation_schedule,
+                // Register the loaded sets before playing the animations of the frame
+                register_animation_sets.before(AnimationSystemSet),
+            );
+
+        #[cfg(feature = "gamepad_rumble")]
+        app.register_type::<MarkerRumble>().add_systems(
+            PostUpdate,
+            marker_rumble::rumble_on_markers.after(AnimationSystemSet),
+        );
 
         if self.enable_3d {
             app
@@ -83,24 +475,72 @@ impl Plugin for SpritesheetAnimationPlugin {
                 .init_resource::<sprite3d::Cache>()
                 .register_type::<sprite3d::Cache>()
                 .register_type::<Sprite3d>()
+                .register_type::<Sprite3dPlaceholder>()
+                .register_type::<Sprite3dSampler>()
+                .register_type::<Sprite3dFilter>()
+                .register_type::<Sprite3dReady>()
+                .add_event::<Sprite3dReady>()
+                .register_type::<Sprite3dPixelPerfect>()
                 // 3D sprite systems
                 .add_systems(
+                    PostUpdate,
+                    (
+                        sprite3d::remove_dropped_standard_materials,
+                        // Images with custom samplers, before the materials that use them
+                        sprite3d::prepare_sampled_images,
+                    )
+                        .in_set(Sprite3dSystemSet)
+                        .after(AnimationSystemSet),
+                );
+
+            // Pixel-perfect sizes, once the frames and the camera projections are up to date
+            // and before the meshes are built
+            app.add_systems(
+                PostUpdate,
+                sprite3d::size_pixel_perfect_sprites
+                    .after(AnimationSystemSet)
+                    .after(CameraUpdateSystem)
+                    .before(Sprite3dSystemSet),
+            );
+
+            if self.batch_3d {
+                app.init_resource::<sprite3d::Batches>().add_systems(
+                    PostUpdate,
+                    sprite3d::batch_sprites
+                        .in_set(Sprite3dSystemSet)
+                        .after(AnimationSystemSet)
+                        .after(sprite3d::prepare_sampled_images)
+                        // The batches are built in world space
+                        .after(TransformSystem::TransformPropagate)
+                        .after(VisibilitySystems::VisibilityPropagate),
+                );
+            } else {
+                app.add_systems(
                     PostUpdate,
                     (
                         sprite3d::setup_rendering,
                         sprite3d::sync_when_sprites_change,
                         sprite3d::sync_when_atlases_change,
-                        sprite3d::remove_dropped_standard_materials,
                     )
                         .in_set(Sprite3dSystemSet)
-                        .after(AnimationSystemSet),
+                        .after(AnimationSystemSet)
+                        .after(sprite3d::prepare_sampled_images),
                 );
+            }
         }
     }
 }
 
 impl Default for SpritesheetAnimationPlugin {
     fn default() -> Self {
-        Self { enable_3d: true }
+        Self {
+            enable_3d: true,
+            batch_3d: false,
+            empty_animation_behavior: EmptyAnimationBehavior::default(),
+            animation_schedule: PostUpdate.intern(),
+            frame_change_markers: false,
+            culling: AnimationCulling::default(),
+            focus_loss_behavior: FocusLossBehavior::default(),
+        }
     }
 }
@@ -0,0 +1,187 @@
+use bevy::{
+    app::{App, Plugin, PostUpdate},
+    ecs::{
+        reflect::ReflectResource,
+        schedule::IntoSystemConfigs,
+        system::{Res, ResMut, Resource},
+    },
+    reflect::prelude::*,
+    state::{
+        condition::in_state,
+        state::{OnEnter, States},
+    },
+    tasks::ComputeTaskPool,
+};
+
+use crate::{
+    animator::cache::AnimationCache, library::AnimationLibrary, plugin::AnimationSystemSet,
+};
+
+/// A plugin that builds the animation caches ahead of time, during a loading state.
+///
+/// Requires the `animation_prewarm` feature.
+///
+/// The library pre-computes a cache with all the data required to play each animation.
+/// By default, the caches are built on the main thread as soon as the animations are registered,
+/// which can cause hitches when registering many animations or loading large animation files.
+///
+/// With this plugin, the caches are not built when the animations are registered (see [AnimationLibrary::set_deferred_cache_builds]).
+/// Instead, while the app is in the loading state, the missing caches are built in parallel on the [ComputeTaskPool].
+/// The [AnimationPrewarmProgress] resource reports the progress so that loading screens can wait for the animations to be ready.
+///
+/// The caches that are still missing when leaving the loading state are built the first time their animation is played.
+/// With a [cache budget](AnimationLibrary::set_cache_budget), the caches are only built until the budget is full.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+/// enum GameState {
+///     #[default]
+///     Loading,
+///     Playing,
+/// }
+///
+/// fn finish_loading(
+///     progress: Res<AnimationPrewarmProgress>,
+///     mut next_state: ResMut<NextState<GameState>>,
+/// ) {
+///     if progress.is_done() {
+///         next_state.set(GameState::Playing);
+///     }
+/// }
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins((
+///     SpritesheetAnimationPlugin::default(),
+///     AnimationPrewarmPlugin::new(GameState::Loading),
+/// ))
+/// .add_systems(Update, finish_loading.run_if(in_state(GameState::Loading)));
+/// ```
+pub struct AnimationPrewarmPlugin<S: States> {
+    /// The state during which the caches are built
+    pub loading_state: S,
+
+    /// The maximum number of caches built per frame, to keep the loading screen responsive.
+    ///
+    /// Defaults to 256.
+    pub caches_per_frame: usize,
+}
+
+impl<S: States> AnimationPrewarmPlugin<S> {
+    /// Creates a plugin that builds the caches while the app is in the given state.
+    pub fn new(loading_state: S) -> Self {
+        Self {
+            loading_state,
+            caches_per_frame: 256,
+        }
+    }
+}
+
+/// The progress of the creation of the animation caches by the [AnimationPrewarmPlugin].
+#[derive(Resource, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Resource, Debug, Default)]
+pub struct AnimationPrewarmProgress {
+    /// The number of animations whose cache is ready (or that will not be built because the cache budget is full)
+    pub ready: usize,
+
+    /// The total number of animations in the library
+    pub total: usize,
+}
+
+impl AnimationPrewarmProgress {
+    /// Returns the progress between 0 and 1.
+    ///
+    /// Returns 1 if the library has no animations.
+    pub fn progress(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.ready as f32 / self.total as f32
+        }
+    }
+
+    /// Returns true if all the caches are ready.
+    pub fn is_done(&self) -> bool {
+        self.ready >= self.total
+    }
+
+    fn of(library: &AnimationLibrary) -> Self {
+        let total = library.animations().len();
+
+        Self {
+            ready: total - library.prewarm_remaining(),
+            total,
+        }
+    }
+}
+
+impl<S: States> Plugin for AnimationPrewarmPlugin<S> {
+    fn build(&self, app: &mut App) {
+        let caches_per_frame = self.caches_per_frame.max(1);
+
+        app.init_resource::<AnimationPrewarmProgress>()
+            .register_type::<AnimationPrewarmProgress>()
+            .add_systems(OnEnter(self.loading_state.clone()), update_progress)
+            .add_systems(
+                PostUpdate,
+                (move |library: ResMut<AnimationLibrary>,
+                       progress: ResMut<AnimationPrewarmProgress>| {
+                    prewarm_caches(library, progress, caches_per_frame)
+                })
+                .run_if(in_state(self.loading_state.clone()))
+                // Build the caches of the animations registered during the frame (from animation sets for instance)
+                .before(AnimationSystemSet),
+            );
+
+        app.world_mut()
+            .get_resource_or_insert_with(AnimationLibrary::default)
+            .set_deferred_cache_builds(true);
+    }
+}
+
+fn update_progress(library: Res<AnimationLibrary>, mut progress: ResMut<AnimationPrewarmProgress>) {
+    *progress = AnimationPrewarmProgress::of(&library);
+}
+
+/// Builds the missing caches in parallel, up to a number per frame
+fn prewarm_caches(
+    mut library: ResMut<AnimationLibrary>,
+    mut progress: ResMut<AnimationPrewarmProgress>,
+    caches_per_frame: usize,
+) {
+    let missing_animation_ids = library.animations_without_cache();
+
+    if library.prewarm_remaining() > 0 {
+        let animation_ids =
+            &missing_animation_ids[..missing_animation_ids.len().min(caches_per_frame)];
+
+        let task_pool = ComputeTaskPool::get();
+
+        let chunk_size = animation_ids.len().div_ceil(task_pool.thread_num().max(1));
+
+        let caches = {
+            let library = &*library;
+
+            task_pool.scope(|scope| {
+                for chunk in animation_ids.chunks(chunk_size) {
+                    scope.spawn(async move {
+                        chunk
+                            .iter()
+                            .map(|animation_id| {
+                                (*animation_id, AnimationCache::new(*animation_id, library))
+                            })
+                            .collect::<Vec<_>>()
+                    });
+                }
+            })
+        };
+
+        library.insert_prewarmed_caches(caches.into_iter().flatten());
+    }
+
+    *progress = AnimationPrewarmProgress::of(&library);
+}
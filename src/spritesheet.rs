@@ -1,8 +1,66 @@
-use std::ops::RangeBounds;
+use std::{
+    collections::HashMap,
+    fmt,
+    ops::{Deref, RangeBounds},
+};
 
-use bevy::{log::warn, math::UVec2, sprite::TextureAtlasLayout};
+use bevy::{
+    asset::{Assets, Handle},
+    image::Image,
+    math::{URect, UVec2},
+    sprite::{Sprite, TextureAtlas, TextureAtlasLayout},
+    ui::widget::ImageNode,
+};
 
-use crate::CRATE_NAME;
+use crate::{
+    diagnostics,
+    prelude::{AnimationId, Sprite3d, SpritesheetAnimation},
+};
+
+/// Error type returned by [Spritesheet::from_cell_size].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpritesheetError {
+    /// The image is not loaded yet
+    ImageNotLoaded,
+    /// The cell size has a zero dimension
+    EmptyCell,
+    /// The size of the image is not a multiple of the cell size
+    UnevenCellSize {
+        /// The size of the image
+        image_size: UVec2,
+        /// The requested cell size
+        cell_size: UVec2,
+    },
+}
+
+impl fmt::Display for SpritesheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpritesheetError::ImageNotLoaded => write!(f, "the image is not loaded"),
+            SpritesheetError::EmptyCell => write!(f, "the cell size has a zero dimension"),
+            SpritesheetError::UnevenCellSize {
+                image_size,
+                cell_size,
+            } => write!(
+                f,
+                "the image size {}x{} is not a multiple of the cell size {}x{}",
+                image_size.x, image_size.y, cell_size.x, cell_size.y
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SpritesheetError {}
+
+/// The order in which the frames of a spritesheet are traversed, see [Spritesheet::all_in_order].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TraversalOrder {
+    /// Left to right, then top to bottom
+    #[default]
+    RowMajor,
+    /// Top to bottom, then left to right
+    ColumnMajor,
+}
 
 /// An helper to obtain frame indices from a spritesheet.
 ///
@@ -32,6 +90,24 @@ use crate::CRATE_NAME;
 ///
 /// let clip2 = Clip::from_frames(spritesheet.vertical_strip(0, 1, 12));
 /// ```
+///
+/// The `_array` variants of the layout queries are `const` and return fixed-size arrays,
+/// so that frame tables can be computed at compile time:
+///
+/// ```
+/// # use bevy_spritesheet_animation::prelude::*;
+/// const SPRITESHEET: Spritesheet = Spritesheet::new(8, 4);
+///
+/// const RUN_FRAMES: [usize; 8] = SPRITESHEET.row_array(2);
+/// const JUMP_FRAMES: [usize; 3] = SPRITESHEET.horizontal_strip_array(5, 3);
+///
+/// assert_eq!(RUN_FRAMES, [16, 17, 18, 19, 20, 21, 22, 23]);
+/// assert_eq!(JUMP_FRAMES, [29, 30, 31]);
+///
+/// let clip = Clip::from_frames(RUN_FRAMES);
+/// ```
+///
+/// Out-of-bounds `const` queries fail to compile when evaluated in a `const` context, and panic otherwise.
 #[derive(Debug, Clone, Copy)]
 pub struct Spritesheet {
     /// The number of columns in the spritesheet
@@ -39,6 +115,21 @@ pub struct Spritesheet {
 
     /// The number of rows in the spritesheet
     rows: usize,
+
+    /// The gap between the frames of the spritesheet
+    padding: Option<UVec2>,
+
+    /// The border around the frames of the spritesheet
+    offset: Option<UVec2>,
+
+    /// The margin cropped inside each frame of the spritesheet
+    inset: Option<UVec2>,
+
+    /// The position of the first cell of the spritesheet in the layout, for sub-sheets
+    origin: (usize, usize),
+
+    /// The number of columns and rows of the layout that the frame indices refer to
+    layout_size: (usize, usize),
 }
 
 impl Spritesheet {
@@ -48,13 +139,190 @@ impl Spritesheet {
     ///
     /// * `columns` - the number of columns in the spritesheet
     /// * `rows` - the number of rows in the spritesheet
-    pub fn new(columns: usize, rows: usize) -> Self {
-        Self { columns, rows }
+    pub const fn new(columns: usize, rows: usize) -> Self {
+        Self {
+            columns,
+            rows,
+            padding: None,
+            offset: None,
+            inset: None,
+            origin: (0, 0),
+            layout_size: (columns, rows),
+        }
+    }
+
+    /// Creates a new spritesheet helper for an image with a single row of frames.
+    ///
+    /// Many spritesheets are horizontal strips with one animation.
+    /// See [AnimationLibrary::create_strip_animation](crate::prelude::AnimationLibrary::create_strip_animation) to create the animation of a whole strip in one call.
+    ///
+    /// Like [Spritesheet::from_cell_size], the image must be loaded and its width must be a multiple of the number of frames.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - the image of the strip
+    /// * `frame_count` - the number of frames in the strip
+    /// * `images` - the loaded images
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// fn setup(assets: Res<AssetServer>, images: Res<Assets<Image>>) {
+    ///     let image = assets.load("coin.png");
+    ///
+    ///     // Once the image is loaded...
+    ///
+    ///     if let Ok(spritesheet) = Spritesheet::strip(&image, 6, &images) {
+    ///         assert_eq!(spritesheet.all(), spritesheet.row(0));
+    ///
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    pub fn strip(
+        image: &Handle<Image>,
+        frame_count: usize,
+        images: &Assets<Image>,
+    ) -> Result<Self, SpritesheetError> {
+        let image_size = images
+            .get(image)
+            .ok_or(SpritesheetError::ImageNotLoaded)?
+            .size();
+
+        let frame_width = image_size.x.checked_div(frame_count as u32).unwrap_or(0);
+
+        Self::from_cell_size(image, UVec2::new(frame_width, image_size.y), images)
+    }
+
+    /// Creates a new spritesheet helper with as many columns and rows as cells of the given size fit in an image.
+    ///
+    /// The image must be loaded and its size must be a multiple of the cell size.
+    /// Spritesheets with padding or a border should be created with [Spritesheet::new] instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - the image of the spritesheet
+    /// * `cell_size` - the size of the cells of the spritesheet, in pixels
+    /// * `images` - the loaded images
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// fn setup(
+    ///     assets: Res<AssetServer>,
+    ///     images: Res<Assets<Image>>,
+    ///     mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    /// ) {
+    ///     let image = assets.load("character.png");
+    ///
+    ///     // Once the image is loaded...
+    ///
+    ///     if let Ok(spritesheet) = Spritesheet::from_cell_size(&image, UVec2::splat(32), &images) {
+    ///         let atlas_layout = atlas_layouts.add(spritesheet.atlas_layout(32, 32));
+    ///
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    pub fn from_cell_size(
+        image: &Handle<Image>,
+        cell_size: UVec2,
+        images: &Assets<Image>,
+    ) -> Result<Self, SpritesheetError> {
+        let image_size = images
+            .get(image)
+            .ok_or(SpritesheetError::ImageNotLoaded)?
+            .size();
+
+        if cell_size.x == 0 || cell_size.y == 0 {
+            return Err(SpritesheetError::EmptyCell);
+        }
+
+        if image_size.x % cell_size.x != 0 || image_size.y % cell_size.y != 0 {
+            return Err(SpritesheetError::UnevenCellSize {
+                image_size,
+                cell_size,
+            });
+        }
+
+        Ok(Self::new(
+            (image_size.x / cell_size.x) as usize,
+            (image_size.y / cell_size.y) as usize,
+        ))
+    }
+
+    /// Sets the gap between the frames of the spritesheet, in pixels.
+    ///
+    /// This only affects the [atlas layouts](Spritesheet::atlas_layout) created from the spritesheet, not the frame indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// // 2px gutters between the frames and a 4px border around the image
+    ///
+    /// let spritesheet = Spritesheet::new(8, 4)
+    ///     .with_padding(UVec2::splat(2))
+    ///     .with_offset(UVec2::splat(4));
+    ///
+    /// let layout = spritesheet.atlas_layout(32, 32);
+    ///
+    /// assert_eq!(layout.textures[1], URect::new(38, 4, 70, 36));
+    /// ```
+    pub const fn with_padding(self, padding: UVec2) -> Self {
+        Self {
+            padding: Some(padding),
+            ..self
+        }
+    }
+
+    /// Sets the border around the frames of the spritesheet, in pixels.
+    ///
+    /// The border is assumed to surround the frames on all sides, so that the size of the atlas layouts created from the spritesheet matches the size of the image.
+    /// This only affects the [atlas layouts](Spritesheet::atlas_layout), not the frame indices.
+    pub const fn with_offset(self, offset: UVec2) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    /// Sets a margin cropped inside each frame of the spritesheet, in pixels.
+    ///
+    /// The frames of the [atlas layouts](Spritesheet::atlas_layout) created from the spritesheet are shrunk by this margin on all sides,
+    /// which prevents the neighboring frames from bleeding into each other when the sprites are filtered or scaled.
+    /// The inset cannot exceed half of a frame.
+    /// This only affects the atlas layouts, not the frame indices.
+    ///
+    /// 3D sprites can also use a sub-pixel [UV inset](crate::prelude::Sprite3d::uv_inset) instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// let spritesheet = Spritesheet::new(8, 4).with_inset(UVec2::ONE);
+    ///
+    /// let layout = spritesheet.atlas_layout(32, 32);
+    ///
+    /// assert_eq!(layout.textures[1], URect::new(33, 1, 63, 31));
+    /// ```
+    pub const fn with_inset(self, inset: UVec2) -> Self {
+        Self {
+            inset: Some(inset),
+            ..self
+        }
     }
 
     /// Returns the frame indices for all of the spritesheet.
     ///
     /// This is convenient if the whole spritesheet represents a single animation.
+    /// See [Spritesheet::all_in_order] for spritesheets exported column by column.
     ///
     /// # Example
     ///
@@ -75,7 +343,37 @@ impl Spritesheet {
     /// assert_eq!(clip.frames(), vec![0, 1, 2, 3]);
     /// ```
     pub fn all(&self) -> Vec<usize> {
-        (0..(self.columns * self.rows)).collect()
+        (0..(self.columns * self.rows))
+            .map(|index| self.layout_index(index))
+            .collect()
+    }
+
+    /// Returns the frame indices for all of the spritesheet, in the given order.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - the order in which the frames are traversed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌───┐
+    /// // │A B│
+    /// // │C D│
+    /// // └───┘
+    ///
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let spritesheet = Spritesheet::new(2, 2);
+    ///
+    /// let clip = Clip::from_frames(spritesheet.all_in_order(TraversalOrder::ColumnMajor));
+    ///
+    /// // This clip will play frames A → C → B → D
+    ///
+    /// assert_eq!(clip.frames(), vec![0, 2, 1, 3]);
+    /// ```
+    pub fn all_in_order(&self, order: TraversalOrder) -> Vec<usize> {
+        self.grid_in_order(.., .., order)
     }
 
     /// Returns the frame indices corresponding to the given positions in the spritesheet.
@@ -111,12 +409,12 @@ impl Spritesheet {
             let index = y * self.columns + x;
 
             if index >= self.columns * self.rows {
-                warn!(
-                    "{CRATE_NAME}: position ({x}, {y}) exceeds the spritesheet size ({}, {})",
+                diagnostics::warning(format_args!(
+                    "position ({x}, {y}) exceeds the spritesheet size ({}, {})",
                     self.columns, self.rows
-                );
+                ));
             } else {
-                indices.push(index)
+                indices.push(self.layout_index(index))
             }
         }
 
@@ -153,17 +451,51 @@ impl Spritesheet {
         if row < self.rows {
             let first_index = row * self.columns;
 
-            (first_index..first_index + self.columns).collect()
+            (first_index..first_index + self.columns)
+                .map(|index| self.layout_index(index))
+                .collect()
         } else {
-            warn!(
-                "{CRATE_NAME}: row {row} exceeds the spritesheet size ({}, {})",
+            diagnostics::warning(format_args!(
+                "row {row} exceeds the spritesheet size ({}, {})",
                 self.columns, self.rows
-            );
+            ));
 
             Vec::new()
         }
     }
 
+    /// Returns the frame indices for a whole row of the spritesheet, from right to left.
+    ///
+    /// This is convenient for spritesheets authored right-to-left.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - the index of the spritesheet row to add frames for
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌─────┐
+    /// // │A B C│
+    /// // │D E F│
+    /// // └─────┘
+    ///
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let spritesheet = Spritesheet::new(3, 2);
+    ///
+    /// let clip = Clip::from_frames(spritesheet.row_reversed(1));
+    ///
+    /// // This clip will play frames F → E → D
+    ///
+    /// assert_eq!(clip.frames(), vec![5, 4, 3]);
+    /// ```
+    pub fn row_reversed(&self, row: usize) -> Vec<usize> {
+        let mut frames = self.row(row);
+        frames.reverse();
+        frames
+    }
+
     /// Returns the frame indices for a section of a row of the spritesheet.
     ///
     /// This is convenient if some spritesheet row contains an animation next to other unrelated frames.
@@ -206,10 +538,10 @@ impl Spritesheet {
     /// ```
     pub fn row_partial<R: RangeBounds<usize>>(&self, row: usize, column_range: R) -> Vec<usize> {
         if row >= self.rows {
-            warn!(
-                "{CRATE_NAME}: row {row} exceeds the spritesheet size ({}, {})",
+            diagnostics::warning(format_args!(
+                "row {row} exceeds the spritesheet size ({}, {})",
                 self.columns, self.rows
-            );
+            ));
 
             Vec::new()
         } else {
@@ -226,13 +558,13 @@ impl Spritesheet {
             };
 
             if first_column >= self.columns || end_column > self.columns {
-                warn!(
-                    "{CRATE_NAME}: range ({:?}, {:?}) exceeds the spritesheet size ({}, {})",
+                diagnostics::warning(format_args!(
+                    "range ({:?}, {:?}) exceeds the spritesheet size ({}, {})",
                     column_range.start_bound(),
                     column_range.end_bound(),
                     self.columns,
                     self.rows
-                );
+                ));
             }
 
             let first_index =
@@ -240,7 +572,9 @@ impl Spritesheet {
 
             let end_index = row * self.columns + end_column.clamp(0, self.columns);
 
-            (first_index..end_index).collect()
+            (first_index..end_index)
+                .map(|index| self.layout_index(index))
+                .collect()
         }
     }
 
@@ -272,17 +606,49 @@ impl Spritesheet {
     /// ```
     pub fn column(&self, column: usize) -> Vec<usize> {
         if column < self.columns {
-            ((0..self.rows).map(|current_row| column + current_row * self.columns)).collect()
+            ((0..self.rows)
+                .map(|current_row| self.layout_index(column + current_row * self.columns)))
+            .collect()
         } else {
-            warn!(
-                "{CRATE_NAME}: column {column} exceeds the spritesheet size ({}, {})",
+            diagnostics::warning(format_args!(
+                "column {column} exceeds the spritesheet size ({}, {})",
                 self.columns, self.rows
-            );
+            ));
 
             Vec::new()
         }
     }
 
+    /// Returns the frame indices for a whole column of the spritesheet, from bottom to top.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - the index of the spritesheet column to add frames for
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌─────┐
+    /// // │A B C│
+    /// // │D E F│
+    /// // └─────┘
+    ///
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let spritesheet = Spritesheet::new(3, 2);
+    ///
+    /// let clip = Clip::from_frames(spritesheet.column_reversed(1));
+    ///
+    /// // This clip will play frames E → B
+    ///
+    /// assert_eq!(clip.frames(), vec![4, 1]);
+    /// ```
+    pub fn column_reversed(&self, column: usize) -> Vec<usize> {
+        let mut frames = self.column(column);
+        frames.reverse();
+        frames
+    }
+
     /// Returns the frame indices for a section of a column of the spritesheet.
     ///
     /// This is convenient if some spritesheet column contains an animation among other unrelated frames.
@@ -314,10 +680,10 @@ impl Spritesheet {
     /// ```
     pub fn column_partial<R: RangeBounds<usize>>(&self, column: usize, row_range: R) -> Vec<usize> {
         if column >= self.columns {
-            warn!(
-                "{CRATE_NAME}: column {column} exceeds the spritesheet size ({}, {})",
+            diagnostics::warning(format_args!(
+                "column {column} exceeds the spritesheet size ({}, {})",
                 self.columns, self.rows
-            );
+            ));
 
             Vec::new()
         } else {
@@ -334,13 +700,13 @@ impl Spritesheet {
             };
 
             if first_row >= self.rows || end_row > self.rows {
-                warn!(
-                    "{CRATE_NAME}: range ({:?}, {:?}) exceeds the spritesheet size ({}, {})",
+                diagnostics::warning(format_args!(
+                    "range ({:?}, {:?}) exceeds the spritesheet size ({}, {})",
                     row_range.start_bound(),
                     row_range.end_bound(),
                     self.columns,
                     self.rows
-                );
+                ));
             }
 
             first_row = first_row.clamp(0, self.rows.saturating_sub(1));
@@ -348,11 +714,177 @@ impl Spritesheet {
             end_row = end_row.clamp(0, self.rows);
 
             (first_row..end_row)
-                .map(|row| row * self.columns + column)
+                .map(|row| self.layout_index(row * self.columns + column))
                 .collect()
         }
     }
 
+    /// Returns the frame indices for several consecutive rows of the spritesheet.
+    ///
+    /// This is convenient if an animation spans a block of full rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `row_range` - the range of rows to add frames for
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌─────┐
+    /// // │A B C│
+    /// // │D E F│
+    /// // │G H I│
+    /// // └─────┘
+    ///
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let spritesheet = Spritesheet::new(3, 3);
+    ///
+    /// let clip = Clip::from_frames(spritesheet.row_range(1..));
+    ///
+    /// // This clip will play frames D → E → F → G → H → I
+    ///
+    /// assert_eq!(clip.frames(), vec![3, 4, 5, 6, 7, 8]);
+    /// ```
+    pub fn row_range<R: RangeBounds<usize>>(&self, row_range: R) -> Vec<usize> {
+        self.grid(.., row_range)
+    }
+
+    /// Returns the frame indices for a rectangular block of the spritesheet, row by row.
+    ///
+    /// This is convenient if an animation is exported as a block of cells next to other unrelated frames.
+    ///
+    /// # Arguments
+    ///
+    /// * `column_range` - the range of columns to add frames for
+    /// * `row_range` - the range of rows to add frames for
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌───────┐
+    /// // │A B C D│
+    /// // │E F G H│
+    /// // │I J K L│
+    /// // └───────┘
+    ///
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let spritesheet = Spritesheet::new(4, 3);
+    ///
+    /// let clip = Clip::from_frames(spritesheet.grid(1..3, 1..=2));
+    ///
+    /// // This clip will play frames F → G → J → K
+    ///
+    /// assert_eq!(clip.frames(), vec![5, 6, 9, 10]);
+    /// ```
+    #[doc(alias = "grid_section")]
+    pub fn grid<C: RangeBounds<usize>, R: RangeBounds<usize>>(
+        &self,
+        column_range: C,
+        row_range: R,
+    ) -> Vec<usize> {
+        self.grid_in_order(column_range, row_range, TraversalOrder::RowMajor)
+    }
+
+    /// Returns the frame indices for a rectangular block of the spritesheet, in the given order.
+    ///
+    /// This is convenient for spritesheets exported column by column.
+    ///
+    /// # Arguments
+    ///
+    /// * `column_range` - the range of columns to add frames for
+    /// * `row_range` - the range of rows to add frames for
+    /// * `order` - the order in which the frames are traversed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌───────┐
+    /// // │A B C D│
+    /// // │E F G H│
+    /// // │I J K L│
+    /// // └───────┘
+    ///
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// # let mut library = AnimationLibrary::default();
+    /// let spritesheet = Spritesheet::new(4, 3);
+    ///
+    /// let clip = Clip::from_frames(spritesheet.grid_in_order(1..3, 1..=2, TraversalOrder::ColumnMajor));
+    ///
+    /// // This clip will play frames F → J → G → K
+    ///
+    /// assert_eq!(clip.frames(), vec![5, 9, 6, 10]);
+    /// ```
+    pub fn grid_in_order<C: RangeBounds<usize>, R: RangeBounds<usize>>(
+        &self,
+        column_range: C,
+        row_range: R,
+        order: TraversalOrder,
+    ) -> Vec<usize> {
+        let (first_column, end_column) = Self::resolve_range(&column_range, self.columns);
+        let (first_row, end_row) = Self::resolve_range(&row_range, self.rows);
+
+        if end_column > self.columns || end_row > self.rows {
+            diagnostics::warning(format_args!(
+                "grid ({:?}, {:?}) x ({:?}, {:?}) exceeds the spritesheet size ({}, {})",
+                column_range.start_bound(),
+                column_range.end_bound(),
+                row_range.start_bound(),
+                row_range.end_bound(),
+                self.columns,
+                self.rows
+            ));
+        }
+
+        let end_column = end_column.min(self.columns);
+        let end_row = end_row.min(self.rows);
+
+        match order {
+            TraversalOrder::RowMajor => (first_row..end_row)
+                .flat_map(|row| {
+                    (first_column..end_column)
+                        .map(move |column| self.layout_index(row * self.columns + column))
+                })
+                .collect(),
+            TraversalOrder::ColumnMajor => (first_column..end_column)
+                .flat_map(|column| {
+                    (first_row..end_row)
+                        .map(move |row| self.layout_index(row * self.columns + column))
+                })
+                .collect(),
+        }
+    }
+
+    /// Resolves a range of columns or rows into its start and (unclamped) end.
+    ///
+    /// The start never exceeds the end.
+    fn resolve_range<R: RangeBounds<usize>>(range: &R, size: usize) -> (usize, usize) {
+        let start = match range.start_bound() {
+            std::ops::Bound::Included(index) => *index,
+            std::ops::Bound::Excluded(index) => (*index).saturating_add(1),
+            std::ops::Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            std::ops::Bound::Included(index) => (*index).saturating_add(1),
+            std::ops::Bound::Excluded(index) => *index,
+            std::ops::Bound::Unbounded => size,
+        };
+
+        (start.min(end), end)
+    }
+
+    /// Converts the index of a cell of the spritesheet into a frame index in the layout.
+    ///
+    /// This is the identity, except for [sub-sheets](Spritesheet::sub_sheet).
+    const fn layout_index(&self, index: usize) -> usize {
+        let x = index % self.columns;
+        let y = index / self.columns;
+
+        (self.origin.1 + y) * self.layout_size.0 + self.origin.0 + x
+    }
+
     /// Returns the frame indices for an horizontal strip in the spritesheet, wrapping from row to row.
     ///
     /// This is convenient if some animations span several rows of a spritesheet.
@@ -386,13 +918,15 @@ impl Spritesheet {
 
         let last_index = (first_index + count).min(self.columns * self.rows);
 
-        let frames = (first_index..last_index).collect();
+        let frames = (first_index..last_index)
+            .map(|index| self.layout_index(index))
+            .collect();
 
         if last_index != first_index + count {
-            warn!(
-                "{CRATE_NAME}: horizontal strip from {x}/{y} with {count} entries exceeds the spritesheet size ({}, {})",
+            diagnostics::warning(format_args!(
+                "horizontal strip from {x}/{y} with {count} entries exceeds the spritesheet size ({}, {})",
                 self.columns, self.rows
-            );
+            ));
         }
 
         frames
@@ -436,31 +970,344 @@ impl Spritesheet {
                 let current_x = x + (y + i) / self.rows;
                 let current_y = (y + i) % self.rows;
 
-                current_y * self.columns + current_x
+                self.layout_index(current_y * self.columns + current_x)
             })
             .collect();
 
         if clamped_count != count {
-            warn!(
-                "{CRATE_NAME}: vertical strip from {x}/{y} with {count} entries exceeds the spritesheet size ({}, {})",
+            diagnostics::warning(format_args!(
+                "vertical strip from {x}/{y} with {count} entries exceeds the spritesheet size ({}, {})",
                 self.columns, self.rows
-            );
+            ));
         }
 
         frames
     }
 
-    /// Creates a [TextureAtlasLayout] from the spritesheet.
+    /// Returns the frame indices for a strip in the spritesheet, in the given order.
     ///
-    /// # Arguments
-    ///
-    /// * `frame_width` - the width of a single frame
-    /// * `frame_height` - the height of a single frame
+    /// This is a [horizontal strip](Spritesheet::horizontal_strip) in row-major order
+    /// and a [vertical strip](Spritesheet::vertical_strip) in column-major order.
     ///
-    /// # Example
+    /// # Arguments
     ///
-    /// ```
-    /// # use bevy::prelude::*;
+    /// * `x` - the x position of the beginning of the strip
+    /// * `y` - the y position of the beginning of the strip
+    /// * `count` - the number of frames to add
+    /// * `order` - the order in which the frames are traversed
+    pub fn strip_in_order(
+        &self,
+        x: usize,
+        y: usize,
+        count: usize,
+        order: TraversalOrder,
+    ) -> Vec<usize> {
+        match order {
+            TraversalOrder::RowMajor => self.horizontal_strip(x, y, count),
+            TraversalOrder::ColumnMajor => self.vertical_strip(x, y, count),
+        }
+    }
+
+    /// Returns a spritesheet restricted to a rectangular block of cells of this spritesheet.
+    ///
+    /// The queries of the sub-sheet are relative to the block but return frame indices in the whole layout.
+    /// This is convenient if several characters share a single atlas, each one occupying a block of cells.
+    ///
+    /// The [atlas layouts](Spritesheet::atlas_layout) created from a sub-sheet cover the whole layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `column_range` - the range of columns of the block
+    /// * `row_range` - the range of rows of the block
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // ┌───────────┐
+    /// // │A B C D E F│
+    /// // │G H I J K L│
+    /// // │M N O P Q R│
+    /// // └───────────┘
+    ///
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// let atlas = Spritesheet::new(6, 3);
+    ///
+    /// // The second character occupies the 3 columns on the right
+    ///
+    /// let character = atlas.sub_sheet(3.., ..);
+    ///
+    /// // Row 1 of the character is J → K → L
+    ///
+    /// assert_eq!(character.row(1), vec![9, 10, 11]);
+    ///
+    /// // Column 0 of the character is D → J → P
+    ///
+    /// assert_eq!(character.column(0), vec![3, 9, 15]);
+    /// ```
+    pub fn sub_sheet<C: RangeBounds<usize>, R: RangeBounds<usize>>(
+        &self,
+        column_range: C,
+        row_range: R,
+    ) -> Self {
+        let (first_column, end_column) = Self::resolve_range(&column_range, self.columns);
+        let (first_row, end_row) = Self::resolve_range(&row_range, self.rows);
+
+        if end_column > self.columns || end_row > self.rows {
+            diagnostics::warning(format_args!(
+                "sub-sheet ({:?}, {:?}) x ({:?}, {:?}) exceeds the spritesheet size ({}, {})",
+                column_range.start_bound(),
+                column_range.end_bound(),
+                row_range.start_bound(),
+                row_range.end_bound(),
+                self.columns,
+                self.rows
+            ));
+        }
+
+        let end_column = end_column.min(self.columns);
+        let end_row = end_row.min(self.rows);
+        let first_column = first_column.min(end_column);
+        let first_row = first_row.min(end_row);
+
+        Self {
+            columns: end_column - first_column,
+            rows: end_row - first_row,
+            origin: (self.origin.0 + first_column, self.origin.1 + first_row),
+            ..*self
+        }
+    }
+
+    /// Returns the number of columns in the spritesheet.
+    pub const fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the number of rows in the spritesheet.
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the frame index at the given position in the spritesheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - the column of the frame
+    /// * `y` - the row of the frame
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position exceeds the spritesheet size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// const SPRITESHEET: Spritesheet = Spritesheet::new(4, 3);
+    ///
+    /// const INDEX: usize = SPRITESHEET.index(2, 1);
+    ///
+    /// assert_eq!(INDEX, 6);
+    /// ```
+    pub const fn index(&self, x: usize, y: usize) -> usize {
+        assert!(
+            x < self.columns && y < self.rows,
+            "position exceeds the spritesheet size"
+        );
+
+        self.layout_index(y * self.columns + x)
+    }
+
+    /// Returns the frame index at the given position in the spritesheet, or None if the position exceeds the spritesheet size.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - the column of the frame
+    /// * `y` - the row of the frame
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// let spritesheet = Spritesheet::new(4, 3);
+    ///
+    /// assert_eq!(spritesheet.index_of(2, 1), Some(6));
+    /// assert_eq!(spritesheet.index_of(4, 1), None);
+    /// ```
+    pub fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.columns && y < self.rows).then(|| self.layout_index(y * self.columns + x))
+    }
+
+    /// Returns the position of a frame in the spritesheet, or None if the index exceeds the spritesheet size.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - the index of the frame
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// let spritesheet = Spritesheet::new(4, 3);
+    ///
+    /// assert_eq!(spritesheet.coords_of(6), Some((2, 1)));
+    /// assert_eq!(spritesheet.coords_of(12), None);
+    /// ```
+    pub fn coords_of(&self, index: usize) -> Option<(usize, usize)> {
+        let (layout_columns, layout_rows) = self.layout_size;
+
+        if index >= layout_columns * layout_rows {
+            return None;
+        }
+
+        let x = (index % layout_columns).checked_sub(self.origin.0)?;
+        let y = (index / layout_columns).checked_sub(self.origin.1)?;
+
+        (x < self.columns && y < self.rows).then_some((x, y))
+    }
+
+    /// Returns the area of a frame in the image of the spritesheet, in pixels, or None if the index exceeds the spritesheet size.
+    ///
+    /// The size of the frames is derived from the size of the image, taking the [padding](Spritesheet::with_padding)
+    /// and the [border](Spritesheet::with_offset) into account.
+    /// The [inset](Spritesheet::with_inset) is not applied: the area covers the whole cell.
+    ///
+    /// This is convenient for tools that work on the pixels of the frames, like hitbox editors or debug overlays.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - the index of the frame
+    /// * `image_size` - the size of the image of the spritesheet
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// let spritesheet = Spritesheet::new(4, 2).with_padding(UVec2::splat(2));
+    ///
+    /// assert_eq!(
+    ///     spritesheet.cell_rect(5, UVec2::new(134, 66)),
+    ///     Some(URect::new(34, 34, 66, 66))
+    /// );
+    /// ```
+    pub fn cell_rect(&self, index: usize, image_size: UVec2) -> Option<URect> {
+        let (x, y) = self.coords_of(index)?;
+        let (x, y) = (x + self.origin.0, y + self.origin.1);
+
+        let grid_size = UVec2::new(self.layout_size.0 as u32, self.layout_size.1 as u32);
+        let padding = self.padding.unwrap_or(UVec2::ZERO);
+        let offset = self.offset.unwrap_or(UVec2::ZERO);
+
+        let frame_size =
+            image_size.saturating_sub(offset * 2 + padding * (grid_size - UVec2::ONE)) / grid_size;
+
+        let min = offset + (frame_size + padding) * UVec2::new(x as u32, y as u32);
+
+        Some(URect::from_corners(min, min + frame_size))
+    }
+
+    /// Same as [Spritesheet::row] but usable in `const` contexts.
+    ///
+    /// `N` must be the number of columns in the spritesheet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row exceeds the spritesheet size or if `N` does not match the number of columns.
+    pub const fn row_array<const N: usize>(&self, row: usize) -> [usize; N] {
+        assert!(
+            N == self.columns,
+            "the array size does not match the number of columns"
+        );
+
+        self.horizontal_strip_array(0, row)
+    }
+
+    /// Same as [Spritesheet::column] but usable in `const` contexts.
+    ///
+    /// `N` must be the number of rows in the spritesheet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column exceeds the spritesheet size or if `N` does not match the number of rows.
+    pub const fn column_array<const N: usize>(&self, column: usize) -> [usize; N] {
+        assert!(
+            N == self.rows,
+            "the array size does not match the number of rows"
+        );
+
+        self.vertical_strip_array(column, 0)
+    }
+
+    /// Same as [Spritesheet::horizontal_strip] but usable in `const` contexts, with `N` frames.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strip exceeds the spritesheet size.
+    pub const fn horizontal_strip_array<const N: usize>(&self, x: usize, y: usize) -> [usize; N] {
+        // Validate the starting position
+        self.index(x, y);
+
+        let first_index = y * self.columns + x;
+
+        assert!(
+            first_index + N <= self.columns * self.rows,
+            "horizontal strip exceeds the spritesheet size"
+        );
+
+        let mut frames = [0; N];
+
+        let mut i = 0;
+
+        while i < N {
+            frames[i] = self.layout_index(first_index + i);
+            i += 1;
+        }
+
+        frames
+    }
+
+    /// Same as [Spritesheet::vertical_strip] but usable in `const` contexts, with `N` frames.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strip exceeds the spritesheet size.
+    pub const fn vertical_strip_array<const N: usize>(&self, x: usize, y: usize) -> [usize; N] {
+        // Validate the starting position
+        self.index(x, y);
+
+        assert!(
+            N <= (self.columns - (x + 1)) * self.rows + self.rows - y,
+            "vertical strip exceeds the spritesheet size"
+        );
+
+        let mut frames = [0; N];
+
+        let mut i = 0;
+
+        while i < N {
+            let current_x = x + (y + i) / self.rows;
+            let current_y = (y + i) % self.rows;
+
+            frames[i] = self.layout_index(current_y * self.columns + current_x);
+            i += 1;
+        }
+
+        frames
+    }
+
+    /// Creates a [TextureAtlasLayout] from the spritesheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_width` - the width of a single frame
+    /// * `frame_height` - the height of a single frame
+    ///
+    /// The [padding](Spritesheet::with_padding), [offset](Spritesheet::with_offset) and [inset](Spritesheet::with_inset) of the spritesheet, if any, are taken into account.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
     /// # use bevy_spritesheet_animation::prelude::*;
     /// fn setup(
     ///     mut commands: Commands,
@@ -487,12 +1334,390 @@ impl Spritesheet {
     /// }
     /// ```
     pub fn atlas_layout(&self, frame_width: u32, frame_height: u32) -> TextureAtlasLayout {
-        TextureAtlasLayout::from_grid(
+        let mut layout = TextureAtlasLayout::from_grid(
             UVec2::new(frame_width, frame_height),
-            self.columns as u32,
-            self.rows as u32,
-            None,
-            None,
+            self.layout_size.0 as u32,
+            self.layout_size.1 as u32,
+            self.padding,
+            self.offset,
+        );
+
+        // Bevy does not count the border in the size of the layout
+
+        if let Some(offset) = self.offset {
+            layout.size += offset * 2;
+        }
+
+        if let Some(inset) = self.inset {
+            let inset = inset.min(UVec2::new(frame_width, frame_height) / 2);
+
+            for rect in &mut layout.textures {
+                rect.min += inset;
+                rect.max -= inset;
+            }
+        }
+
+        layout
+    }
+
+    /// Creates a texture atlas from the spritesheet, with a new [atlas layout](Spritesheet::atlas_layout).
+    fn texture_atlas(
+        &self,
+        frame_width: u32,
+        frame_height: u32,
+        atlas_layouts: &mut Assets<TextureAtlasLayout>,
+    ) -> TextureAtlas {
+        TextureAtlas {
+            layout: atlas_layouts.add(self.atlas_layout(frame_width, frame_height)),
+            index: 0,
+        }
+    }
+
+    /// Creates the components of a 2D sprite animated with the spritesheet, ready to be spawned.
+    ///
+    /// A new [atlas layout](Spritesheet::atlas_layout) is added to the assets on each call.
+    /// When spawning many sprites, prefer creating the layout once and sharing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - the image of the spritesheet
+    /// * `frame_width` - the width of a single frame
+    /// * `frame_height` - the height of a single frame
+    /// * `atlas_layouts` - the atlas layout assets
+    /// * `animation_id` - the animation to play
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spritesheet_animation::prelude::*;
+    /// fn setup(
+    ///     mut commands: Commands,
+    ///     mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    ///     assets: Res<AssetServer>,
+    /// #   animation_id: AnimationId
+    /// ) {
+    ///     let spritesheet = Spritesheet::new(8, 8);
+    ///
+    ///     // ... omitted: create an animation ...
+    ///
+    ///     commands.spawn(spritesheet.animated_sprite(
+    ///         assets.load("character.png"),
+    ///         96,
+    ///         96,
+    ///         &mut atlas_layouts,
+    ///         animation_id,
+    ///     ));
+    /// }
+    /// ```
+    pub fn animated_sprite(
+        &self,
+        image: Handle<Image>,
+        frame_width: u32,
+        frame_height: u32,
+        atlas_layouts: &mut Assets<TextureAtlasLayout>,
+        animation_id: AnimationId,
+    ) -> (Sprite, SpritesheetAnimation) {
+        let atlas = self.texture_atlas(frame_width, frame_height, atlas_layouts);
+
+        (
+            Sprite::from_atlas_image(image, atlas),
+            SpritesheetAnimation::from_id(animation_id),
         )
     }
+
+    /// Same as [Spritesheet::animated_sprite] but for a [3D sprite](Sprite3d).
+    pub fn animated_sprite3d(
+        &self,
+        image: Handle<Image>,
+        frame_width: u32,
+        frame_height: u32,
+        atlas_layouts: &mut Assets<TextureAtlasLayout>,
+        animation_id: AnimationId,
+    ) -> (Sprite3d, SpritesheetAnimation) {
+        let atlas = self.texture_atlas(frame_width, frame_height, atlas_layouts);
+
+        (
+            Sprite3d::from_atlas_image(image, atlas),
+            SpritesheetAnimation::from_id(animation_id),
+        )
+    }
+
+    /// Same as [Spritesheet::animated_sprite] but for a UI [ImageNode].
+    pub fn animated_image_node(
+        &self,
+        image: Handle<Image>,
+        frame_width: u32,
+        frame_height: u32,
+        atlas_layouts: &mut Assets<TextureAtlasLayout>,
+        animation_id: AnimationId,
+    ) -> (ImageNode, SpritesheetAnimation) {
+        let atlas = self.texture_atlas(frame_width, frame_height, atlas_layouts);
+
+        (
+            ImageNode::from_atlas_image(image, atlas),
+            SpritesheetAnimation::from_id(animation_id),
+        )
+    }
+}
+
+/// A [Spritesheet] whose cells can be referred to by name.
+///
+/// Naming the cells once keeps the definitions of the clips readable
+/// and only the names have to be updated when the spritesheet is reorganized.
+///
+/// All the layout queries of [Spritesheet] remain available.
+///
+/// # Example
+///
+/// ```
+/// // ┌───────────────┐
+/// // │idle_1   idle_2│
+/// // │flash    shoot │
+/// // └───────────────┘
+///
+/// # use bevy_spritesheet_animation::prelude::*;
+/// let mut spritesheet = NamedSpritesheet::new(Spritesheet::new(2, 2));
+///
+/// spritesheet
+///     .name_cell("idle_1", (0, 0))
+///     .name_cell("idle_2", (1, 0))
+///     .name_cell("muzzle_flash", (0, 1));
+///
+/// let idle_clip = Clip::from_frames(spritesheet.named_frames(["idle_1", "idle_2"]));
+///
+/// assert_eq!(idle_clip.frames(), vec![0, 1]);
+///
+/// // The other layout queries are still available
+///
+/// let shoot_clip = Clip::from_frames(spritesheet.row(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct NamedSpritesheet {
+    /// The spritesheet with the cells
+    spritesheet: Spritesheet,
+
+    /// The frame index of each named cell
+    cell_names: HashMap<String, usize>,
+}
+
+impl NamedSpritesheet {
+    /// Creates a new spritesheet helper with named cells from a [Spritesheet].
+    pub fn new(spritesheet: Spritesheet) -> Self {
+        Self {
+            spritesheet,
+            cell_names: HashMap::new(),
+        }
+    }
+
+    /// Names a cell of the spritesheet.
+    ///
+    /// If the name is already used, it now refers to this cell.
+    /// Positions that exceed the spritesheet size are ignored with a warning.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - the name of the cell
+    /// * `position` - the (x, y) position of the cell
+    pub fn name_cell(&mut self, name: impl Into<String>, position: (usize, usize)) -> &mut Self {
+        let (x, y) = position;
+
+        if x >= self.spritesheet.columns() || y >= self.spritesheet.rows() {
+            diagnostics::warning(format_args!(
+                "position ({x}, {y}) exceeds the spritesheet size ({}, {})",
+                self.spritesheet.columns(),
+                self.spritesheet.rows()
+            ));
+        } else {
+            self.cell_names
+                .insert(name.into(), self.spritesheet.index(x, y));
+        }
+
+        self
+    }
+
+    /// Same as [NamedSpritesheet::name_cell] but consumes and returns the spritesheet, for chaining.
+    pub fn with_named_cell(mut self, name: impl Into<String>, position: (usize, usize)) -> Self {
+        self.name_cell(name, position);
+        self
+    }
+
+    /// Returns the frame index of a named cell, if it exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - the name of the cell
+    pub fn named_cell(&self, name: impl AsRef<str>) -> Option<usize> {
+        self.cell_names.get(name.as_ref()).copied()
+    }
+
+    /// Returns all the named cells with their frame index.
+    pub fn cell_names(&self) -> &HashMap<String, usize> {
+        &self.cell_names
+    }
+
+    /// Returns the frame indices of named cells, in order.
+    ///
+    /// Unknown names are skipped with a warning.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - the names of the cells
+    pub fn named_frames<S: AsRef<str>>(&self, names: impl IntoIterator<Item = S>) -> Vec<usize> {
+        let mut indices = Vec::new();
+
+        for name in names {
+            let name = name.as_ref();
+
+            match self.named_cell(name) {
+                Some(index) => indices.push(index),
+                None => diagnostics::warning(format_args!(
+                    "no cell named \"{name}\" in the spritesheet"
+                )),
+            }
+        }
+
+        indices
+    }
+}
+
+impl Deref for NamedSpritesheet {
+    type Target = Spritesheet;
+
+    fn deref(&self) -> &Self::Target {
+        &self.spritesheet
+    }
+}
+
+/// An helper to obtain frame indices from a spritesheet whose frames are arbitrary rectangles.
+///
+/// Unlike [Spritesheet], the frames do not have to form a uniform grid: they can have different sizes and positions.
+/// The index of a frame is its position in the list of rectangles and [RectSpritesheet::atlas_layout] creates a matching [TextureAtlasLayout].
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spritesheet_animation::prelude::*;
+/// # let mut library = AnimationLibrary::default();
+/// // A spritesheet with a small idle frame and two wider attack frames
+///
+/// let spritesheet = RectSpritesheet::from_rects(
+///     UVec2::new(256, 64),
+///     [
+///         URect::new(0, 0, 32, 64),
+///         URect::new(32, 0, 128, 64),
+///         URect::new(128, 0, 224, 64),
+///     ],
+/// );
+///
+/// let attack_clip = Clip::from_frames(spritesheet.frames_within(URect::new(32, 0, 256, 64)));
+///
+/// assert_eq!(attack_clip.frames(), vec![1, 2]);
+///
+/// let layout = spritesheet.atlas_layout();
+///
+/// assert_eq!(layout.textures.len(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RectSpritesheet {
+    /// The size of the spritesheet image
+    size: UVec2,
+
+    /// The rectangle of each frame in the spritesheet image
+    rects: Vec<URect>,
+
+    /// The margin cropped inside each frame of the spritesheet
+    inset: Option<UVec2>,
+}
+
+impl RectSpritesheet {
+    /// Creates a new spritesheet helper from the rectangles of its frames.
+    ///
+    /// The rectangles that exceed the image are kept, so that the indices of the following frames do not shift, but a warning is reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - the size of the spritesheet image
+    /// * `rects` - the rectangle of each frame in the spritesheet image, in the order of their indices
+    pub fn from_rects(size: UVec2, rects: impl IntoIterator<Item = URect>) -> Self {
+        let rects: Vec<URect> = rects.into_iter().collect();
+
+        for (index, rect) in rects.iter().enumerate() {
+            if rect.max.x > size.x || rect.max.y > size.y {
+                diagnostics::warning(format_args!(
+                    "rect {index} ({rect:?}) exceeds the spritesheet size ({}, {})",
+                    size.x, size.y
+                ));
+            }
+        }
+
+        Self {
+            size,
+            rects,
+            inset: None,
+        }
+    }
+
+    /// Sets a margin cropped inside each frame of the spritesheet, in pixels.
+    ///
+    /// Same as [Spritesheet::with_inset]: this only affects the [atlas layouts](RectSpritesheet::atlas_layout), not the frame indices.
+    pub fn with_inset(self, inset: UVec2) -> Self {
+        Self {
+            inset: Some(inset),
+            ..self
+        }
+    }
+
+    /// Returns the frame indices for all of the spritesheet.
+    pub fn all(&self) -> Vec<usize> {
+        (0..self.rects.len()).collect()
+    }
+
+    /// Returns the rectangle of a frame, if it exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - the index of the frame
+    pub fn rect(&self, index: usize) -> Option<URect> {
+        self.rects.get(index).copied()
+    }
+
+    /// Returns the indices of the frames that are entirely contained in an area of the spritesheet.
+    ///
+    /// This is convenient to extract the frames laid out in a region of the image, like a row of frames of different widths.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - the area of the spritesheet image
+    pub fn frames_within(&self, area: URect) -> Vec<usize> {
+        self.rects
+            .iter()
+            .enumerate()
+            .filter(|(_, rect)| area.union(**rect) == area)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Creates a [TextureAtlasLayout] with the rectangles of the frames.
+    ///
+    /// The [inset](RectSpritesheet::with_inset) of the spritesheet, if any, is taken into account.
+    pub fn atlas_layout(&self) -> TextureAtlasLayout {
+        let mut layout = TextureAtlasLayout::new_empty(self.size);
+
+        for rect in &self.rects {
+            let mut rect = *rect;
+
+            if let Some(inset) = self.inset {
+                let inset = inset.min(rect.size() / 2);
+
+                rect.min += inset;
+                rect.max -= inset;
+            }
+
+            layout.add_texture(rect);
+        }
+
+        layout
+    }
 }
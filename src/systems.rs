@@ -1,2 +1,17 @@
+#[cfg(feature = "animation_states")]
+pub mod animation_by_state;
+pub mod animation_event_filter;
+pub mod animation_scrubber;
+pub mod animation_state_machine;
+pub mod despawn_on_animation_end;
+pub mod image_node;
+pub mod image_sequence;
+#[cfg(feature = "gamepad_rumble")]
+pub mod marker_rumble;
+pub mod phase_from_position;
+pub mod play_variant;
+pub mod shake_on_marker;
 pub mod sprite3d;
+pub mod sprite_rig;
 pub mod spritesheet_animation;
+pub mod window_focus;
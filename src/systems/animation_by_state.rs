@@ -0,0 +1,56 @@
+use bevy::{
+    ecs::{
+        change_detection::{DetectChanges, Ref},
+        entity::Entity,
+        system::{Commands, Query, Res},
+    },
+    state::state::{State, States},
+};
+
+use crate::components::{
+    animation_by_state::AnimationByState, spritesheet_animation::SpritesheetAnimation,
+};
+
+/// Switches the animations of the entities with an [AnimationByState] component when the state changes.
+///
+/// The entities whose component was just added or changed also switch to the animation of the current state.
+pub fn switch_animations_by_state<S: States>(
+    mut commands: Commands,
+    state: Option<Res<State<S>>>,
+    mut entities: Query<(
+        Entity,
+        Ref<AnimationByState<S>>,
+        Option<&mut SpritesheetAnimation>,
+    )>,
+) {
+    // The state may not exist yet or anymore
+
+    let Some(state) = state else {
+        return;
+    };
+
+    let state_changed = state.is_changed();
+
+    for (entity, animation_by_state, animation) in &mut entities {
+        if !state_changed && !animation_by_state.is_changed() {
+            continue;
+        }
+
+        let Some(animation_id) = animation_by_state.animation(state.get()) else {
+            continue;
+        };
+
+        match animation {
+            Some(mut animation) => {
+                if animation.animation_id != animation_id {
+                    animation.switch(animation_id);
+                }
+            }
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(SpritesheetAnimation::from_id(animation_id));
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use bevy::ecs::{event::EventReader, system::Query};
+
+use crate::{components::animation_event_filter::AnimationEventFilter, events::AnimationEvent};
+
+/// Routes the animation events to the buffers of the [AnimationEventFilter]s of their entities.
+pub fn route_animation_events(
+    mut animation_events: EventReader<AnimationEvent>,
+    mut filters: Query<&mut AnimationEventFilter>,
+) {
+    // Clear the events of the previous update
+    // (only flagging the filters that had events as changed)
+
+    for mut filter in &mut filters {
+        if !filter.events.is_empty() {
+            filter.events.clear();
+        }
+    }
+
+    for event in animation_events.read() {
+        let Ok(mut filter) = filters.get_mut(event.entity()) else {
+            continue;
+        };
+
+        if filter.matches(event) {
+            filter.events.push(*event);
+        }
+    }
+}
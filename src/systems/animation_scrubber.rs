@@ -0,0 +1,178 @@
+use bevy::{
+    ecs::{
+        change_detection::DetectChangesMut,
+        entity::Entity,
+        query::With,
+        system::{Commands, Query, Res, ResMut},
+    },
+    hierarchy::{BuildChildren, ChildBuild, Children, DespawnRecursiveExt},
+    ui::{BackgroundColor, Interaction, Node, PositionType, RelativeCursorPosition, Val},
+};
+
+use crate::{
+    animator::cache::AnimationCacheEvent,
+    components::{
+        animation_scrubber::{
+            AnimationScrubber, ScrubberClipBoundary, ScrubberFrame, ScrubberMarker,
+        },
+        spritesheet_animation::{AnimationProgress, SpritesheetAnimation},
+    },
+    diagnostics,
+    library::AnimationLibrary,
+};
+
+/// Width of the clip boundaries and markers, in pixels
+const TICK_WIDTH: f32 = 2.0;
+
+/// Rebuilds the children of the scrubbers when their target switches to a new animation.
+pub fn build_scrubbers(
+    mut commands: Commands,
+    mut library: ResMut<AnimationLibrary>,
+    mut scrubbers: Query<(Entity, &mut AnimationScrubber)>,
+    targets: Query<&SpritesheetAnimation>,
+) {
+    for (entity, mut scrubber) in &mut scrubbers {
+        let Ok(target_animation) = targets.get(scrubber.target) else {
+            continue;
+        };
+
+        let animation_id = target_animation.animation_id;
+
+        if scrubber
+            .displayed_animation
+            .is_some_and(|(displayed_id, _)| displayed_id == animation_id)
+        {
+            continue;
+        }
+
+        // The library only updates its internal caches, which should not be reported as a change
+        let cache = library
+            .bypass_change_detection()
+            .get_animation_cache(animation_id);
+
+        let frame_count = cache.frames.len();
+
+        scrubber.displayed_animation = Some((animation_id, frame_count));
+
+        let position =
+            |frame: usize| Val::Percent(frame as f32 / frame_count.max(1) as f32 * 100.0);
+
+        let tick = |frame: usize| Node {
+            position_type: PositionType::Absolute,
+            left: position(frame),
+            width: Val::Px(TICK_WIDTH),
+            height: Val::Percent(100.0),
+            ..Default::default()
+        };
+
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|parent| {
+                // The current frame
+
+                parent.spawn((
+                    ScrubberFrame,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: position(0),
+                        width: Val::Percent(100.0 / frame_count.max(1) as f32),
+                        height: Val::Percent(100.0),
+                        ..Default::default()
+                    },
+                    BackgroundColor(scrubber.frame_color),
+                ));
+
+                for (frame_index, frame) in cache.frames.iter().enumerate() {
+                    // Clip boundaries
+
+                    let starts_clip =
+                        frame_index > 0 && cache.frames[frame_index - 1].clip_id != frame.clip_id;
+
+                    if starts_clip {
+                        parent.spawn((
+                            ScrubberClipBoundary,
+                            tick(frame_index),
+                            BackgroundColor(scrubber.clip_color),
+                        ));
+                    }
+
+                    // Markers
+
+                    for event in &frame.events {
+                        if let AnimationCacheEvent::MarkerHit { .. } = event {
+                            parent.spawn((
+                                ScrubberMarker,
+                                tick(frame_index),
+                                BackgroundColor(scrubber.marker_color),
+                            ));
+                        }
+                    }
+                }
+            });
+    }
+}
+
+/// Moves the animations to the frames selected with the scrubbers.
+pub fn seek_with_scrubbers(
+    library: Res<AnimationLibrary>,
+    scrubbers: Query<(&AnimationScrubber, &Interaction, &RelativeCursorPosition)>,
+    mut targets: Query<&mut SpritesheetAnimation>,
+) {
+    for (scrubber, interaction, cursor_position) in &scrubbers {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let (Some(frame_count), Some(cursor)) =
+            (scrubber.frame_count(), cursor_position.normalized)
+        else {
+            continue;
+        };
+
+        let Ok(mut target_animation) = targets.get_mut(scrubber.target) else {
+            continue;
+        };
+
+        let frame = ((cursor.x.clamp(0.0, 1.0) * frame_count as f32) as usize)
+            .min(frame_count.saturating_sub(1));
+
+        let progress = AnimationProgress {
+            frame,
+            repetition: target_animation.progress.repetition,
+        };
+
+        if progress != target_animation.progress {
+            if let Err(error) = target_animation.set_progress(progress, &library) {
+                diagnostics::warning(format_args!("{error}, cannot seek with scrubber"));
+            }
+        }
+    }
+}
+
+/// Moves the current frame of the scrubbers to the progress of their target.
+pub fn sync_scrubbers(
+    scrubbers: Query<(&AnimationScrubber, &Children)>,
+    targets: Query<&SpritesheetAnimation>,
+    mut frame_nodes: Query<&mut Node, With<ScrubberFrame>>,
+) {
+    for (scrubber, children) in &scrubbers {
+        let (Some(frame_count), Ok(target_animation)) =
+            (scrubber.frame_count(), targets.get(scrubber.target))
+        else {
+            continue;
+        };
+
+        let left = Val::Percent(
+            target_animation.progress.frame as f32 / frame_count.max(1) as f32 * 100.0,
+        );
+
+        for child in children {
+            if let Ok(mut node) = frame_nodes.get_mut(*child) {
+                if node.left != left {
+                    node.left = left;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use bevy::ecs::{
+    entity::Entity,
+    event::{EventReader, EventWriter},
+    system::Query,
+};
+
+use crate::{
+    components::{
+        animation_state_machine::{
+            AnimationStateChanged, AnimationStateMachine, TransitionCondition,
+        },
+        spritesheet_animation::SpritesheetAnimation,
+    },
+    events::AnimationEvent,
+};
+
+/// Switches the animations of the entities with an [AnimationStateMachine] when their transitions are met.
+pub fn drive_state_machines(
+    mut animation_events: EventReader<AnimationEvent>,
+    mut state_events: EventWriter<AnimationStateChanged>,
+    mut state_machines: Query<(
+        Entity,
+        &mut AnimationStateMachine,
+        &mut SpritesheetAnimation,
+    )>,
+) {
+    // Group the animation events by entity
+
+    let mut entity_events: HashMap<Entity, Vec<&AnimationEvent>> = HashMap::new();
+
+    for event in animation_events.read() {
+        let entity = match event {
+            AnimationEvent::AnimationEnd { entity, .. }
+            | AnimationEvent::MarkerHit { entity, .. } => *entity,
+            _ => continue,
+        };
+
+        entity_events.entry(entity).or_default().push(event);
+    }
+
+    for (entity, mut state_machine, mut animation) in &mut state_machines {
+        // Start in the initial state
+
+        if state_machine.is_added() && animation.animation_id != state_machine.state() {
+            animation.switch(state_machine.state());
+        }
+
+        // Consider the triggers first
+        //
+        // (only taken when there are some to avoid needless change detection)
+
+        let triggers = if state_machine.has_pending_triggers() {
+            state_machine.take_triggers()
+        } else {
+            Vec::new()
+        };
+
+        let mut next_state = triggers
+            .into_iter()
+            .find_map(|trigger| state_machine.next_state(TransitionCondition::Trigger(trigger)));
+
+        // Then the events of the current animation
+
+        if next_state.is_none() {
+            next_state = entity_events
+                .get(&entity)
+                .into_iter()
+                .flatten()
+                .filter_map(|event| match event {
+                    AnimationEvent::AnimationEnd { animation_id, .. }
+                        if *animation_id == state_machine.state() =>
+                    {
+                        Some(TransitionCondition::AnimationEnd)
+                    }
+                    AnimationEvent::MarkerHit {
+                        marker_id,
+                        animation_id,
+                        ..
+                    } if *animation_id == state_machine.state() => {
+                        Some(TransitionCondition::Marker(*marker_id))
+                    }
+                    _ => None,
+                })
+                .find_map(|condition| state_machine.next_state(condition));
+        }
+
+        if let Some(next_state) = next_state {
+            let previous_state = state_machine.state();
+
+            state_machine.set_state(next_state);
+            animation.switch(next_state);
+
+            state_events.send(AnimationStateChanged {
+                entity,
+                from: previous_state,
+                to: next_state,
+            });
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use bevy::{
+    ecs::{
+        event::EventReader,
+        system::{Commands, Query},
+    },
+    hierarchy::DespawnRecursiveExt,
+};
+
+use crate::{
+    components::{
+        despawn_on_animation_end::DespawnOnAnimationEnd,
+        spritesheet_animation::SpritesheetAnimation,
+    },
+    events::AnimationEvent,
+};
+
+/// Cleans up the entities with a [DespawnOnAnimationEnd] component when their animation ends.
+pub fn despawn_on_animation_end(
+    mut commands: Commands,
+    mut animation_events: EventReader<AnimationEvent>,
+    entities: Query<(&DespawnOnAnimationEnd, &SpritesheetAnimation)>,
+) {
+    for event in animation_events.read() {
+        let AnimationEvent::AnimationEnd {
+            entity,
+            animation_id,
+        } = event
+        else {
+            continue;
+        };
+
+        let Ok((despawn, animation)) = entities.get(*entity) else {
+            continue;
+        };
+
+        // The entity already moved on to another animation
+
+        if animation.animation_id != *animation_id {
+            continue;
+        }
+
+        match despawn {
+            DespawnOnAnimationEnd::Despawn => commands.entity(*entity).despawn_recursive(),
+            DespawnOnAnimationEnd::RemoveAnimation => {
+                commands.entity(*entity).remove::<SpritesheetAnimation>();
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use bevy::{
+    asset::Assets,
+    ecs::{
+        change_detection::DetectChanges,
+        query::{Changed, Or},
+        system::{Query, Res},
+        world::Ref,
+    },
+    math::Vec2,
+    sprite::TextureAtlasLayout,
+    ui::{widget::ImageNode, ComputedNode, Node, Val},
+};
+
+use crate::components::image_node_frame_size::ImageNodeFrameSize;
+
+/// Resizes the UI images with an [ImageNodeFrameSize] to the size of their current frame.
+///
+/// Pixel-perfect images are also resized when their scale factor changes.
+pub fn resize_image_nodes(
+    atlas_layouts: Res<Assets<TextureAtlasLayout>>,
+    mut image_nodes: Query<
+        (
+            Ref<ImageNode>,
+            Ref<ImageNodeFrameSize>,
+            Option<Ref<ComputedNode>>,
+            &mut Node,
+        ),
+        Or<(
+            Changed<ImageNode>,
+            Changed<ImageNodeFrameSize>,
+            Changed<ComputedNode>,
+        )>,
+    >,
+) {
+    for (image_node, frame_size, computed_node, mut node) in &mut image_nodes {
+        // Only the pixel-perfect images depend on the computed layout, which includes the scale factor
+
+        let scale_factor_may_have_changed = frame_size.pixel_perfect
+            && computed_node
+                .as_ref()
+                .is_some_and(|computed_node| computed_node.is_changed());
+
+        if !image_node.is_changed() && !frame_size.is_changed() && !scale_factor_may_have_changed {
+            continue;
+        }
+
+        let Some(frame_rect) = image_node.texture_atlas.as_ref().and_then(|atlas| {
+            atlas_layouts
+                .get(&atlas.layout)
+                .and_then(|layout| layout.textures.get(atlas.index))
+        }) else {
+            continue;
+        };
+
+        let scale = if frame_size.pixel_perfect {
+            // Round the scale so that each texel covers a whole number of physical pixels
+
+            let scale_factor = computed_node.map_or(1.0, |computed_node| {
+                computed_node.inverse_scale_factor().recip()
+            });
+
+            (frame_size.scale * scale_factor).round().max(Vec2::ONE) / scale_factor
+        } else {
+            frame_size.scale
+        };
+
+        let size = frame_rect.size().as_vec2() * scale;
+
+        let width = Val::Px(size.x);
+        let height = Val::Px(size.y);
+
+        // We compare the sizes to prevent needless layout updates
+
+        if node.width != width || node.height != height {
+            node.width = width;
+            node.height = height;
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use bevy::{
+    asset::{Assets, Handle, LoadedFolder},
+    ecs::{
+        entity::Entity,
+        query::Without,
+        system::{Commands, Query, Res, ResMut},
+    },
+    image::Image,
+    sprite::{Sprite, TextureAtlas, TextureAtlasBuilder, TextureAtlasLayout},
+};
+
+use crate::{
+    animation::Animation,
+    clip::Clip,
+    components::{image_sequence::ImageSequence, spritesheet_animation::SpritesheetAnimation},
+    diagnostics,
+    library::AnimationLibrary,
+};
+
+/// Builds the atlases and animations of the image sequences whose frames are loaded.
+pub fn build_image_sequences(
+    mut commands: Commands,
+    sequences: Query<(Entity, &ImageSequence), Without<SpritesheetAnimation>>,
+    folders: Res<Assets<LoadedFolder>>,
+    mut images: ResMut<Assets<Image>>,
+    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    mut library: ResMut<AnimationLibrary>,
+) {
+    for (entity, sequence) in &sequences {
+        let Some(folder) = folders.get(&sequence.folder) else {
+            continue;
+        };
+
+        // Sort the frames by file name
+
+        let mut frame_handles: Vec<Handle<Image>> = folder
+            .handles
+            .iter()
+            .filter_map(|handle| handle.clone().try_typed::<Image>().ok())
+            .collect();
+
+        frame_handles.sort_by_cached_key(|handle| handle.path().map(|path| path.to_string()));
+
+        // Wait for all the frames to be loaded
+
+        if frame_handles
+            .iter()
+            .any(|handle| images.get(handle).is_none())
+        {
+            continue;
+        }
+
+        if frame_handles.is_empty() {
+            diagnostics::warning(format_args!(
+                "the image sequence of entity {entity} has no images"
+            ));
+
+            commands.entity(entity).remove::<ImageSequence>();
+            continue;
+        }
+
+        // Stitch the frames into an atlas
+
+        let mut builder = TextureAtlasBuilder::default();
+
+        for handle in &frame_handles {
+            if let Some(image) = images.get(handle) {
+                builder.add_texture(Some(handle.id()), image);
+            }
+        }
+
+        let (layout, sources, atlas_image) = match builder.build() {
+            Ok(atlas) => atlas,
+            Err(error) => {
+                diagnostics::warning(format_args!(
+                    "cannot build the atlas of the image sequence of entity {entity}: {error}"
+                ));
+
+                commands.entity(entity).remove::<ImageSequence>();
+                continue;
+            }
+        };
+
+        // Create an animation that plays the frames in order
+
+        let frames: Vec<usize> = frame_handles
+            .iter()
+            .filter_map(|handle| sources.texture_index(handle.id()))
+            .collect();
+
+        let first_frame = frames[0];
+
+        let mut clip = Clip::from_frames(frames);
+
+        if let Some(duration) = sequence.duration {
+            clip.set_duration(duration);
+        }
+
+        let clip_id = library.register_clip(clip);
+
+        let mut animation = Animation::from_clip(clip_id);
+
+        if let Some(repetitions) = sequence.repetitions {
+            animation.set_repetitions(repetitions);
+        }
+
+        let animation_id = library.register_animation(animation);
+
+        commands.entity(entity).insert((
+            Sprite::from_atlas_image(
+                images.add(atlas_image),
+                TextureAtlas {
+                    layout: atlas_layouts.add(layout),
+                    index: first_frame,
+                },
+            ),
+            SpritesheetAnimation::from_id(animation_id),
+        ));
+    }
+}
@@ -0,0 +1,43 @@
+use bevy::{
+    ecs::{
+        event::{EventReader, EventWriter},
+        system::Query,
+    },
+    input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest},
+};
+
+use crate::{components::marker_rumble::MarkerRumble, events::AnimationEvent};
+
+/// Requests the gamepad rumbles of the markers hit by animations.
+pub fn rumble_on_markers(
+    mut animation_events: EventReader<AnimationEvent>,
+    mut rumble_requests: EventWriter<GamepadRumbleRequest>,
+    rumbles: Query<&MarkerRumble>,
+) {
+    for event in animation_events.read() {
+        let AnimationEvent::MarkerHit {
+            entity, marker_id, ..
+        } = event
+        else {
+            continue;
+        };
+
+        let Some((rumble, pattern)) = rumbles.get(*entity).ok().and_then(|rumble| {
+            rumble
+                .patterns
+                .get(marker_id)
+                .map(|pattern| (rumble, pattern))
+        }) else {
+            continue;
+        };
+
+        rumble_requests.send(GamepadRumbleRequest::Add {
+            gamepad: rumble.gamepad,
+            intensity: GamepadRumbleIntensity {
+                strong_motor: pattern.strong_motor,
+                weak_motor: pattern.weak_motor,
+            },
+            duration: pattern.duration,
+        });
+    }
+}
@@ -0,0 +1,60 @@
+use bevy::{
+    ecs::{
+        entity::Entity,
+        query::{Added, Or},
+        system::{Query, Res},
+    },
+    transform::helper::TransformHelper,
+};
+
+use crate::{
+    components::{
+        phase_from_position::PhaseFromPosition,
+        spritesheet_animation::{AnimationProgress, SpritesheetAnimation},
+    },
+    hash::hash_u64,
+    library::AnimationLibrary,
+};
+
+/// Sets the starting progress of the animations with a [PhaseFromPosition] from the position of their entity.
+pub fn offset_phases_from_positions(
+    library: Res<AnimationLibrary>,
+    transform_helper: TransformHelper,
+    mut animations: Query<
+        (Entity, &PhaseFromPosition, &mut SpritesheetAnimation),
+        Or<(Added<PhaseFromPosition>, Added<SpritesheetAnimation>)>,
+    >,
+) {
+    for (entity, phase, mut animation) in &mut animations {
+        // Keep explicit starting progresses
+
+        if animation.progress != AnimationProgress::default() {
+            continue;
+        }
+
+        let frame_count = library.animation_frames(animation.animation_id).count();
+
+        if frame_count == 0 {
+            continue;
+        }
+
+        // Compute the global transform from the hierarchy: entities spawned during this frame
+        // do not have a propagated GlobalTransform yet
+
+        let Ok(global_transform) = transform_helper.compute_global_transform(entity) else {
+            continue;
+        };
+
+        let cell = (global_transform.translation() * phase.scale)
+            .floor()
+            .as_ivec3();
+
+        // Pack the cell coordinates into a single value to hash
+
+        let packed_cell = (cell.x as u32 as u64)
+            ^ ((cell.y as u32 as u64) << 21)
+            ^ ((cell.z as u32 as u64) << 42);
+
+        animation.progress.frame = (hash_u64(packed_cell) % frame_count as u64) as usize;
+    }
+}
@@ -0,0 +1,35 @@
+use bevy::ecs::{
+    entity::Entity,
+    query::Changed,
+    system::{Commands, Query},
+};
+
+use crate::components::{play_variant::PlayVariant, spritesheet_animation::SpritesheetAnimation};
+
+/// Plays the animations picked by the [PlayVariant] components that were added or changed.
+pub fn play_variants(
+    mut commands: Commands,
+    mut variants: Query<
+        (Entity, &PlayVariant, Option<&mut SpritesheetAnimation>),
+        Changed<PlayVariant>,
+    >,
+) {
+    for (entity, variant, animation) in &mut variants {
+        let Some(animation_id) = variant.set.pick(variant.seed) else {
+            continue;
+        };
+
+        match animation {
+            Some(mut animation) => {
+                if animation.animation_id != animation_id {
+                    animation.switch(animation_id);
+                }
+            }
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(SpritesheetAnimation::from_id(animation_id));
+            }
+        }
+    }
+}
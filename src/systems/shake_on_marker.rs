@@ -0,0 +1,39 @@
+use bevy::ecs::{
+    event::{EventReader, EventWriter},
+    system::Query,
+};
+
+use crate::{
+    components::shake_on_marker::{CameraShakeRequest, ShakeOnMarker},
+    events::AnimationEvent,
+};
+
+/// Requests the camera shakes of the markers hit by animations.
+pub fn request_camera_shakes(
+    mut animation_events: EventReader<AnimationEvent>,
+    mut shake_requests: EventWriter<CameraShakeRequest>,
+    shakes: Query<&ShakeOnMarker>,
+) {
+    for event in animation_events.read() {
+        let AnimationEvent::MarkerHit {
+            entity, marker_id, ..
+        } = event
+        else {
+            continue;
+        };
+
+        let Some(shake) = shakes
+            .get(*entity)
+            .ok()
+            .filter(|shake| shake.marker == *marker_id)
+        else {
+            continue;
+        };
+
+        shake_requests.send(CameraShakeRequest {
+            entity: *entity,
+            amplitude: shake.amplitude,
+            duration: shake.duration,
+        });
+    }
+}
@@ -1,4 +1,8 @@
-use std::{collections::HashMap, hash::Hash};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    ops::Range,
+};
 
 use bevy::{
     asset::{Assets, Handle},
@@ -7,18 +11,27 @@ use bevy::{
         query::Changed,
         system::{Commands, Query, Res, ResMut, Resource},
     },
+    image::ImageSampler,
+    math::{Vec3, Vec3A},
     pbr::StandardMaterial,
     prelude::*,
     render::{
         alpha::AlphaMode,
-        mesh::{Mesh, PrimitiveTopology},
+        mesh::{Indices, Mesh, PrimitiveTopology, VertexAttributeValues},
         render_asset::RenderAssetUsages,
         render_resource::Face,
+        view::NoFrustumCulling,
     },
     sprite::TextureAtlasLayout,
 };
 
-use crate::prelude::Sprite3d;
+use crate::{
+    diagnostics,
+    prelude::{
+        Sprite3d, Sprite3dPixelPerfect, Sprite3dPlaceholder, Sprite3dReady, Sprite3dSampler,
+        Sprite3dUvInset,
+    },
+};
 
 /// Cached data for the 3D sprites
 #[derive(Resource, Debug, Default, Reflect)]
@@ -31,10 +44,71 @@ pub struct Cache {
 
     /// Meshes used by the 3D sprites.
     ///
-    /// Shared when the size, flips and atlas are the same.
+    /// Shared when the size, flips, atlas, UV inset and base mesh are the same.
     meshes: HashMap<MeshId, Handle<Mesh>>,
+
+    /// Copies of the images with the samplers of the 3D sprites that override them.
+    #[reflect(ignore)]
+    sampled_images: HashMap<(AssetId<Image>, Sprite3dSampler), Handle<Image>>,
+
+    /// The atlas layouts and indices that have already been reported as unavailable, to only warn once about each of them.
+    #[reflect(ignore)]
+    reported_atlases: HashSet<(AssetId<TextureAtlasLayout>, Option<usize>)>,
+}
+
+impl Cache {
+    /// Returns the image to render a 3D sprite with, taking its sampler into account.
+    ///
+    /// Returns None if the sprite has a sampler but its image is not loaded yet.
+    fn texture(&self, sprite: &Sprite3d) -> Option<Handle<Image>> {
+        match sprite.sampler {
+            Some(sampler) => self
+                .sampled_images
+                .get(&(sprite.image.id(), sampler))
+                .cloned(),
+            None => Some(sprite.image.clone()),
+        }
+    }
+}
+
+/// Batched meshes for the 3D sprites, when batching is enabled
+#[derive(Resource, Debug, Default)]
+pub struct Batches {
+    /// The entities rendering the batched sprites, one per material
+    batches: HashMap<AssetId<StandardMaterial>, Batch>,
+
+    /// The material of each batched sprite
+    sprite_materials: HashMap<Entity, AssetId<StandardMaterial>>,
+
+    /// The visible sprites that could not be batched yet because their mesh or material is not ready
+    pending_sprites: HashSet<Entity>,
+}
+
+#[derive(Debug)]
+struct Batch {
+    entity: Entity,
+    mesh: Handle<Mesh>,
+
+    /// The vertices of each sprite in the merged mesh
+    vertices: HashMap<Entity, Range<usize>>,
 }
 
+/// The vertices of a sprite in world space, ready to be merged
+struct BatchPart {
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    uvs: Vec<[f32; 2]>,
+    indices: Vec<u32>,
+}
+
+/// Marks the entities that render batched 3D sprites
+#[derive(Component, Debug)]
+pub struct Sprite3dBatch;
+
+/// Marks the 3D sprites that currently display their placeholder
+#[derive(Component, Debug)]
+pub struct Sprite3dPlaceholderShown;
+
 /// Uniquely identifies a sprite material
 #[derive(Debug, Hash, PartialEq, Eq, Reflect)]
 #[reflect(Debug, Hash, PartialEq)]
@@ -44,6 +118,11 @@ struct MaterialId {
     alpha_mode: HashableAlphaMode,
     unlit: bool,
     emissive: HashableLinearRgba,
+    perceptual_roughness: u32,
+    reflectance: u32,
+    metallic: u32,
+    depth_bias: u32,
+    material_template: Option<AssetId<StandardMaterial>>,
 }
 
 #[derive(Eq, PartialEq, Debug, Reflect)]
@@ -94,6 +173,14 @@ impl MaterialId {
             alpha_mode: HashableAlphaMode(sprite.alpha_mode),
             unlit: sprite.unlit,
             emissive: HashableLinearRgba::new(sprite.emissive),
+            perceptual_roughness: sprite.perceptual_roughness.to_bits(),
+            reflectance: sprite.reflectance.to_bits(),
+            metallic: sprite.metallic.to_bits(),
+            depth_bias: sprite.depth_bias.to_bits(),
+            material_template: sprite
+                .material_template
+                .as_ref()
+                .map(|material| material.id()),
         }
     }
 }
@@ -108,6 +195,8 @@ struct MeshId {
     sprite_flip_y: bool,
     image_size: UVec2,
     atlas_rect: URect,
+    uv_inset: [u32; 2],
+    base_mesh: Option<AssetId<Mesh>>,
 }
 
 impl MeshId {
@@ -126,11 +215,50 @@ impl MeshId {
             sprite_flip_y: sprite.flip_y,
             image_size: image.size(),
             atlas_rect: *atlas_rect,
+            uv_inset: match sprite.uv_inset {
+                Sprite3dUvInset::None => [0, 0],
+                Sprite3dUvInset::HalfTexel => [1, 0],
+                Sprite3dUvInset::Pixels(pixels) => [2, pixels.to_bits()],
+            },
+            base_mesh: sprite.base_mesh.as_ref().map(|mesh| mesh.id()),
+        }
+    }
+}
+
+/// Copies the images of the 3D sprites that override their sampler.
+pub fn prepare_sampled_images(
+    mut images: ResMut<Assets<Image>>,
+    mut cache: ResMut<Cache>,
+    sprites: Query<&Sprite3d>,
+) {
+    for sprite in &sprites {
+        let Some(sampler) = sprite.sampler else {
+            continue;
+        };
+
+        let key = (sprite.image.id(), sampler);
+
+        if cache.sampled_images.contains_key(&key) {
+            continue;
         }
+
+        let Some(image) = images.get(&sprite.image) else {
+            continue;
+        };
+
+        let mut sampled_image = image.clone();
+        sampled_image.sampler = ImageSampler::Descriptor(sampler.descriptor());
+
+        let sampled_image_handle = images.add(sampled_image);
+
+        cache.sampled_images.insert(key, sampled_image_handle);
     }
 }
 
 /// Setups 3D sprites for rendering by attaching the 3D geometry and materials to display them.
+///
+/// A placeholder is displayed until the sprite's image is loaded, if the sprite has one.
+#[allow(clippy::too_many_arguments)]
 pub fn setup_rendering(
     mut commands: Commands,
     atlas_layouts: Res<Assets<TextureAtlasLayout>>,
@@ -138,48 +266,153 @@ pub fn setup_rendering(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut cache: ResMut<Cache>,
+    mut ready_events: EventWriter<Sprite3dReady>,
     sprites: Query<
         (
             Entity,
-            &Sprite3d,
+            Ref<Sprite3d>,
             Option<&Mesh3d>,
             Option<&MeshMaterial3d<StandardMaterial>>,
+            Has<Sprite3dPlaceholderShown>,
         ),
-        Or<(Without<Mesh3d>, Without<MeshMaterial3d<StandardMaterial>>)>,
+        Or<(
+            Without<Mesh3d>,
+            Without<MeshMaterial3d<StandardMaterial>>,
+            With<Sprite3dPlaceholderShown>,
+        )>,
     >,
 ) {
-    for (entity, sprite, maybe_mesh, maybe_material) in &sprites {
-        // Add a mesh to the entity if it does not have one yet
-
-        if maybe_mesh.is_none() {
-            try_get_or_create_mesh(sprite, &images, &atlas_layouts, &mut meshes, &mut cache)
-                .inspect(|mesh_handle| {
-                    commands.entity(entity).insert(Mesh3d(mesh_handle.clone()));
-                });
+    for (entity, sprite, maybe_mesh, maybe_material, placeholder_shown) in &sprites {
+        let mesh =
+            try_get_or_create_mesh(&sprite, &images, &atlas_layouts, &mut meshes, &mut cache);
+
+        // Display the placeholder while the actual mesh cannot be created
+        // (and update it when the sprite changes, for instance when its atlas index is animated)
+
+        if mesh.is_none() && (!placeholder_shown || sprite.is_changed()) {
+            if let Some((placeholder_mesh, placeholder_material)) =
+                create_placeholder(&sprite, &atlas_layouts, &materials)
+            {
+                commands.entity(entity).insert((
+                    Mesh3d(meshes.add(placeholder_mesh)),
+                    MeshMaterial3d(materials.add(placeholder_material)),
+                    Sprite3dPlaceholderShown,
+                ));
+
+                continue;
+            }
         }
 
-        // Add a material to the entity if it does not have one yet
-
-        if maybe_material.is_none() {
-            let material_handle = materials.add(StandardMaterial {
-                base_color_texture: Some(sprite.image.clone()),
-                base_color: sprite.color,
-                cull_mode: Some(Face::Back),
-                unlit: sprite.unlit,
-                alpha_mode: sprite.alpha_mode,
-                emissive: sprite.emissive,
-                // TODO
-                // these are sensible values for 3d rendering,
-                // but could be extended to public API
-                perceptual_roughness: 0.5,
-                reflectance: 0.15,
-                ..default()
-            });
+        // Add a mesh to the entity if it does not have one yet (or only the placeholder's)
+
+        if let Some(mesh_handle) = &mesh {
+            if maybe_mesh.is_none() || placeholder_shown {
+                commands.entity(entity).insert(Mesh3d(mesh_handle.clone()));
+            }
+        }
+
+        // Add a material to the entity if it does not have one yet (or only the placeholder's)
+
+        if maybe_material.is_none() || (placeholder_shown && mesh.is_some()) {
+            // Wait for the image to be loaded if the sprite overrides its sampler
+
+            let Some(texture) = cache.texture(&sprite) else {
+                continue;
+            };
+
+            let material = create_material(&sprite, sprite.color, Some(texture), &materials);
+
+            let material_handle = materials.add(material);
 
             commands
                 .entity(entity)
                 .insert(MeshMaterial3d(material_handle));
         }
+
+        // The sprite is now rendered with its actual image
+
+        if mesh.is_some() {
+            if placeholder_shown {
+                commands.entity(entity).remove::<Sprite3dPlaceholderShown>();
+            }
+
+            ready_events.send(Sprite3dReady { entity });
+        }
+    }
+}
+
+/// Sizes the 3D sprites with a [Sprite3dPixelPerfect] component for their camera.
+pub fn size_pixel_perfect_sprites(
+    images: Res<Assets<Image>>,
+    atlas_layouts: Res<Assets<TextureAtlasLayout>>,
+    cameras: Query<(
+        &Camera,
+        Option<&Projection>,
+        Option<&OrthographicProjection>,
+    )>,
+    mut sprites: Query<(&mut Sprite3d, &Sprite3dPixelPerfect)>,
+) {
+    for (mut sprite, pixel_perfect) in &mut sprites {
+        if sprite.base_mesh.is_some() {
+            continue;
+        }
+
+        // The world size of a screen pixel
+
+        let Ok((camera, projection, orthographic_projection)) = cameras.get(pixel_perfect.camera)
+        else {
+            continue;
+        };
+
+        let orthographic_projection = match projection {
+            Some(Projection::Orthographic(projection)) => projection,
+            Some(Projection::Perspective(_)) => continue,
+            None => match orthographic_projection {
+                Some(projection) => projection,
+                None => continue,
+            },
+        };
+
+        let Some(viewport_size) = camera.physical_viewport_size() else {
+            continue;
+        };
+
+        if viewport_size.y == 0 {
+            continue;
+        }
+
+        let pixel_size = orthographic_projection.area.height() / viewport_size.y as f32;
+
+        // The size of the current frame in texels
+
+        let Some(image) = images.get(&sprite.image) else {
+            continue;
+        };
+
+        let texel_size = match &sprite.texture_atlas {
+            Some(atlas) => {
+                let Some(atlas_layout) = atlas_layouts.get(&atlas.layout) else {
+                    continue;
+                };
+
+                let Some(atlas_rect) = atlas_layout.textures.get(atlas.index) else {
+                    continue;
+                };
+
+                // The layout may be scaled relative to the image
+
+                atlas_rect.size().as_vec2() * image.size_f32() / atlas_layout.size.as_vec2()
+            }
+            None => image.size_f32(),
+        };
+
+        let size = texel_size * pixel_perfect.pixels_per_texel as f32 * pixel_size;
+
+        // Prevent needless "Changed" events that would rebuild the mesh
+
+        if sprite.custom_size != Some(size) {
+            sprite.custom_size = Some(size);
+        }
     }
 }
 
@@ -198,7 +431,7 @@ pub fn sync_when_sprites_change(
             &Mesh3d,
             &MeshMaterial3d<StandardMaterial>,
         ),
-        Changed<Sprite3d>,
+        (Changed<Sprite3d>, Without<Sprite3dPlaceholderShown>),
     >,
 ) {
     for (entity, sprite, mesh, material) in &sprites {
@@ -217,7 +450,10 @@ pub fn sync_when_sprites_change(
         );
         // Update the material if it changed
 
-        let new_material_handle = get_or_create_material(sprite, &mut materials, &mut cache);
+        let Some(new_material_handle) = get_or_create_material(sprite, &mut materials, &mut cache)
+        else {
+            continue;
+        };
 
         if material.0 != new_material_handle {
             commands
@@ -238,7 +474,10 @@ pub fn sync_when_atlases_change(
     atlas_layouts: Res<Assets<TextureAtlasLayout>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut cache: ResMut<Cache>,
-    sprites: Query<(Entity, &Sprite3d, &Mesh3d), Changed<Sprite3d>>,
+    sprites: Query<
+        (Entity, &Sprite3d, &Mesh3d),
+        (Changed<Sprite3d>, Without<Sprite3dPlaceholderShown>),
+    >,
 ) {
     for (entity, sprite, mesh) in &sprites {
         try_get_or_create_mesh(sprite, &images, &atlas_layouts, &mut meshes, &mut cache).inspect(
@@ -254,40 +493,353 @@ pub fn sync_when_atlases_change(
     }
 }
 
+/// Renders the 3D sprites that share the same material with a single merged mesh.
+///
+/// The merged mesh of a material is only rebuilt when sprites join or leave it (when they appear, disappear, change material or visibility).
+/// The sprites that move or play their animation are updated in place in the merged mesh.
+#[allow(clippy::too_many_arguments)]
+pub fn batch_sprites(
+    mut commands: Commands,
+    images: Res<Assets<Image>>,
+    atlas_layouts: Res<Assets<TextureAtlasLayout>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut cache: ResMut<Cache>,
+    mut batches: ResMut<Batches>,
+    sprites: Query<(&Sprite3d, &GlobalTransform, &InheritedVisibility)>,
+    changed_sprites: Query<
+        Entity,
+        (
+            With<Sprite3d>,
+            Or<(
+                Changed<Sprite3d>,
+                Changed<GlobalTransform>,
+                Changed<InheritedVisibility>,
+            )>,
+        ),
+    >,
+    mut removed_sprites: RemovedComponents<Sprite3d>,
+) {
+    let batches = &mut *batches;
+
+    // The materials whose batch must be rebuilt because sprites joined or left it
+
+    let mut dirty_materials = HashSet::new();
+
+    for entity in removed_sprites.read() {
+        batches.pending_sprites.remove(&entity);
+
+        if let Some(material_id) = batches.sprite_materials.remove(&entity) {
+            dirty_materials.insert(material_id);
+        }
+    }
+
+    // The sprites that stay in their batch, to update in place
+
+    let mut updated_sprites = Vec::new();
+
+    // Strong handles for the new batches, as the cache only keeps weak ones
+
+    let mut material_handles = HashMap::new();
+
+    let sprites_to_check: HashSet<Entity> = changed_sprites
+        .iter()
+        .chain(std::mem::take(&mut batches.pending_sprites))
+        .collect();
+
+    for entity in sprites_to_check {
+        let Ok((sprite, _, visibility)) = sprites.get(entity) else {
+            continue;
+        };
+
+        let previous_material_id = batches.sprite_materials.get(&entity).copied();
+
+        let material_handle = if visibility.get() {
+            let mesh_ready =
+                try_get_or_create_mesh(sprite, &images, &atlas_layouts, &mut meshes, &mut cache)
+                    .is_some();
+
+            let material_handle =
+                get_or_create_material(sprite, &mut materials, &mut cache).filter(|_| mesh_ready);
+
+            if material_handle.is_none() {
+                batches.pending_sprites.insert(entity);
+            }
+
+            material_handle
+        } else {
+            None
+        };
+
+        let material_id = material_handle.as_ref().map(Handle::id);
+
+        if material_id.is_some() && material_id == previous_material_id {
+            updated_sprites.push(entity);
+            continue;
+        }
+
+        if let Some(previous_material_id) = previous_material_id {
+            batches.sprite_materials.remove(&entity);
+            dirty_materials.insert(previous_material_id);
+        }
+
+        if let Some(material_handle) = material_handle {
+            let material_id = material_handle.id();
+
+            batches.sprite_materials.insert(entity, material_id);
+            dirty_materials.insert(material_id);
+
+            material_handles.entry(material_id).or_insert_with(|| {
+                materials
+                    .get_strong_handle(material_id)
+                    .unwrap_or(material_handle)
+            });
+        }
+    }
+
+    let mut batch_part = |entity: Entity, meshes: &mut Assets<Mesh>| {
+        let (sprite, transform, _) = sprites.get(entity).ok()?;
+
+        let mesh_handle =
+            try_get_or_create_mesh(sprite, &images, &atlas_layouts, meshes, &mut cache)?;
+
+        create_batch_part(meshes.get(&mesh_handle)?, transform)
+    };
+
+    // Update the sprites in place in the batches that are not rebuilt anyway
+
+    for entity in updated_sprites {
+        let material_id = batches.sprite_materials[&entity];
+
+        if dirty_materials.contains(&material_id) {
+            continue;
+        }
+
+        let Some(batch) = batches.batches.get(&material_id) else {
+            continue;
+        };
+
+        let updated = batch_part(entity, &mut meshes).is_some_and(|part| {
+            let vertices = batch.vertices.get(&entity).cloned().unwrap_or_default();
+
+            meshes
+                .get_mut(&batch.mesh)
+                .is_some_and(|mesh| write_batch_part(mesh, vertices, &part))
+        });
+
+        // The sprite does not fit in its previous vertices anymore
+
+        if !updated {
+            dirty_materials.insert(material_id);
+        }
+    }
+
+    if dirty_materials.is_empty() {
+        return;
+    }
+
+    // Rebuild the batches that sprites joined or left
+
+    let mut batch_sprites: HashMap<AssetId<StandardMaterial>, Vec<Entity>> = HashMap::new();
+
+    for (entity, material_id) in &batches.sprite_materials {
+        if dirty_materials.contains(material_id) {
+            batch_sprites.entry(*material_id).or_default().push(*entity);
+        }
+    }
+
+    for material_id in dirty_materials {
+        let parts: Vec<_> = batch_sprites
+            .remove(&material_id)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|entity| batch_part(entity, &mut meshes).map(|part| (entity, part)))
+            .collect();
+
+        // Remove the batches of the materials that are not used anymore
+
+        if parts.is_empty() {
+            if let Some(batch) = batches.batches.remove(&material_id) {
+                commands.entity(batch.entity).despawn();
+            }
+
+            continue;
+        }
+
+        let (merged_mesh, vertices) = merge_batch_parts(parts);
+
+        match batches.batches.get_mut(&material_id) {
+            Some(batch) => {
+                meshes.insert(&batch.mesh, merged_mesh);
+                batch.vertices = vertices;
+            }
+            None => {
+                let Some(material_handle) = material_handles.remove(&material_id) else {
+                    continue;
+                };
+
+                let mesh = meshes.add(merged_mesh);
+
+                let entity = commands
+                    .spawn((
+                        Sprite3dBatch,
+                        Mesh3d(mesh.clone()),
+                        MeshMaterial3d(material_handle),
+                        Transform::default(),
+                        Visibility::default(),
+                        // The bounds of the batch change with its sprites
+                        NoFrustumCulling,
+                    ))
+                    .id();
+
+                batches.batches.insert(
+                    material_id,
+                    Batch {
+                        entity,
+                        mesh,
+                        vertices,
+                    },
+                );
+            }
+        }
+    }
+}
+
+// Transforms the vertices of the mesh of a sprite in world space
+fn create_batch_part(mesh: &Mesh, transform: &GlobalTransform) -> Option<BatchPart> {
+    let (
+        Some(VertexAttributeValues::Float32x3(positions)),
+        Some(VertexAttributeValues::Float32x2(uvs)),
+    ) = (
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION),
+        mesh.attribute(Mesh::ATTRIBUTE_UV_0),
+    )
+    else {
+        return None;
+    };
+
+    let affine = transform.affine();
+    let normal_matrix = affine.matrix3.inverse().transpose();
+
+    let normals = match mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+        Some(VertexAttributeValues::Float32x3(normals)) => normals
+            .iter()
+            .map(|normal| {
+                Vec3::from(normal_matrix * Vec3A::from(*normal))
+                    .normalize_or_zero()
+                    .to_array()
+            })
+            .collect(),
+        _ => {
+            // Use the normal of a flat sprite for meshes without normals
+            let normal = Vec3::from(normal_matrix * Vec3A::Z).normalize_or_zero();
+
+            vec![normal.to_array(); positions.len()]
+        }
+    };
+
+    Some(BatchPart {
+        positions: positions
+            .iter()
+            .map(|position| affine.transform_point3(Vec3::from(*position)).to_array())
+            .collect(),
+        normals,
+        uvs: uvs.clone(),
+        indices: match mesh.indices() {
+            Some(indices) => indices.iter().map(|index| index as u32).collect(),
+            None => (0..positions.len() as u32).collect(),
+        },
+    })
+}
+
+// Merges the parts of several sprites into a single mesh, returning the vertices of each sprite
+fn merge_batch_parts(parts: Vec<(Entity, BatchPart)>) -> (Mesh, HashMap<Entity, Range<usize>>) {
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut normals: Vec<[f32; 3]> = Vec::new();
+    let mut uvs: Vec<[f32; 2]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+
+    let mut vertices = HashMap::with_capacity(parts.len());
+
+    for (entity, part) in parts {
+        let first_vertex = positions.len();
+
+        indices.extend(part.indices.iter().map(|index| first_vertex as u32 + index));
+
+        positions.extend(part.positions);
+        normals.extend(part.normals);
+        uvs.extend(part.uvs);
+
+        vertices.insert(entity, first_vertex..positions.len());
+    }
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    );
+
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_indices(Indices::U32(indices));
+
+    (mesh, vertices)
+}
+
+// Overwrites the vertices of a sprite in a merged mesh.
+//
+// Returns false if the sprite now has a different number of vertices.
+fn write_batch_part(mesh: &mut Mesh, vertices: Range<usize>, part: &BatchPart) -> bool {
+    if vertices.len() != part.positions.len() {
+        return false;
+    }
+
+    if let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+    {
+        positions[vertices.clone()].copy_from_slice(&part.positions);
+    }
+
+    if let Some(VertexAttributeValues::Float32x3(normals)) =
+        mesh.attribute_mut(Mesh::ATTRIBUTE_NORMAL)
+    {
+        normals[vertices.clone()].copy_from_slice(&part.normals);
+    }
+
+    if let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute_mut(Mesh::ATTRIBUTE_UV_0) {
+        uvs[vertices].copy_from_slice(&part.uvs);
+    }
+
+    true
+}
+
 // Retrieves a material from the cache or create a new one
 fn get_or_create_material(
     sprite: &Sprite3d,
     materials: &mut ResMut<Assets<StandardMaterial>>,
     cache: &mut Cache,
-) -> Handle<StandardMaterial> {
-    let material_id = MaterialId::new(sprite, &sprite.image);
+) -> Option<Handle<StandardMaterial>> {
+    let texture = cache.texture(sprite)?;
 
-    cache
+    let material_id = MaterialId::new(sprite, &texture);
+
+    let material_handle = cache
         .materials
         .get(&material_id)
         .cloned()
         .unwrap_or_else(|| {
-            let material_handle: Handle<StandardMaterial> = materials.add(StandardMaterial {
-                base_color_texture: Some(sprite.image.clone()),
-                base_color: sprite.color,
-                cull_mode: Some(Face::Back),
-                unlit: sprite.unlit,
-                alpha_mode: sprite.alpha_mode,
-                emissive: sprite.emissive,
-                // TODO
-                // these are sensible values for 3d rendering,
-                // but could be extended to public API
-                perceptual_roughness: 0.5,
-                reflectance: 0.15,
-                ..default()
-            });
+            let material = create_material(sprite, sprite.color, Some(texture), &**materials);
+
+            let material_handle: Handle<StandardMaterial> = materials.add(material);
 
             cache
                 .materials
                 .insert(material_id, material_handle.clone_weak());
 
             material_handle
-        })
+        });
+
+    Some(material_handle)
 }
 
 // Retrieves a mesh from the cache or create a new one
@@ -300,137 +852,316 @@ fn try_get_or_create_mesh(
 ) -> Option<Handle<Mesh>> {
     // We have to wait for the image to be loaded to access its dimensions
 
-    images.get(&sprite.image).map(|sprite_image| {
-        sprite.texture_atlas.as_ref().map(|sprite_atlas| {
-            let atlas_layout = atlas_layouts
-                .get(&sprite_atlas.layout)
-                .expect("cannot get 3D sprite's atlas layout");
+    let sprite_image = images.get(&sprite.image)?;
 
-            let atlas_rect = atlas_layout
-                .textures
-                .get(sprite_atlas.index)
-                .expect("cannot get 3D sprite's atlas rect");
+    let sprite_atlas = sprite.texture_atlas.as_ref()?;
 
-            let mesh_id = MeshId::new(sprite, sprite_image, atlas_rect);
+    // The layout may not be available yet, for instance if it is loaded asynchronously:
+    // the mesh is then created in a later update
 
-            cache.meshes.get(&mesh_id).cloned().unwrap_or_else(|| {
-                let mut mesh = Mesh::new(
-                    PrimitiveTopology::TriangleList, // Needed to support raycasting
-                    RenderAssetUsages::default(),
-                );
+    let Some(atlas_layout) = atlas_layouts.get(&sprite_atlas.layout) else {
+        if cache
+            .reported_atlases
+            .insert((sprite_atlas.layout.id(), None))
+        {
+            diagnostics::warning(format_args!(
+                "the atlas layout of a 3D sprite is not available, the sprite is not displayed until it is"
+            ));
+        }
 
-                // Vertices
+        return None;
+    };
 
-                let size = match sprite.custom_size {
-                    Some(size) => size,
-                    None => sprite_image.size_f32(),
-                };
+    let Some(atlas_rect) = atlas_layout.textures.get(sprite_atlas.index) else {
+        if cache
+            .reported_atlases
+            .insert((sprite_atlas.layout.id(), Some(sprite_atlas.index)))
+        {
+            diagnostics::warning(format_args!(
+                "atlas index {} exceeds the atlas layout of a 3D sprite ({} textures), the sprite is not updated",
+                sprite_atlas.index,
+                atlas_layout.textures.len()
+            ));
+        }
 
-                let half = size / 2.0;
-
-                let offset = sprite.anchor.as_vec() * size;
-
-                mesh.insert_attribute(
-                    Mesh::ATTRIBUTE_POSITION,
-                    vec![
-                        // Triangle 1
-                        [
-                            // bottom left
-                            -half.x - offset.x,
-                            -half.y - offset.y,
-                            0.0,
-                        ],
-                        [
-                            // bottom right
-                            half.x - offset.x,
-                            -half.y - offset.y,
-                            0.0,
-                        ],
-                        [
-                            // top left
-                            -half.x - offset.x,
-                            half.y - offset.y,
-                            0.0,
-                        ],
-                        // Triangle 2
-                        [
-                            // bottom right
-                            half.x - offset.x,
-                            -half.y - offset.y,
-                            0.0,
-                        ],
-                        [
-                            // top right
-                            half.x - offset.x,
-                            half.y - offset.y,
-                            0.0,
-                        ],
-                        [
-                            // top left
-                            -half.x - offset.x,
-                            half.y - offset.y,
-                            0.0,
-                        ],
-                    ],
-                );
+        return None;
+    };
 
-                mesh.insert_attribute(
-                    Mesh::ATTRIBUTE_NORMAL,
-                    vec![
-                        [0.0, 0.0, 1.0],
-                        [0.0, 0.0, 1.0],
-                        [0.0, 0.0, 1.0],
-                        [0.0, 0.0, 1.0],
-                        [0.0, 0.0, 1.0],
-                        [0.0, 0.0, 1.0],
-                    ],
-                );
+    let mesh_id = MeshId::new(sprite, sprite_image, atlas_rect);
 
-                // Texture coordinates
-
-                let atlas_size = atlas_layout.size.as_vec2();
-
-                let mut uvs = vec![
-                    // Triangle 1
-                    (UVec2::new(atlas_rect.min.x, atlas_rect.max.y).as_vec2() / atlas_size)
-                        .to_array(),
-                    (UVec2::new(atlas_rect.max.x, atlas_rect.max.y).as_vec2() / atlas_size)
-                        .to_array(),
-                    (UVec2::new(atlas_rect.min.x, atlas_rect.min.y).as_vec2() / atlas_size)
-                        .to_array(),
-                    // Triangle 2
-                    (UVec2::new(atlas_rect.max.x, atlas_rect.max.y).as_vec2() / atlas_size)
-                        .to_array(),
-                    (UVec2::new(atlas_rect.max.x, atlas_rect.min.y).as_vec2() / atlas_size)
-                        .to_array(),
-                    (UVec2::new(atlas_rect.min.x, atlas_rect.min.y).as_vec2() / atlas_size)
-                        .to_array(),
-                ];
-
-                if sprite.flip_x {
-                    uvs.swap(0, 1);
-                    uvs.swap(5, 4);
-                    uvs[2] = uvs[5];
-                    uvs[3] = uvs[1];
-                }
+    if let Some(mesh_handle) = cache.meshes.get(&mesh_id) {
+        return Some(mesh_handle.clone());
+    }
 
-                if sprite.flip_y {
-                    uvs.swap(0, 2);
-                    uvs.swap(3, 4);
-                    uvs[1] = uvs[3];
-                    uvs[5] = uvs[2];
-                }
+    let mesh = match &sprite.base_mesh {
+        // We also have to wait for the base mesh to be loaded
+        Some(base_mesh) => create_mesh_from_base(
+            meshes.get(base_mesh)?,
+            sprite,
+            sprite_image.size_f32(),
+            atlas_layout,
+            atlas_rect,
+        ),
+        None => {
+            let size = sprite
+                .custom_size
+                .unwrap_or_else(|| sprite_image.size_f32());
 
-                mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+            create_quad_mesh(
+                sprite,
+                size,
+                sprite_image.size_f32(),
+                atlas_layout,
+                atlas_rect,
+            )
+        }
+    };
 
-                let mesh_handle = meshes.add(mesh);
+    let mesh_handle = meshes.add(mesh);
 
-                cache.meshes.insert(mesh_id, mesh_handle.clone());
+    cache.meshes.insert(mesh_id, mesh_handle.clone());
 
-                mesh_handle
-            })
-        })
-    })?
+    Some(mesh_handle)
+}
+
+// Creates the mesh and material of the placeholder of a sprite, if it has one
+fn create_placeholder(
+    sprite: &Sprite3d,
+    atlas_layouts: &Res<Assets<TextureAtlasLayout>>,
+    materials: &Assets<StandardMaterial>,
+) -> Option<(Mesh, StandardMaterial)> {
+    let placeholder = sprite.placeholder.as_ref()?;
+
+    let sprite_atlas = sprite.texture_atlas.as_ref()?;
+
+    let atlas_layout = atlas_layouts.get(&sprite_atlas.layout)?;
+
+    let atlas_rect = atlas_layout.textures.get(sprite_atlas.index)?;
+
+    // The image is not available yet so we assume that it has the size of the atlas
+
+    let size = sprite
+        .custom_size
+        .unwrap_or_else(|| atlas_layout.size.as_vec2());
+
+    let mesh = create_quad_mesh(
+        sprite,
+        size,
+        atlas_layout.size.as_vec2(),
+        atlas_layout,
+        atlas_rect,
+    );
+
+    let (base_color, base_color_texture) = match placeholder {
+        Sprite3dPlaceholder::Color(color) => (*color, None),
+        Sprite3dPlaceholder::Image(image) => (sprite.color, Some(image.clone())),
+    };
+
+    let material = create_material(sprite, base_color, base_color_texture, materials);
+
+    Some((mesh, material))
+}
+
+// Creates the material of a sprite from its template, if it has one
+fn create_material(
+    sprite: &Sprite3d,
+    base_color: Color,
+    base_color_texture: Option<Handle<Image>>,
+    materials: &Assets<StandardMaterial>,
+) -> StandardMaterial {
+    let template = sprite
+        .material_template
+        .as_ref()
+        .and_then(|template| materials.get(template))
+        .cloned()
+        .unwrap_or_else(|| StandardMaterial {
+            cull_mode: Some(Face::Back),
+            ..default()
+        });
+
+    StandardMaterial {
+        base_color_texture,
+        base_color,
+        unlit: sprite.unlit,
+        alpha_mode: sprite.alpha_mode,
+        emissive: sprite.emissive,
+        perceptual_roughness: sprite.perceptual_roughness,
+        reflectance: sprite.reflectance,
+        metallic: sprite.metallic,
+        depth_bias: sprite.depth_bias,
+        ..template
+    }
+}
+
+// Creates a flat quad displaying a frame of an atlas
+fn create_quad_mesh(
+    sprite: &Sprite3d,
+    size: Vec2,
+    image_size: Vec2,
+    atlas_layout: &TextureAtlasLayout,
+    atlas_rect: &URect,
+) -> Mesh {
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList, // Needed to support raycasting
+        RenderAssetUsages::default(),
+    );
+
+    // Vertices
+
+    let half = size / 2.0;
+
+    let offset = sprite.anchor.as_vec() * size;
+
+    mesh.insert_attribute(
+        Mesh::ATTRIBUTE_POSITION,
+        vec![
+            // Triangle 1
+            [
+                // bottom left
+                -half.x - offset.x,
+                -half.y - offset.y,
+                0.0,
+            ],
+            [
+                // bottom right
+                half.x - offset.x,
+                -half.y - offset.y,
+                0.0,
+            ],
+            [
+                // top left
+                -half.x - offset.x,
+                half.y - offset.y,
+                0.0,
+            ],
+            // Triangle 2
+            [
+                // bottom right
+                half.x - offset.x,
+                -half.y - offset.y,
+                0.0,
+            ],
+            [
+                // top right
+                half.x - offset.x,
+                half.y - offset.y,
+                0.0,
+            ],
+            [
+                // top left
+                -half.x - offset.x,
+                half.y - offset.y,
+                0.0,
+            ],
+        ],
+    );
+
+    mesh.insert_attribute(
+        Mesh::ATTRIBUTE_NORMAL,
+        vec![
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0],
+        ],
+    );
+
+    // Texture coordinates
+
+    let uv_rect = frame_uv_rect(sprite, image_size, atlas_layout, atlas_rect);
+
+    let mut uvs = vec![
+        // Triangle 1
+        [uv_rect.min.x, uv_rect.max.y],
+        [uv_rect.max.x, uv_rect.max.y],
+        [uv_rect.min.x, uv_rect.min.y],
+        // Triangle 2
+        [uv_rect.max.x, uv_rect.max.y],
+        [uv_rect.max.x, uv_rect.min.y],
+        [uv_rect.min.x, uv_rect.min.y],
+    ];
+
+    if sprite.flip_x {
+        uvs.swap(0, 1);
+        uvs.swap(5, 4);
+        uvs[2] = uvs[5];
+        uvs[3] = uvs[1];
+    }
+
+    if sprite.flip_y {
+        uvs.swap(0, 2);
+        uvs.swap(3, 4);
+        uvs[1] = uvs[3];
+        uvs[5] = uvs[2];
+    }
+
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+
+    mesh
+}
+
+// Creates a copy of a user-provided mesh with its UVs remapped to a frame of an atlas
+fn create_mesh_from_base(
+    base_mesh: &Mesh,
+    sprite: &Sprite3d,
+    image_size: Vec2,
+    atlas_layout: &TextureAtlasLayout,
+    atlas_rect: &URect,
+) -> Mesh {
+    let mut mesh = base_mesh.clone();
+
+    let uv_rect = frame_uv_rect(sprite, image_size, atlas_layout, atlas_rect);
+
+    if let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute_mut(Mesh::ATTRIBUTE_UV_0) {
+        for uv in uvs.iter_mut() {
+            let mut base_uv = Vec2::from(*uv);
+
+            if sprite.flip_x {
+                base_uv.x = 1.0 - base_uv.x;
+            }
+
+            if sprite.flip_y {
+                base_uv.y = 1.0 - base_uv.y;
+            }
+
+            *uv = (uv_rect.min + base_uv * uv_rect.size()).to_array();
+        }
+    } else {
+        diagnostics::warning(format_args!(
+            "the base mesh of a 3D sprite has no UVs, it will not be animated"
+        ));
+    }
+
+    mesh
+}
+
+// Returns the UV rectangle of a frame of an atlas, moved inward by the UV inset of the sprite
+fn frame_uv_rect(
+    sprite: &Sprite3d,
+    image_size: Vec2,
+    atlas_layout: &TextureAtlasLayout,
+    atlas_rect: &URect,
+) -> Rect {
+    let atlas_size = atlas_layout.size.as_vec2();
+
+    let rect = atlas_rect.as_rect();
+
+    let inset = match sprite.uv_inset {
+        Sprite3dUvInset::None => Vec2::ZERO,
+        // The layout may be scaled relative to the image
+        Sprite3dUvInset::HalfTexel => 0.5 * atlas_size / image_size.max(Vec2::ONE),
+        Sprite3dUvInset::Pixels(pixels) => Vec2::splat(pixels.max(0.0)),
+    };
+
+    // The inset cannot exceed half of the frame or the UVs would cross
+    let inset = inset.min(rect.half_size());
+
+    Rect::from_corners(
+        (rect.min + inset) / atlas_size,
+        (rect.max - inset) / atlas_size,
+    )
 }
 
 pub(crate) fn remove_dropped_standard_materials(
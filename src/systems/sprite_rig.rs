@@ -0,0 +1,100 @@
+use bevy::{
+    ecs::{
+        entity::Entity,
+        query::{Added, Changed},
+        system::{Commands, Query, Res, ResMut},
+    },
+    hierarchy::{BuildChildren, ChildBuild},
+    sprite::Sprite,
+    transform::components::Transform,
+};
+
+use crate::{
+    animator::Animator,
+    components::{
+        sprite_rig::{SpriteRig, SLOT_DEPTH},
+        spritesheet_animation::SpritesheetAnimation,
+    },
+    library::AnimationLibrary,
+};
+
+/// Spawns the slots of the rigs that were just added.
+pub fn build_sprite_rigs(
+    mut commands: Commands,
+    mut rigs: Query<(Entity, &mut SpriteRig), Added<SpriteRig>>,
+) {
+    for (entity, mut rig) in &mut rigs {
+        let slots = std::mem::take(&mut rig.slots);
+
+        commands.entity(entity).with_children(|parent| {
+            for (index, slot) in slots.into_iter().enumerate() {
+                let slot_entity = parent
+                    .spawn((
+                        slot.sprite,
+                        SpritesheetAnimation::from_id(slot.animation_id),
+                        Transform::from_xyz(0.0, 0.0, index as f32 * SLOT_DEPTH),
+                    ))
+                    .id();
+
+                rig.slot_entities.push((slot.name, slot_entity));
+            }
+        });
+    }
+}
+
+/// Applies the swaps of the rigs, keeping the swapped slots in sync with the other slots.
+pub fn swap_sprite_rig_slots(
+    library: Res<AnimationLibrary>,
+    mut animator: ResMut<Animator>,
+    mut rigs: Query<&mut SpriteRig, Changed<SpriteRig>>,
+    mut slots: Query<(&mut SpritesheetAnimation, &mut Sprite)>,
+) {
+    for mut rig in &mut rigs {
+        if rig.pending_swaps.is_empty() {
+            continue;
+        }
+
+        let swaps = std::mem::take(&mut rig.pending_swaps);
+
+        // The slots that are not swapped lead the playback of the rig
+
+        let playback = rig
+            .slots()
+            .filter(|(name, _)| !rig.is_overlay(name))
+            .filter(|(name, _)| !swaps.iter().any(|swap| swap.slot == *name))
+            .find_map(|(_, entity)| animator.playback(entity));
+
+        for swap in swaps {
+            let Some(slot_entity) = rig.slot(&swap.slot) else {
+                continue;
+            };
+
+            let Ok((mut animation, mut sprite)) = slots.get_mut(slot_entity) else {
+                continue;
+            };
+
+            if let Some(image) = swap.image {
+                sprite.image = image;
+            }
+
+            // Overlays play independently
+
+            if rig.is_overlay(&swap.slot) {
+                animation.switch(swap.animation_id);
+                continue;
+            }
+
+            if animation.animation_id == swap.animation_id {
+                continue;
+            }
+
+            animation.switch(swap.animation_id);
+
+            if let Some((progress, accumulated_time)) = playback {
+                if animation.set_progress(progress, &library).is_ok() {
+                    animator.synchronize_start(slot_entity, accumulated_time);
+                }
+            }
+        }
+    }
+}
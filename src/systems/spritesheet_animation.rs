@@ -1,23 +1,88 @@
+use std::collections::HashSet;
+
 use bevy::{
     ecs::{
+        change_detection::DetectChangesMut,
+        entity::Entity,
         event::EventWriter,
-        system::{Query, Res, ResMut},
+        query::{With, Without},
+        system::{Commands, Query, Res, ResMut},
     },
+    render::camera::Camera,
     time::Time,
+    transform::components::GlobalTransform,
 };
 
 use crate::{
-    animator::{Animator, SpritesheetAnimationQuery},
-    events::AnimationEvent,
+    animator::{Animator, ClipTargetQuery, FrameSkipping, SpritesheetAnimationQuery},
+    components::{
+        frame_just_changed::FrameJustChanged, marker_listener::MarkerListener,
+        spritesheet_animation::SpritesheetAnimation,
+    },
+    events::{AnimationEvent, AnimationEventSettings},
+    hooks::FrameHooks,
     library::AnimationLibrary,
 };
 
 pub fn play_animations(
     time: Res<Time>,
-    library: Res<AnimationLibrary>,
+    mut library: ResMut<AnimationLibrary>,
+    event_settings: Res<AnimationEventSettings>,
+    frame_skipping: Res<FrameSkipping>,
     mut animator: ResMut<Animator>,
     mut event_writer: EventWriter<AnimationEvent>,
     mut query: Query<SpritesheetAnimationQuery>,
+    mut target_query: Query<ClipTargetQuery, Without<SpritesheetAnimation>>,
+    frame_hooks: Res<FrameHooks>,
+    changed_frames_query: Query<Entity, With<FrameJustChanged>>,
+    listener_query: Query<&GlobalTransform, With<MarkerListener>>,
+    camera_query: Query<&GlobalTransform, With<Camera>>,
+    mut commands: Commands,
 ) {
-    animator.update(&time, &library, &mut event_writer, &mut query);
+    animator.set_collect_frame_transitions(!frame_hooks.is_empty());
+
+    if event_settings.marker_max_distance.is_some() {
+        animator.set_marker_listeners(
+            listener_query
+                .iter()
+                .map(|transform| transform.translation()),
+        );
+    }
+
+    if animator.culling().max_distance.is_some() {
+        animator.set_cameras(camera_query.iter().map(|transform| transform.translation()));
+    }
+
+    animator.update(
+        &time,
+        // The library only updates its internal caches, which should not be reported as a change
+        library.bypass_change_detection(),
+        &event_settings,
+        &frame_skipping,
+        &mut event_writer,
+        &mut query,
+        &mut target_query,
+    );
+
+    // Call the frame hooks
+
+    for frame_context in animator.take_frame_transitions() {
+        frame_hooks.call(&mut commands, &frame_context);
+    }
+
+    // Move the frame change markers to the entities that changed frame during this update
+
+    let frame_changes: HashSet<Entity> = animator.take_frame_changes().into_iter().collect();
+
+    for entity in &changed_frames_query {
+        if !frame_changes.contains(&entity) {
+            commands.entity(entity).remove::<FrameJustChanged>();
+        }
+    }
+
+    for entity in frame_changes {
+        if !changed_frames_query.contains(entity) {
+            commands.entity(entity).try_insert(FrameJustChanged);
+        }
+    }
 }
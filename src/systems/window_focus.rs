@@ -0,0 +1,18 @@
+use bevy::{
+    ecs::{event::EventReader, system::ResMut},
+    window::WindowFocused,
+};
+
+use crate::animator::Animator;
+
+/// Tracks the focus of the window for the [FocusLossBehavior](crate::prelude::FocusLossBehavior) of the plugin.
+pub fn track_window_focus(
+    mut animator: ResMut<Animator>,
+    mut focus_events: EventReader<WindowFocused>,
+) {
+    // Only the latest focus change matters, for instance when the focus moves from a window to another
+
+    if let Some(event) = focus_events.read().last() {
+        animator.set_window_focused(event.focused);
+    }
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "animation_states")]
+
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+enum Weather {
+    #[default]
+    Sunny,
+    Stormy,
+    Foggy,
+}
+
+#[test]
+fn animation_by_state() {
+    let mut ctx = Context::new();
+
+    let calm_clip_id = ctx.library().register_clip(Clip::from_frames([0, 1]));
+    let calm_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(calm_clip_id));
+
+    let windy_clip_id = ctx.library().register_clip(Clip::from_frames([5, 6]));
+    let windy_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(windy_clip_id));
+
+    ctx.app
+        .init_state::<Weather>()
+        .register_animation_states::<Weather>();
+
+    ctx.app.world_mut().entity_mut(ctx.sprite_entity).insert(
+        AnimationByState::default()
+            .with_animation(Weather::Sunny, calm_id)
+            .with_animation(Weather::Stormy, windy_id),
+    );
+
+    // The animation of the current state is played right away
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(calm_id)]);
+
+    // The animation follows the state
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<NextState<Weather>>()
+        .set(Weather::Stormy);
+
+    ctx.run(50);
+    ctx.check(5, [ctx.anim_start(windy_id)]);
+
+    // States without an animation keep the current one
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<NextState<Weather>>()
+        .set(Weather::Foggy);
+
+    ctx.run(50);
+
+    ctx.get_sprite(|animation| assert_eq!(animation.animation_id, windy_id));
+}
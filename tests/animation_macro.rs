@@ -0,0 +1,35 @@
+use bevy_spritesheet_animation::{animation, prelude::*};
+
+#[test]
+fn animation_macro() {
+    let mut library = AnimationLibrary::default();
+
+    let spritesheet = Spritesheet::new(4, 3);
+
+    let animation_id = animation!(library, spritesheet, { row 1, fps 10, repeat 4, ping_pong });
+
+    let animation = library.get_animation(animation_id).clone();
+
+    assert_eq!(animation.clip_ids().len(), 1);
+    assert_eq!(
+        library.get_clip(animation.clip_ids()[0]).frames(),
+        [4, 5, 6, 7]
+    );
+    assert_eq!(
+        animation.duration(),
+        &Some(AnimationDuration::PerFrame(100))
+    );
+    assert_eq!(animation.repetitions(), &Some(AnimationRepeat::Times(4)));
+    assert_eq!(animation.direction(), &Some(AnimationDirection::PingPong));
+    assert_eq!(animation.easing(), &None);
+
+    // Explicit frames without parameters
+
+    let animation_id = animation!(library, spritesheet, { frames [0, 11] });
+
+    let animation = library.get_animation(animation_id).clone();
+
+    assert_eq!(library.get_clip(animation.clip_ids()[0]).frames(), [0, 11]);
+    assert_eq!(animation.duration(), &None);
+    assert_eq!(animation.repetitions(), &None);
+}
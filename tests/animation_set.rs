@@ -0,0 +1,329 @@
+#![cfg(feature = "animation_files")]
+
+pub mod context;
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+fn animation_set(frames: Vec<usize>, duration: u32) -> AnimationSet {
+    AnimationSet {
+        clips: HashMap::from([(
+            "run".to_string(),
+            ClipDefinition {
+                frames,
+                duration: Some(AnimationDuration::PerFrame(duration)),
+                markers: vec![("footstep".to_string(), 1)],
+                ..Default::default()
+            },
+        )]),
+        animations: HashMap::from([
+            (
+                "run".to_string(),
+                AnimationDefinition {
+                    clips: vec!["run".to_string()],
+                    repetitions: Some(AnimationRepeat::Times(2)),
+                    ..Default::default()
+                },
+            ),
+            (
+                "broken".to_string(),
+                AnimationDefinition {
+                    clips: vec!["missing".to_string()],
+                    ..Default::default()
+                },
+            ),
+        ]),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn register_by_name() {
+    let mut library = AnimationLibrary::default();
+
+    animation_set(vec![0, 1, 2], 100).register(&mut library);
+
+    let clip_id = library.clip_with_name("run").unwrap();
+    let animation_id = library.animation_with_name("run").unwrap();
+    let marker_id = library.marker_with_name("footstep").unwrap();
+
+    let clip = library.get_clip(clip_id);
+
+    assert_eq!(clip.frames(), [0, 1, 2]);
+    assert_eq!(*clip.duration(), Some(AnimationDuration::PerFrame(100)));
+    assert_eq!(clip.markers().get(&1), Some(&vec![marker_id]));
+
+    let animation = library.get_animation(animation_id);
+
+    assert_eq!(animation.clip_ids(), [clip_id]);
+    assert_eq!(*animation.repetitions(), Some(AnimationRepeat::Times(2)));
+
+    // Animations with unknown clips are skipped
+
+    assert_eq!(library.animation_with_name("broken"), None);
+}
+
+#[test]
+fn reload_in_place() {
+    let mut library = AnimationLibrary::default();
+
+    animation_set(vec![0, 1, 2], 100).register(&mut library);
+
+    let clip_id = library.clip_with_name("run").unwrap();
+    let animation_id = library.animation_with_name("run").unwrap();
+    let marker_id = library.marker_with_name("footstep").unwrap();
+
+    // Registering a modified set updates the existing clips and animations
+
+    animation_set(vec![4, 5], 50).register(&mut library);
+
+    assert_eq!(library.clip_with_name("run"), Some(clip_id));
+    assert_eq!(library.animation_with_name("run"), Some(animation_id));
+    assert_eq!(library.marker_with_name("footstep"), Some(marker_id));
+
+    let clip = library.get_clip(clip_id);
+
+    assert_eq!(clip.frames(), [4, 5]);
+    assert_eq!(*clip.duration(), Some(AnimationDuration::PerFrame(50)));
+}
+
+fn invalid(source: &str) -> (AnimationSetError, (usize, usize)) {
+    match AnimationSet::from_ron(source) {
+        Err(AnimationSetLoaderError::Invalid { error, position }) => {
+            (error, (position.line, position.col))
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[test]
+fn validation() {
+    // Valid
+
+    let set = AnimationSet::from_ron(
+        r#"(
+    grid: Some((4, 2)),
+    clips: {
+        "run": (frames: [0, 1, 7], markers: [("footstep", 0), ("footstep", 2)]),
+    },
+    animations: {
+        "run": (clips: ["run"]),
+    },
+)"#,
+    )
+    .unwrap();
+
+    assert_eq!(set.grid, Some((4, 2)));
+
+    // Unknown fields
+
+    assert!(matches!(
+        AnimationSet::from_ron(r#"(clips: { "run": (frames: [0], durtion: None) })"#),
+        Err(AnimationSetLoaderError::Ron(_))
+    ));
+
+    // Frames out of the grid
+
+    let (error, position) = invalid(
+        r#"(
+    grid: Some((4, 2)),
+    clips: {
+        "idle": (frames: [0]),
+        "run": (frames: [0, 8]),
+    },
+)"#,
+    );
+
+    assert_eq!(
+        error,
+        AnimationSetError::FrameOutOfBounds {
+            clip: "run".to_string(),
+            frame: 8,
+            frame_count: 8
+        }
+    );
+    assert_eq!(position, (5, 9));
+
+    // Markers after the last frame
+
+    let (error, position) = invalid(
+        r#"(
+    clips: {
+        "run": (
+            frames: [0, 1],
+            markers: [("footstep", 0), ("footstep", 2)],
+        ),
+    },
+)"#,
+    );
+
+    assert_eq!(
+        error,
+        AnimationSetError::MarkerOutOfBounds {
+            clip: "run".to_string(),
+            marker: "footstep".to_string(),
+            frame_index: 2
+        }
+    );
+    assert_eq!(position, (5, 41));
+
+    // Duplicate markers
+
+    let (error, position) = invalid(
+        r#"(
+    clips: {
+        "run": (
+            frames: [0, 1],
+            markers: [("footstep", 1), ("land", 1), ("footstep", 1)],
+        ),
+    },
+)"#,
+    );
+
+    assert_eq!(
+        error,
+        AnimationSetError::DuplicateMarker {
+            clip: "run".to_string(),
+            marker: "footstep".to_string(),
+            frame_index: 1
+        }
+    );
+    assert_eq!(position, (5, 54));
+
+    // Unknown clips
+
+    let (error, position) = invalid(
+        r#"(
+    animations: {
+        "run": (clips: ["walk"]),
+    },
+    clips: {
+        "run": (frames: [0]),
+    },
+)"#,
+    );
+
+    assert_eq!(
+        error,
+        AnimationSetError::UnknownClip {
+            animation: "run".to_string(),
+            clip: "walk".to_string()
+        }
+    );
+    assert_eq!(position, (3, 25));
+}
+
+#[test]
+fn loaded_sets_share_names() {
+    let mut ctx = Context::new();
+
+    let add_set = |ctx: &mut Context, set: AnimationSet| {
+        ctx.app
+            .world_mut()
+            .resource_mut::<Assets<AnimationSet>>()
+            .add(set)
+    };
+
+    let set1 = add_set(&mut ctx, animation_set(vec![0, 1, 2], 100));
+
+    ctx.run(10);
+    ctx.run(10);
+
+    let clip_id = ctx.library().clip_with_name("run").unwrap();
+    let animation_id = ctx.library().animation_with_name("run").unwrap();
+
+    // Names already in use by another set are not replaced
+
+    let mut set2 = animation_set(vec![4, 5], 50);
+
+    set2.clips.insert(
+        "walk".to_string(),
+        ClipDefinition {
+            frames: vec![6, 7],
+            ..Default::default()
+        },
+    );
+
+    set2.animations.insert(
+        "walk".to_string(),
+        AnimationDefinition {
+            clips: vec!["walk".to_string()],
+            ..Default::default()
+        },
+    );
+
+    let _set2 = add_set(&mut ctx, set2);
+
+    ctx.run(10);
+    ctx.run(10);
+
+    assert_eq!(ctx.library().clip_with_name("run"), Some(clip_id));
+    assert_eq!(ctx.library().get_clip(clip_id).frames(), [0, 1, 2]);
+    assert_eq!(ctx.library().animation_with_name("run"), Some(animation_id));
+    assert!(ctx.library().animation_with_name("walk").is_some());
+
+    // The animations removed from a set lose their names when it is reloaded
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<Assets<AnimationSet>>()
+        .get_mut(&set1)
+        .unwrap()
+        .animations
+        .remove("run");
+
+    ctx.run(10);
+    ctx.run(10);
+
+    assert_eq!(ctx.library().animation_with_name("run"), None);
+    assert_eq!(ctx.library().clip_with_name("run"), Some(clip_id));
+    assert!(ctx.library().animations().contains_key(&animation_id));
+}
+
+#[test]
+fn dropped_sets_release_names() {
+    let mut ctx = Context::new();
+
+    let set = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<AnimationSet>>()
+        .add(animation_set(vec![0, 1, 2], 100));
+
+    ctx.run(10);
+    ctx.run(10);
+
+    let clip_id = ctx.library().clip_with_name("run").unwrap();
+
+    // Dropping the set releases its names but keeps its clips and animations
+
+    drop(set);
+
+    ctx.run(10);
+    ctx.run(10);
+    ctx.run(10);
+
+    assert_eq!(ctx.library().clip_with_name("run"), None);
+    assert_eq!(ctx.library().animation_with_name("run"), None);
+    assert!(ctx.library().clips().contains_key(&clip_id));
+
+    // Reloading the set registers its names again
+
+    let _set = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<AnimationSet>>()
+        .add(animation_set(vec![3, 4], 100));
+
+    ctx.run(10);
+    ctx.run(10);
+
+    let reloaded_clip_id = ctx.library().clip_with_name("run").unwrap();
+
+    assert_ne!(reloaded_clip_id, clip_id);
+    assert_eq!(ctx.library().get_clip(reloaded_clip_id).frames(), [3, 4]);
+    assert!(ctx.library().animation_with_name("run").is_some());
+}
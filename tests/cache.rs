@@ -0,0 +1,200 @@
+pub mod context;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn evicted_caches_are_rebuilt() {
+    let mut ctx = Context::new();
+
+    // Evict all the caches that are not in use
+
+    ctx.library().set_cache_budget(Some(0));
+
+    let clip = Clip::from_frames([4, 5]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    assert_eq!(ctx.library().cache_memory_size(), 0);
+
+    // The animation still plays
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+    ctx.check(4, [ctx.anim_start(animation_id)]);
+
+    ctx.run(100);
+    ctx.check(5, []);
+
+    // The cache of an animation being played is not evicted
+
+    assert!(ctx.library().cache_memory_size() > 0);
+}
+
+#[test]
+fn least_recently_used_caches_are_evicted() {
+    let mut ctx = Context::new();
+
+    let clip_id = ctx.library().register_clip(Clip::from_frames([4, 5]));
+
+    let animation1_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    let one_cache_size = ctx.library().cache_memory_size();
+
+    let animation2_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    // Only keep one cache
+
+    ctx.library().set_cache_budget(Some(one_cache_size));
+
+    assert_eq!(ctx.library().cache_memory_size(), one_cache_size);
+
+    // Both animations can still be played
+
+    ctx.add_animation_to_sprite(animation1_id);
+
+    ctx.run(50);
+    ctx.check(4, [ctx.anim_start(animation1_id)]);
+
+    ctx.add_animation_to_sprite(animation2_id);
+
+    ctx.run(50);
+    ctx.check(4, [ctx.anim_start(animation2_id)]);
+}
+
+#[test]
+fn identical_animations_are_deduplicated() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([4, 5]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(2));
+    let animation_id = ctx.library().register_animation(animation.clone());
+
+    let memory_size = ctx.library().cache_memory_size();
+
+    // Identical animations share the same ID and cache
+
+    assert_eq!(
+        ctx.library().register_animation_deduplicated(animation),
+        animation_id
+    );
+
+    assert_eq!(ctx.library().animations().len(), 1);
+    assert_eq!(ctx.library().cache_memory_size(), memory_size);
+
+    // Different animations are registered
+
+    let other_animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(3));
+
+    assert_ne!(
+        ctx.library()
+            .register_animation_deduplicated(other_animation),
+        animation_id
+    );
+
+    assert_eq!(ctx.library().animations().len(), 2);
+}
+
+#[test]
+fn frame_events() {
+    let mut ctx = Context::new();
+
+    let marker_id = ctx.library().new_marker();
+
+    let clip = Clip::from_frames([4, 5, 6]).with_marker(marker_id, 1);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    let cache = ctx.library().animation_cache(animation_id);
+
+    let marker_hit = |clip_repetition| AnimationCacheEvent::MarkerHit {
+        marker_id,
+        clip_id,
+        clip_repetition,
+    };
+
+    let frame_events = |frame, repetition| {
+        cache
+            .frame_events(AnimationProgress { frame, repetition })
+            .map(|events| events.to_vec())
+    };
+
+    // The marker can be looked up ahead of the playback, in any repetition
+
+    assert!(frame_events(1, 0).unwrap().contains(&marker_hit(0)));
+    assert!(!frame_events(2, 0).unwrap().contains(&marker_hit(0)));
+    assert!(frame_events(1, 5).unwrap().contains(&marker_hit(0)));
+
+    // Out of the animation
+
+    assert_eq!(frame_events(3, 0), None);
+}
+
+#[test]
+fn repetition_frames() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([4, 5, 6]).with_duration(AnimationDuration::PerRepetition(300));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id).with_direction(AnimationDirection::PingPong);
+    let animation_id = ctx.library().register_animation(animation);
+
+    let cache = ctx.library().animation_cache(animation_id);
+
+    let timeline = |repetition| {
+        cache
+            .repetition_frames(repetition)
+            .iter()
+            .map(|frame| (frame.atlas_index, frame.duration.as_millis()))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(timeline(0), vec![(4, 100), (5, 100), (6, 100)]);
+    assert_eq!(timeline(1), vec![(6, 100), (5, 100), (4, 100)]);
+    assert_eq!(timeline(2), timeline(0));
+}
+
+#[test]
+fn memory_size_follows_edits() {
+    let mut ctx = Context::new();
+
+    let clip_id = ctx.library().register_clip(Clip::from_frames([4, 5]));
+
+    let animation1_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    let animation2_id = ctx.library().register_animation(
+        Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(2)),
+    );
+
+    let caches_size = |ctx: &mut Context| {
+        ctx.library().animation_cache(animation1_id).memory_size()
+            + ctx.library().animation_cache(animation2_id).memory_size()
+    };
+
+    let memory_size = ctx.library().cache_memory_size();
+
+    assert_eq!(memory_size, caches_size(&mut ctx));
+
+    // The caches are rebuilt with more frames
+
+    ctx.library().edit_clip(clip_id, |clip| {
+        *clip = Clip::from_frames([4, 5, 6, 7]);
+    });
+
+    assert!(ctx.library().cache_memory_size() > memory_size);
+    assert_eq!(ctx.library().cache_memory_size(), caches_size(&mut ctx));
+}
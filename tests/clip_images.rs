@@ -0,0 +1,106 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn clips_swap_images() {
+    let mut ctx = Context::new();
+
+    let walk_image = Handle::<Image>::weak_from_u128(0x2779_0000_0000_0000_0000_0000_0000_0001);
+    let walk_layout =
+        Handle::<TextureAtlasLayout>::weak_from_u128(0x2779_0000_0000_0000_0000_0000_0000_0002);
+    let attack_image = Handle::<Image>::weak_from_u128(0x2779_0000_0000_0000_0000_0000_0000_0003);
+    let attack_layout =
+        Handle::<TextureAtlasLayout>::weak_from_u128(0x2779_0000_0000_0000_0000_0000_0000_0004);
+
+    let walk = Clip::from_frames([0, 1])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_image(walk_image.clone(), walk_layout.clone());
+
+    let attack = Clip::from_frames([5, 6])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_image(attack_image.clone(), attack_layout.clone());
+
+    let walk_id = ctx.library().register_clip(walk);
+    let attack_id = ctx.library().register_clip(attack);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clips([walk_id, attack_id]));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    let displayed = |ctx: &Context| {
+        let sprite = ctx.app.world().get::<Sprite>(ctx.sprite_entity).unwrap();
+        let atlas = sprite.texture_atlas.as_ref().unwrap();
+
+        (sprite.image.clone(), atlas.layout.clone())
+    };
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+    assert_eq!(displayed(&ctx), (walk_image.clone(), walk_layout.clone()));
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+    assert_eq!(displayed(&ctx), (walk_image.clone(), walk_layout.clone()));
+
+    // The attack clip displays its own image
+
+    ctx.run(100); // 250
+    ctx.check(
+        5,
+        [
+            ctx.clip_rep_end(animation_id, walk_id, 0),
+            ctx.clip_end(animation_id, walk_id),
+        ],
+    );
+    assert_eq!(displayed(&ctx), (attack_image, attack_layout));
+
+    // Back to the walk clip on the next repetition
+
+    ctx.run(200); // 450
+    ctx.check(
+        0,
+        [
+            ctx.clip_rep_end(animation_id, attack_id, 0),
+            ctx.clip_end(animation_id, attack_id),
+            ctx.anim_rep_end(animation_id, 0),
+        ],
+    );
+    assert_eq!(displayed(&ctx), (walk_image, walk_layout));
+}
+
+#[test]
+fn clips_without_image_keep_current_image() {
+    let mut ctx = Context::new();
+
+    let image = ctx
+        .app
+        .world()
+        .get::<Sprite>(ctx.sprite_entity)
+        .unwrap()
+        .image
+        .clone();
+
+    let clip_id = ctx.library().register_clip(Clip::from_frames([0, 1]));
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+
+    assert_eq!(
+        ctx.app
+            .world()
+            .get::<Sprite>(ctx.sprite_entity)
+            .unwrap()
+            .image,
+        image
+    );
+}
@@ -0,0 +1,126 @@
+pub mod context;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn disabled_clips_are_skipped() {
+    let mut ctx = Context::new();
+
+    let marker_id = ctx.library().new_marker();
+
+    let clip1_id = ctx.library().register_clip(Clip::from_frames([4, 5]));
+
+    let clip2_id = ctx
+        .library()
+        .register_clip(Clip::from_frames([6, 7]).with_marker(marker_id, 0));
+
+    let clip3_id = ctx.library().register_clip(Clip::from_frames([8]));
+
+    let animation = Animation::from_clips([clip1_id, clip2_id, clip3_id])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_repetitions(AnimationRepeat::Times(1));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.update_sprite_animation(|anim| {
+        anim.set_clip_enabled(clip2_id, false);
+    });
+
+    ctx.run(50);
+    ctx.check(4, [ctx.anim_start(animation_id)]);
+
+    ctx.run(100); // 150
+    ctx.check(5, []);
+
+    // The second clip is skipped along with its events
+
+    ctx.run(100); // 250
+    ctx.check(
+        8,
+        [
+            ctx.clip_rep_end(animation_id, clip1_id, 0),
+            ctx.clip_end(animation_id, clip1_id),
+        ],
+    );
+
+    ctx.run(100); // 350
+    ctx.check(
+        8,
+        [
+            ctx.clip_rep_end(animation_id, clip3_id, 0),
+            ctx.clip_end(animation_id, clip3_id),
+            ctx.anim_rep_end(animation_id, 0),
+            ctx.anim_end(animation_id),
+        ],
+    );
+}
+
+#[test]
+fn clips_can_be_enabled_again() {
+    let mut ctx = Context::new();
+
+    let clip1_id = ctx.library().register_clip(Clip::from_frames([4]));
+    let clip2_id = ctx.library().register_clip(Clip::from_frames([5]));
+
+    let animation =
+        Animation::from_clips([clip1_id, clip2_id]).with_duration(AnimationDuration::PerFrame(100));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.update_sprite_animation(|anim| {
+        anim.set_clip_enabled(clip2_id, false);
+    });
+
+    ctx.run(50);
+    ctx.check(4, [ctx.anim_start(animation_id)]);
+
+    ctx.run(100); // 150, the second clip is skipped
+    ctx.check(
+        4,
+        [
+            ctx.clip_rep_end(animation_id, clip1_id, 0),
+            ctx.clip_end(animation_id, clip1_id),
+            ctx.anim_rep_end(animation_id, 0),
+        ],
+    );
+
+    ctx.update_sprite_animation(|anim| {
+        anim.set_clip_enabled(clip2_id, true);
+    });
+
+    ctx.run(100); // 250
+    ctx.check(
+        5,
+        [
+            ctx.clip_rep_end(animation_id, clip1_id, 0),
+            ctx.clip_end(animation_id, clip1_id),
+        ],
+    );
+}
+
+#[test]
+fn all_clips_disabled() {
+    let mut ctx = Context::new();
+
+    let clip_id = ctx.library().register_clip(Clip::from_frames([4, 5]));
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.update_sprite_animation(|anim| {
+        anim.set_clip_enabled(clip_id, false);
+    });
+
+    // The animation has nothing to play
+
+    ctx.run(100);
+    ctx.check(0, [ctx.anim_empty(animation_id)]);
+
+    ctx.run(100);
+    ctx.check(0, []);
+}
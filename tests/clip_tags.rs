@@ -0,0 +1,83 @@
+pub mod context;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn clip_tags_in_events() {
+    let mut ctx = Context::new();
+
+    let windup_clip = Clip::from_frames([0, 1])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_tag("attack_windup");
+    let windup_clip_id = ctx.library().register_clip(windup_clip);
+
+    let strike_clip = Clip::from_frames([2])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_tag("attack_strike");
+    let strike_clip_id = ctx.library().register_clip(strike_clip);
+
+    let animation = Animation::from_clips([windup_clip_id, strike_clip_id])
+        .with_repetitions(AnimationRepeat::Times(1));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    let tagged_clip_end = |ctx: &Context, clip_id: ClipId, clip_tag: &str| {
+        [
+            AnimationEvent::ClipRepetitionEnd {
+                entity: ctx.sprite_entity,
+                animation_id,
+                clip_id,
+                clip_tag: Some(ClipTag::new(clip_tag)),
+                clip_repetition: 0,
+            },
+            AnimationEvent::ClipEnd {
+                entity: ctx.sprite_entity,
+                animation_id,
+                clip_id,
+                clip_tag: Some(ClipTag::new(clip_tag)),
+            },
+        ]
+    };
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+
+    ctx.run(100); // 250
+    ctx.check(2, tagged_clip_end(&ctx, windup_clip_id, "attack_windup"));
+
+    // The end events emitted by the animator at the end of the animation are tagged too
+
+    ctx.run(100); // 350
+    ctx.check(
+        2,
+        tagged_clip_end(&ctx, strike_clip_id, "attack_strike")
+            .into_iter()
+            .chain([
+                ctx.anim_rep_end(animation_id, 0),
+                ctx.anim_end(animation_id),
+            ]),
+    );
+}
+
+#[test]
+fn clip_tags_are_reflected_by_name() {
+    use bevy::reflect::{PartialReflect, ReflectRef, TupleStruct};
+
+    let tag = ClipTag::new("attack_windup");
+
+    let ReflectRef::TupleStruct(reflected_tag) = tag.reflect_ref() else {
+        panic!("ClipTag should be reflected as a tuple struct");
+    };
+
+    let name = reflected_tag.field(0).unwrap();
+
+    // The interned index is not exposed
+
+    assert!(matches!(name.reflect_ref(), ReflectRef::Opaque(_)));
+    assert_eq!(format!("{name:?}"), "\"attack_windup\"");
+}
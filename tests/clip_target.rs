@@ -0,0 +1,77 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+fn child_atlas_index(ctx: &Context, child: Entity) -> usize {
+    ctx.app
+        .world()
+        .get::<Sprite>(child)
+        .and_then(|sprite| sprite.texture_atlas.as_ref())
+        .unwrap()
+        .index
+}
+
+#[test]
+fn clip_animates_child_target() {
+    let mut ctx = Context::new();
+
+    const OVERLAY: ClipTarget = ClipTarget(1);
+
+    let overlay = ctx
+        .app
+        .world_mut()
+        .spawn((
+            Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..default()
+            },
+            OVERLAY,
+        ))
+        .id();
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .add_child(overlay);
+
+    let main_clip_id = ctx.library().register_clip(Clip::from_frames([1, 2]));
+
+    let overlay_clip_id = ctx
+        .library()
+        .register_clip(Clip::from_frames([4, 5]).with_target(OVERLAY));
+
+    let animation = Animation::from_clips([main_clip_id, overlay_clip_id])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_repetitions(AnimationRepeat::Times(1));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    // The first clip animates the sprite
+
+    ctx.run(50);
+    ctx.check(1, [ctx.anim_start(animation_id)]);
+    assert_eq!(child_atlas_index(&ctx, overlay), 0);
+
+    ctx.run(100); // 150
+    ctx.check(2, []);
+    assert_eq!(child_atlas_index(&ctx, overlay), 0);
+
+    // The second clip animates the child while the sprite keeps its frame
+
+    ctx.run(100); // 250
+    ctx.check(
+        2,
+        [
+            ctx.clip_rep_end(animation_id, main_clip_id, 0),
+            ctx.clip_end(animation_id, main_clip_id),
+        ],
+    );
+    assert_eq!(child_atlas_index(&ctx, overlay), 4);
+
+    ctx.run(100); // 350
+    ctx.check(2, []);
+    assert_eq!(child_atlas_index(&ctx, overlay), 5);
+}
@@ -18,6 +18,10 @@ pub struct Context {
 
 impl Context {
     pub fn new() -> Self {
+        Self::with_plugin(SpritesheetAnimationPlugin::default())
+    }
+
+    pub fn with_plugin(plugin: SpritesheetAnimationPlugin) -> Self {
         // Initialize the app
 
         let mut app = App::new();
@@ -35,7 +39,7 @@ impl Context {
                     .into(),
                     ..default()
                 }),
-            SpritesheetAnimationPlugin::default(),
+            plugin,
         ))
         // Insert a manual update strategy to control time
         .insert_resource(TimeUpdateStrategy::ManualInstant(Instant::now()));
@@ -186,6 +190,20 @@ impl Context {
         builder(&mut sprite_animation);
     }
 
+    pub fn anim_start(&self, animation_id: AnimationId) -> AnimationEvent {
+        AnimationEvent::AnimationStarted {
+            entity: self.sprite_entity,
+            animation_id,
+        }
+    }
+
+    pub fn anim_empty(&self, animation_id: AnimationId) -> AnimationEvent {
+        AnimationEvent::EmptyAnimation {
+            entity: self.sprite_entity,
+            animation_id,
+        }
+    }
+
     pub fn marker_hit(
         &self,
         marker_id: AnimationMarkerId,
@@ -214,6 +232,7 @@ impl Context {
             entity: self.sprite_entity,
             animation_id,
             clip_id,
+            clip_tag: None,
             clip_repetition,
         }
     }
@@ -223,6 +242,7 @@ impl Context {
             entity: self.sprite_entity,
             animation_id,
             clip_id,
+            clip_tag: None,
         }
     }
 
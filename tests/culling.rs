@@ -0,0 +1,75 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+fn atlas_index(ctx: &Context, entity: Entity) -> usize {
+    ctx.app
+        .world()
+        .get::<Sprite>(entity)
+        .unwrap()
+        .texture_atlas
+        .as_ref()
+        .unwrap()
+        .index
+}
+
+#[test]
+fn offscreen() {
+    // No camera: all the sprites are offscreen
+
+    let mut ctx = Context::with_plugin(SpritesheetAnimationPlugin {
+        culling: AnimationCulling {
+            offscreen: true,
+            ..default()
+        },
+        ..default()
+    });
+
+    let clip = Clip::from_frames([0, 1, 2, 3]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    let opted_out_entity = ctx
+        .app
+        .world_mut()
+        .spawn((
+            Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..default()
+            },
+            SpritesheetAnimation::from_id(animation_id),
+            NoAnimationCulling,
+        ))
+        .id();
+
+    ctx.run(50);
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 0);
+    assert_eq!(atlas_index(&ctx, opted_out_entity), 0);
+
+    // The culled sprite is held back
+
+    ctx.run(100); // 150
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 0);
+    assert_eq!(atlas_index(&ctx, opted_out_entity), 1);
+
+    ctx.run(200); // 350
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 0);
+    assert_eq!(atlas_index(&ctx, opted_out_entity), 3);
+
+    // The culled sprite catches up at the update interval
+
+    ctx.run(200); // 550
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 1);
+    assert_eq!(atlas_index(&ctx, opted_out_entity), 1);
+}
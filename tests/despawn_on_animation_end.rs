@@ -0,0 +1,113 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+fn once(ctx: &mut Context) -> (AnimationId, ClipId) {
+    let clip = Clip::from_frames([0, 1]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(1));
+    let animation_id = ctx.library().register_animation(animation);
+
+    (animation_id, clip_id)
+}
+
+#[test]
+fn despawn() {
+    let mut ctx = Context::new();
+
+    let (animation_id, _) = once(&mut ctx);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(DespawnOnAnimationEnd::Despawn);
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+
+    ctx.run(100); // 250
+    assert!(ctx.app.world().get::<Sprite>(ctx.sprite_entity).is_none());
+}
+
+#[test]
+fn remove_animation() {
+    let mut ctx = Context::new();
+
+    let (animation_id, clip_id) = once(&mut ctx);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(DespawnOnAnimationEnd::RemoveAnimation);
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    ctx.run(200); // 250
+    ctx.check(
+        1,
+        [
+            ctx.clip_rep_end(animation_id, clip_id, 0),
+            ctx.clip_end(animation_id, clip_id),
+            ctx.anim_rep_end(animation_id, 0),
+            ctx.anim_end(animation_id),
+        ],
+    );
+
+    // The last frame stays displayed
+
+    assert!(ctx
+        .app
+        .world()
+        .get::<SpritesheetAnimation>(ctx.sprite_entity)
+        .is_none());
+}
+
+#[test]
+fn queued_animations_keep_the_entity() {
+    let mut ctx = Context::new();
+
+    let (animation_id, clip_id) = once(&mut ctx);
+    let (next_animation_id, _) = once(&mut ctx);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.update_sprite_animation(|animation| animation.queue(next_animation_id));
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(DespawnOnAnimationEnd::Despawn);
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    ctx.run(200); // 250
+    ctx.check(
+        1,
+        [
+            ctx.clip_rep_end(animation_id, clip_id, 0),
+            ctx.clip_end(animation_id, clip_id),
+            ctx.anim_rep_end(animation_id, 0),
+            ctx.anim_end(animation_id),
+        ],
+    );
+
+    // The last animation despawns the entity
+
+    ctx.run(10); // 260
+    ctx.check(0, [ctx.anim_start(next_animation_id)]);
+
+    ctx.run(300); // 560
+    assert!(ctx.app.world().get::<Sprite>(ctx.sprite_entity).is_none());
+}
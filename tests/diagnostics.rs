@@ -0,0 +1,66 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+// A single test as the diagnostics mode is shared by all the apps of the process
+
+#[test]
+fn diagnostics_modes() {
+    let mut ctx = Context::new();
+
+    let diagnostics = |ctx: &Context| {
+        ctx.app
+            .world()
+            .resource::<Events<AnimationDiagnostic>>()
+            .iter_current_update_events()
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    let set_mode = |ctx: &mut Context, mode: DiagnosticsMode| {
+        ctx.app
+            .world_mut()
+            .resource_mut::<AnimationDiagnostics>()
+            .mode = mode;
+
+        ctx.app.update();
+    };
+
+    let spritesheet = Spritesheet::new(4, 2);
+
+    // Logged issues are not collected
+
+    assert!(spritesheet.row(2).is_empty());
+
+    ctx.app.update();
+
+    assert!(diagnostics(&ctx).is_empty());
+
+    // Collected issues are emitted on the next update
+
+    set_mode(&mut ctx, DiagnosticsMode::Collect);
+
+    assert!(spritesheet.row(2).is_empty());
+
+    ctx.app.update();
+
+    assert_eq!(
+        diagnostics(&ctx),
+        vec![AnimationDiagnostic {
+            level: DiagnosticLevel::Warning,
+            message: "row 2 exceeds the spritesheet size (4, 2)".to_string(),
+        }]
+    );
+
+    // Silenced issues are dropped
+
+    set_mode(&mut ctx, DiagnosticsMode::Silent);
+
+    assert!(spritesheet.row(2).is_empty());
+
+    ctx.app.update();
+
+    assert!(diagnostics(&ctx).is_empty());
+}
@@ -20,7 +20,7 @@ fn clip_backwards() {
     ctx.add_animation_to_sprite(animation_id);
 
     ctx.run(50);
-    ctx.check(2, []);
+    ctx.check(2, [ctx.anim_start(animation_id)]);
 
     ctx.run(100);
     ctx.check(1, []);
@@ -61,7 +61,7 @@ fn animation_backwards() {
     ctx.add_animation_to_sprite(animation_id);
 
     ctx.run(50);
-    ctx.check(2, []);
+    ctx.check(2, [ctx.anim_start(animation_id)]);
 
     ctx.run(100);
     ctx.check(1, []);
@@ -109,7 +109,7 @@ fn animation_backwards_clip_backwards() {
     // clip 3 (played backwards)
 
     ctx.run(50);
-    ctx.check(2, []);
+    ctx.check(2, [ctx.anim_start(animation_id)]);
 
     ctx.run(100);
     ctx.check(1, []);
@@ -185,7 +185,7 @@ fn clip_pingpong() {
     // Ping
 
     ctx.run(50);
-    ctx.check(0, []);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
 
     ctx.run(100);
     ctx.check(1, []);
@@ -240,7 +240,7 @@ fn animation_pingpong() {
     // Ping
 
     ctx.run(50);
-    ctx.check(0, []);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
 
     ctx.run(100);
     ctx.check(1, []);
@@ -369,7 +369,7 @@ fn animation_pingpong_clip_backwards() {
     // Ping
 
     ctx.run(50);
-    ctx.check(2, []);
+    ctx.check(2, [ctx.anim_start(animation_id)]);
 
     ctx.run(100);
     ctx.check(1, []);
@@ -428,7 +428,7 @@ fn animation_backwards_clip_pingpong() {
     // Pong
 
     ctx.run(50);
-    ctx.check(0, []);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
 
     ctx.run(100);
     ctx.check(1, []);
@@ -459,3 +459,49 @@ fn animation_backwards_clip_pingpong() {
     ctx.run(100);
     ctx.check(1, []);
 }
+
+// Reversed copies
+
+#[test]
+fn clip_reversed_copy() {
+    let mut ctx = Context::new();
+
+    let marker_id = ctx.library().new_marker();
+
+    let clip = Clip::from_frames([0, 1, 2]).with_marker(marker_id, 0);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let reversed_clip_id = ctx.library().copy_clip_reversed(clip_id);
+
+    let animation = Animation::from_clip(reversed_clip_id)
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_repetitions(AnimationRepeat::Times(1));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+    ctx.check(2, [ctx.anim_start(animation_id)]);
+
+    ctx.run(100);
+    ctx.check(1, []);
+
+    // The marker follows its frame
+
+    ctx.run(100);
+    ctx.check(
+        0,
+        [ctx.marker_hit(marker_id, animation_id, 0, reversed_clip_id, 0)],
+    );
+
+    ctx.run(100);
+    ctx.check(
+        0,
+        [
+            ctx.clip_rep_end(animation_id, reversed_clip_id, 0),
+            ctx.clip_end(animation_id, reversed_clip_id),
+            ctx.anim_rep_end(animation_id, 0),
+            ctx.anim_end(animation_id),
+        ],
+    );
+}
@@ -1,5 +1,8 @@
 pub mod context;
 
+use std::time::Duration;
+
+use bevy::prelude::*;
 use bevy_spritesheet_animation::prelude::*;
 use context::*;
 
@@ -16,7 +19,7 @@ fn clip_duration_per_frame() {
     ctx.add_animation_to_sprite(animation_id);
 
     ctx.run(400);
-    ctx.check(5, []);
+    ctx.check(5, [ctx.anim_start(animation_id)]);
 
     ctx.run(400); // 800
     ctx.check(5, []);
@@ -41,7 +44,7 @@ fn clip_duration_per_cycle() {
     ctx.add_animation_to_sprite(animation_id);
 
     ctx.run(500);
-    ctx.check(4, []);
+    ctx.check(4, [ctx.anim_start(animation_id)]);
 
     ctx.run(1000); // 1.5
     ctx.check(5, []);
@@ -87,7 +90,7 @@ fn animation_duration_per_frame() {
     ctx.add_animation_to_sprite(animation_id);
 
     ctx.run(400);
-    ctx.check(0, []);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
 
     ctx.run(400); // 800
     ctx.check(1, []);
@@ -140,7 +143,7 @@ fn animation_duration_per_cycle() {
     // clip 1, frame 0: 0 to 555
 
     ctx.run(200);
-    ctx.check(0, []);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
 
     ctx.run(350); // 550
     ctx.check(0, []);
@@ -236,7 +239,7 @@ fn pause_resume() {
     ctx.add_animation_to_sprite(animation_id);
 
     ctx.run(50);
-    ctx.check(4, []);
+    ctx.check(4, [ctx.anim_start(animation_id)]);
 
     ctx.run(50);
     ctx.check(5, []);
@@ -297,7 +300,7 @@ fn speed_factor() {
     });
 
     ctx.run(60); // +60*2 = 120
-    ctx.check(2, []);
+    ctx.check(2, [ctx.anim_start(animation_id)]);
 
     ctx.run(50); // +50*2 = 220
     ctx.check(3, []);
@@ -323,3 +326,324 @@ fn speed_factor() {
     ctx.run(100); // 520
     ctx.check(6, []);
 }
+
+#[test]
+fn negative_speed_factor() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([1, 2, 3, 4]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(1));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+    ctx.check(1, [ctx.anim_start(animation_id)]);
+
+    ctx.run(200); // 250
+    ctx.check(3, []);
+
+    // Backwards
+
+    ctx.update_sprite_animation(|anim| {
+        anim.speed_factor = -1.0;
+    });
+
+    ctx.run(100); // 150
+    ctx.check(2, []);
+
+    ctx.run(100); // 50
+    ctx.check(1, []);
+
+    // Stops at the first frame
+
+    ctx.run(200); // 0
+    ctx.check(1, []);
+
+    // Forwards again
+
+    ctx.update_sprite_animation(|anim| {
+        anim.speed_factor = 1.0;
+    });
+
+    ctx.run(150); // 150
+    ctx.check(2, []);
+
+    ctx.run(300); // 450 (end)
+    ctx.check(
+        4,
+        [
+            ctx.clip_rep_end(animation_id, clip_id, 0),
+            ctx.clip_end(animation_id, clip_id),
+            ctx.anim_rep_end(animation_id, 0),
+            ctx.anim_end(animation_id),
+        ],
+    );
+
+    // Backwards from the end
+
+    ctx.update_sprite_animation(|anim| {
+        anim.speed_factor = -1.0;
+    });
+
+    ctx.run(150); // 250
+    ctx.check(3, []);
+}
+
+#[test]
+fn frame_durations() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_timed_frames([(4, 100), (5, 300), (6, 200)]);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+    ctx.check(4, [ctx.anim_start(animation_id)]);
+
+    ctx.run(100); // 150
+    ctx.check(5, []);
+
+    ctx.run(200); // 350
+    ctx.check(5, []);
+
+    ctx.run(100); // 450
+    ctx.check(6, []);
+
+    ctx.run(200); // 650
+    ctx.check(
+        4,
+        [
+            ctx.clip_rep_end(animation_id, clip_id, 0),
+            ctx.clip_end(animation_id, clip_id),
+            ctx.anim_rep_end(animation_id, 0),
+        ],
+    );
+}
+
+#[test]
+fn frame_durations_with_animation_duration() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([4, 5, 6])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_frame_duration(1, 400);
+    let clip_id = ctx.library().register_clip(clip);
+
+    // One repetition lasts 600 ms, scale it x2: 200 → 800 → 200
+
+    let animation =
+        Animation::from_clip(clip_id).with_duration(AnimationDuration::PerRepetition(1200));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(100);
+    ctx.check(4, [ctx.anim_start(animation_id)]);
+
+    ctx.run(200); // 300
+    ctx.check(5, []);
+
+    ctx.run(750); // 1050
+    ctx.check(6, []);
+
+    ctx.run(100); // 1150
+    ctx.check(6, []);
+
+    ctx.run(100); // 1250
+    ctx.check(
+        4,
+        [
+            ctx.clip_rep_end(animation_id, clip_id, 0),
+            ctx.clip_end(animation_id, clip_id),
+            ctx.anim_rep_end(animation_id, 0),
+        ],
+    );
+}
+
+#[test]
+fn duration_jitter() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([4, 5])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_duration_jitter(0.5);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    // Measure the duration of the frames with small time steps
+
+    let mut frame_durations = Vec::new();
+    let mut current_frame = 0;
+    let mut current_frame_duration = 0;
+
+    for _ in 0..400 {
+        ctx.run(5);
+        current_frame_duration += 5;
+
+        let mut frame = 0;
+        ctx.get_sprite(|anim| frame = anim.progress.frame);
+
+        if frame != current_frame {
+            frame_durations.push(current_frame_duration);
+            current_frame = frame;
+            current_frame_duration = 0;
+        }
+    }
+
+    // The frames last between 50% and 150% of their duration, but not all exactly as specified
+
+    assert!(frame_durations.len() > 10);
+
+    assert!(frame_durations
+        .iter()
+        .all(|duration| (45..=155).contains(duration)));
+
+    assert!(frame_durations
+        .iter()
+        .any(|duration| !(95..=105).contains(duration)));
+}
+
+#[test]
+fn speed_jitter() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([1, 2, 3]);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    let entities: Vec<Entity> = (0..20)
+        .map(|_| {
+            ctx.app
+                .world_mut()
+                .spawn((
+                    Sprite {
+                        texture_atlas: Some(TextureAtlas::default()),
+                        ..default()
+                    },
+                    SpritesheetAnimation::from_id(animation_id).with_speed_jitter(0.5..1.5),
+                ))
+                .id()
+        })
+        .collect();
+
+    ctx.run(50);
+
+    let speeds: Vec<f32> = entities
+        .iter()
+        .map(|entity| {
+            let animation = ctx
+                .app
+                .world()
+                .get::<SpritesheetAnimation>(*entity)
+                .unwrap();
+
+            // The jitter is applied once
+
+            assert!(animation.speed_jitter.is_none());
+
+            animation.speed_factor
+        })
+        .collect();
+
+    assert!(speeds.iter().all(|speed| (0.5..1.5).contains(speed)));
+
+    // The entities get different speeds
+
+    assert!(speeds.iter().any(|speed| *speed != speeds[0]));
+
+    // Switching animations keeps the speed
+
+    ctx.app
+        .world_mut()
+        .get_mut::<SpritesheetAnimation>(entities[0])
+        .unwrap()
+        .switch(animation_id);
+
+    ctx.run(50);
+
+    assert_eq!(
+        ctx.app
+            .world()
+            .get::<SpritesheetAnimation>(entities[0])
+            .unwrap()
+            .speed_factor,
+        speeds[0]
+    );
+}
+
+#[test]
+fn retimed() {
+    let mut ctx = Context::new();
+
+    let clip1 = Clip::from_frames([0, 1, 2])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_frame_duration(1, 200);
+    let clip1_id = ctx.library().register_clip(clip1);
+
+    let clip2 = Clip::from_frames([3, 4]).with_duration(AnimationDuration::PerFrame(200));
+    let clip2_id = ctx.library().register_clip(clip2);
+
+    let frame_durations = |ctx: &mut Context, animation: Animation| {
+        let animation_id = ctx.library().register_animation(animation);
+
+        ctx.library()
+            .animation_frames(animation_id)
+            .map(|frame| frame.duration.as_millis() as u64)
+            .collect::<Vec<_>>()
+    };
+
+    // 800ms in total, retimed to 1600ms
+
+    let animation =
+        Animation::from_clips([clip1_id, clip2_id]).retimed(Duration::from_millis(1600));
+
+    assert_eq!(animation.retimed_duration(), &Some(1600));
+
+    assert_eq!(
+        frame_durations(&mut ctx, animation),
+        vec![200, 400, 200, 400, 400]
+    );
+
+    // The duration of the animation is kept
+
+    let animation = Animation::from_clips([clip1_id, clip2_id])
+        .with_duration(AnimationDuration::PerFrame(50))
+        .retimed(Duration::from_millis(500));
+
+    assert_eq!(
+        frame_durations(&mut ctx, animation),
+        vec![100, 100, 100, 100, 100]
+    );
+
+    // The rounding remainder is spread over the frames
+
+    let animation = Animation::from_clip(clip1_id).retimed(Duration::from_millis(1001));
+
+    assert_eq!(frame_durations(&mut ctx, animation), vec![250, 501, 250]);
+
+    let animation = Animation::from_clip(clip2_id).retimed(Duration::from_millis(1001));
+
+    assert_eq!(frame_durations(&mut ctx, animation), vec![501, 500]);
+
+    // The total duration covers all the repetitions
+
+    let animation = Animation::from_clip(clip2_id)
+        .with_repetitions(AnimationRepeat::Times(4))
+        .retimed(Duration::from_millis(1000));
+
+    assert_eq!(animation.retimed_duration(), &Some(250));
+}
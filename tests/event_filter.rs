@@ -0,0 +1,90 @@
+pub mod context;
+
+use bevy::{ecs::system::SystemState, prelude::*};
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+fn filtered_events(ctx: &mut Context) -> Vec<AnimationEvent> {
+    let mut state: SystemState<FilteredAnimationEvents> = SystemState::new(ctx.app.world_mut());
+
+    let events = state.get(ctx.app.world());
+
+    events.read(ctx.sprite_entity).to_vec()
+}
+
+#[test]
+fn kinds_and_markers() {
+    let mut ctx = Context::new();
+
+    let marker1_id = ctx.library().new_marker();
+    let marker2_id = ctx.library().new_marker();
+
+    let clip = Clip::from_frames([0, 1, 2])
+        .with_marker(marker1_id, 0)
+        .with_marker(marker2_id, 1)
+        .with_repetitions(1);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id)
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_repetitions(AnimationRepeat::Times(1));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.app.world_mut().entity_mut(ctx.sprite_entity).insert(
+        AnimationEventFilter::new(
+            AnimationEventKinds::MARKER_HIT | AnimationEventKinds::ANIMATION_END,
+        )
+        .with_markers([marker2_id]),
+    );
+
+    // The start and the first marker are filtered out
+
+    ctx.run(50);
+    ctx.check(
+        0,
+        [
+            ctx.anim_start(animation_id),
+            ctx.marker_hit(marker1_id, animation_id, 0, clip_id, 0),
+        ],
+    );
+
+    assert_eq!(filtered_events(&mut ctx), []);
+
+    ctx.run(100); // 150
+    ctx.check(1, [ctx.marker_hit(marker2_id, animation_id, 0, clip_id, 0)]);
+
+    assert_eq!(
+        filtered_events(&mut ctx),
+        [ctx.marker_hit(marker2_id, animation_id, 0, clip_id, 0)]
+    );
+
+    // The buffer is cleared on the next update
+
+    ctx.run(50); // 200
+
+    assert_eq!(filtered_events(&mut ctx), []);
+
+    ctx.run(150); // 350
+
+    assert_eq!(filtered_events(&mut ctx), [ctx.anim_end(animation_id)]);
+}
+
+#[test]
+fn entities_without_filter() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([0, 1, 2]);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+
+    assert_eq!(filtered_events(&mut ctx), []);
+}
@@ -0,0 +1,103 @@
+pub mod context;
+
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+use bevy_spritesheet_animation::{plugin::AnimationSystemSet, prelude::*};
+use context::*;
+
+#[derive(Resource, Default)]
+struct StartCounts {
+    update: usize,
+    post_update: usize,
+}
+
+fn count_in_update(mut events: EventReader<AnimationEvent>, mut counts: ResMut<StartCounts>) {
+    counts.update += events
+        .read()
+        .filter(|event| matches!(event, AnimationEvent::AnimationStarted { .. }))
+        .count();
+}
+
+fn count_in_post_update(mut events: EventReader<AnimationEvent>, mut counts: ResMut<StartCounts>) {
+    counts.post_update += events
+        .read()
+        .filter(|event| matches!(event, AnimationEvent::AnimationStarted { .. }))
+        .count();
+}
+
+fn start_animation(ctx: &mut Context) {
+    let clip_id = ctx.library().register_clip(Clip::from_frames([0, 1, 2]));
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+}
+
+fn counts(ctx: &Context) -> (usize, usize) {
+    let counts = ctx.app.world().resource::<StartCounts>();
+
+    (counts.update, counts.post_update)
+}
+
+#[test]
+fn events_read_once_with_default_schedule() {
+    let mut ctx = Context::new();
+
+    ctx.app
+        .init_resource::<StartCounts>()
+        .add_systems(Update, count_in_update)
+        .add_systems(PostUpdate, count_in_post_update.after(AnimationSystemSet));
+
+    start_animation(&mut ctx);
+
+    // Readers after the animations get the events on the same frame
+
+    ctx.app.update();
+    assert_eq!(counts(&ctx), (0, 1));
+
+    // Readers before the animations get them on the next frame
+
+    ctx.app.update();
+    assert_eq!(counts(&ctx), (1, 1));
+
+    // No reader gets them twice
+
+    for _ in 0..3 {
+        ctx.app.update();
+    }
+
+    assert_eq!(counts(&ctx), (1, 1));
+}
+
+#[test]
+fn events_read_once_with_custom_schedule() {
+    let mut ctx = Context::with_plugin(SpritesheetAnimationPlugin {
+        animation_schedule: Update.intern(),
+        ..default()
+    });
+
+    ctx.app
+        .init_resource::<StartCounts>()
+        .add_systems(Update, count_in_update.after(AnimationSystemSet))
+        .add_systems(PostUpdate, count_in_post_update);
+
+    start_animation(&mut ctx);
+
+    ctx.app.update();
+    assert_eq!(counts(&ctx), (1, 1));
+
+    for _ in 0..3 {
+        ctx.app.update();
+    }
+
+    assert_eq!(counts(&ctx), (1, 1));
+}
+
+#[test]
+#[should_panic]
+fn schedules_after_post_update_are_rejected() {
+    Context::with_plugin(SpritesheetAnimationPlugin {
+        animation_schedule: Last.intern(),
+        ..default()
+    });
+}
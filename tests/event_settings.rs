@@ -0,0 +1,144 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn entity_without_events() {
+    let mut ctx = Context::new();
+
+    let marker_id = ctx.library().new_marker();
+
+    let clip = Clip::from_frames([4, 5])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(marker_id, 1);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(1));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.update_sprite_animation(|anim| {
+        *anim = SpritesheetAnimation::from_id(animation_id).without_events();
+    });
+
+    // The animation plays but emits no events
+
+    ctx.run(50);
+    ctx.check(4, []);
+
+    ctx.run(100); // 150
+    ctx.check(5, []);
+
+    ctx.run(100); // 250, the end
+    ctx.check(5, []);
+}
+
+#[test]
+fn sprites_without_events() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([4, 5]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<AnimationEventSettings>()
+        .sprite = false;
+
+    ctx.run(50);
+    ctx.check(4, []);
+
+    ctx.run(100); // 150
+    ctx.check(5, []);
+
+    ctx.run(100); // 250, loop
+    ctx.check(4, []);
+
+    // Re-enable the events for sprites
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<AnimationEventSettings>()
+        .sprite = true;
+
+    ctx.run(100); // 350
+    ctx.check(5, []);
+
+    ctx.run(100); // 450, loop
+    ctx.check(
+        4,
+        [
+            ctx.clip_rep_end(animation_id, clip_id, 0),
+            ctx.clip_end(animation_id, clip_id),
+            ctx.anim_rep_end(animation_id, 1),
+        ],
+    );
+}
+
+#[test]
+fn clip_start_events() {
+    let mut ctx = Context::new();
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<AnimationEventSettings>()
+        .clip_starts = true;
+
+    let clip1_id = ctx
+        .library()
+        .register_clip(Clip::from_frames([4, 5]).with_duration(AnimationDuration::PerFrame(100)));
+
+    let clip2_id = ctx
+        .library()
+        .register_clip(Clip::from_frames([6]).with_duration(AnimationDuration::PerFrame(100)));
+
+    let animation = Animation::from_clips([clip1_id, clip2_id]);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    // Collects the ClipStart events with the time elapsed since the clips started
+
+    fn clip_starts(ctx: &Context) -> Vec<(ClipId, usize, u32)> {
+        let events = ctx.app.world().resource::<Events<AnimationEvent>>();
+
+        events
+            .iter_current_update_events()
+            .filter_map(|event| match event {
+                AnimationEvent::ClipStart {
+                    clip_id,
+                    animation_repetition,
+                    elapsed,
+                    ..
+                } => Some((
+                    *clip_id,
+                    *animation_repetition,
+                    (elapsed.as_secs_f32() * 1000.0).round() as u32,
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    ctx.run(50);
+    assert_eq!(clip_starts(&ctx), [(clip1_id, 0, 50)]);
+
+    ctx.run(100); // 150
+    assert!(clip_starts(&ctx).is_empty());
+
+    // The second clip started 30 ms ago
+
+    ctx.run(80); // 230
+    assert_eq!(clip_starts(&ctx), [(clip2_id, 0, 30)]);
+
+    ctx.run(100); // 330
+    assert_eq!(clip_starts(&ctx), [(clip1_id, 1, 30)]);
+}
@@ -0,0 +1,88 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[derive(Component)]
+struct CustomSprite {
+    index: usize,
+}
+
+impl AnimationTarget for CustomSprite {
+    fn atlas_index(&self) -> usize {
+        self.index
+    }
+
+    fn set_atlas_index(&mut self, atlas_index: usize) {
+        self.index = atlas_index;
+    }
+}
+
+#[derive(Component)]
+struct HookedEvent(AnimationEvent);
+
+fn record_event(commands: &mut Commands, event: &AnimationEvent) {
+    commands.spawn(HookedEvent(*event));
+}
+
+#[test]
+fn custom_animation_target() {
+    let mut ctx = Context::new();
+
+    ctx.app.register_animation_target::<CustomSprite>();
+
+    let clip = Clip::from_frames([4, 5, 6]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    let entity = ctx
+        .app
+        .world_mut()
+        .spawn((
+            CustomSprite { index: 0 },
+            SpritesheetAnimation::from_id(animation_id)
+                .with_frame_remap(FrameRemap::from_offset(10)),
+        ))
+        .id();
+
+    let index = |ctx: &Context| ctx.app.world().get::<CustomSprite>(entity).unwrap().index;
+
+    ctx.run(50);
+    assert_eq!(index(&ctx), 14);
+
+    ctx.run(100);
+    assert_eq!(index(&ctx), 15);
+
+    ctx.run(100);
+    assert_eq!(index(&ctx), 16);
+}
+
+#[test]
+fn animation_event_hook() {
+    let mut ctx = Context::new();
+
+    ctx.app.add_animation_event_hook(record_event);
+
+    let clip = Clip::from_frames([4, 5, 6]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+
+    let events: Vec<_> = ctx
+        .app
+        .world_mut()
+        .query::<&HookedEvent>()
+        .iter(ctx.app.world())
+        .map(|hooked| hooked.0)
+        .collect();
+
+    assert_eq!(events, [ctx.anim_start(animation_id)]);
+}
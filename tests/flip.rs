@@ -0,0 +1,189 @@
+pub mod context;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn clips_flip_the_sprite() {
+    let mut ctx = Context::new();
+
+    let walk = Clip::from_frames([0, 1]).with_duration(AnimationDuration::PerFrame(100));
+
+    let walk_right_id = ctx.library().register_clip(walk.with_flip_x(false));
+    let walk_left_id = ctx
+        .library()
+        .register_clip(walk.with_flip_x(true).with_flip_y(true));
+    let unflipped_id = ctx.library().register_clip(walk);
+
+    let animation = Animation::from_clips([walk_right_id, walk_left_id, unflipped_id])
+        .with_repetitions(AnimationRepeat::Times(1));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    let flips = |ctx: &Context| {
+        let sprite = ctx.app.world().get::<Sprite>(ctx.sprite_entity).unwrap();
+
+        (sprite.flip_x, sprite.flip_y)
+    };
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+    assert_eq!(flips(&ctx), (false, false));
+
+    ctx.run(200); // 250
+    ctx.check(
+        0,
+        [
+            ctx.clip_rep_end(animation_id, walk_right_id, 0),
+            ctx.clip_end(animation_id, walk_right_id),
+        ],
+    );
+    assert_eq!(flips(&ctx), (true, true));
+
+    // Clips without flips keep the current flips
+
+    ctx.run(200); // 450
+    ctx.check(
+        0,
+        [
+            ctx.clip_rep_end(animation_id, walk_left_id, 0),
+            ctx.clip_end(animation_id, walk_left_id),
+        ],
+    );
+    assert_eq!(flips(&ctx), (true, true));
+}
+
+#[test]
+fn flip_transition_hold() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([0, 1, 2]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(FlipTransition::new(FlipTransitionStyle::Hold(
+            Duration::from_millis(60),
+        )));
+
+    let flip_x = |ctx: &Context| {
+        ctx.app
+            .world()
+            .get::<Sprite>(ctx.sprite_entity)
+            .unwrap()
+            .flip_x
+    };
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+    assert!(!flip_x(&ctx));
+
+    // Turn around: the frame is held before flipping the sprite
+
+    ctx.app
+        .world_mut()
+        .get_mut::<FlipTransition>(ctx.sprite_entity)
+        .unwrap()
+        .flip_x = true;
+
+    ctx.run(30); // 80
+    ctx.check(0, []);
+    assert!(!flip_x(&ctx));
+
+    ctx.run(40); // 120
+    ctx.check(0, []);
+    assert!(flip_x(&ctx));
+
+    // The playback continues after the hold
+
+    ctx.run(50); // 170
+    ctx.check(1, []);
+    assert!(flip_x(&ctx));
+}
+
+#[test]
+fn flip_transition_turn_animation() {
+    let mut ctx = Context::new();
+
+    let walk = Clip::from_frames([0, 1]).with_duration(AnimationDuration::PerFrame(100));
+    let walk_clip_id = ctx.library().register_clip(walk);
+    let walk_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(walk_clip_id));
+
+    let turn = Clip::from_frames([8, 9]).with_duration(AnimationDuration::PerFrame(100));
+    let turn_clip_id = ctx.library().register_clip(turn);
+    let turn_id = ctx.library().register_animation(
+        Animation::from_clip(turn_clip_id).with_repetitions(AnimationRepeat::Times(1)),
+    );
+
+    ctx.add_animation_to_sprite(walk_id);
+
+    // The initial direction is applied immediately
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(FlipTransition::new(FlipTransitionStyle::TurnAnimation(turn_id)).with_flip_x(true));
+
+    let flip_x = |ctx: &Context| {
+        ctx.app
+            .world()
+            .get::<Sprite>(ctx.sprite_entity)
+            .unwrap()
+            .flip_x
+    };
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(walk_id)]);
+    assert!(flip_x(&ctx));
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+
+    // Turn around with the turn animation
+
+    ctx.app
+        .world_mut()
+        .get_mut::<FlipTransition>(ctx.sprite_entity)
+        .unwrap()
+        .flip_x = false;
+
+    ctx.run(10); // 160
+    ctx.check(1, []);
+
+    ctx.run(10); // 170
+    ctx.check(8, [ctx.anim_start(turn_id)]);
+    assert!(flip_x(&ctx));
+
+    // The sprite is flipped at the end of the turn animation
+
+    ctx.run(200); // 370
+    ctx.check(
+        9,
+        [
+            ctx.clip_rep_end(turn_id, turn_clip_id, 0),
+            ctx.clip_end(turn_id, turn_clip_id),
+            ctx.anim_rep_end(turn_id, 0),
+            ctx.anim_end(turn_id),
+        ],
+    );
+    assert!(!flip_x(&ctx));
+
+    // The walk animation resumes from the frame where it was interrupted
+
+    ctx.run(10); // 380
+    ctx.check(1, []);
+    assert!(!flip_x(&ctx));
+}
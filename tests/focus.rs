@@ -0,0 +1,138 @@
+pub mod context;
+
+use bevy::{prelude::*, window::WindowFocused};
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+fn atlas_index(ctx: &Context, entity: Entity) -> usize {
+    let entity_ref = ctx.app.world().entity(entity);
+
+    entity_ref
+        .get::<Sprite>()
+        .and_then(|sprite| sprite.texture_atlas.as_ref())
+        .or(entity_ref
+            .get::<ImageNode>()
+            .and_then(|image_node| image_node.texture_atlas.as_ref()))
+        .unwrap()
+        .index
+}
+
+fn set_focus(ctx: &mut Context, focused: bool) {
+    ctx.app.world_mut().send_event(WindowFocused {
+        window: Entity::PLACEHOLDER,
+        focused,
+    });
+}
+
+#[test]
+fn keep_running() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([0, 1, 2]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 0);
+
+    set_focus(&mut ctx, false);
+
+    ctx.run(100);
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 1);
+}
+
+#[test]
+fn pause_except_ui() {
+    let mut ctx = Context::with_plugin(SpritesheetAnimationPlugin {
+        focus_loss_behavior: FocusLossBehavior::PauseExceptUi,
+        ..default()
+    });
+
+    let clip = Clip::from_frames([0, 1, 2, 3]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    let ui_entity = ctx
+        .app
+        .world_mut()
+        .spawn((
+            ImageNode {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..default()
+            },
+            SpritesheetAnimation::from_id(animation_id),
+        ))
+        .id();
+
+    ctx.run(50);
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 0);
+
+    // The sprite is paused while the window is unfocused but the UI image keeps playing
+
+    set_focus(&mut ctx, false);
+
+    ctx.run(100);
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 0);
+    assert_eq!(atlas_index(&ctx, ui_entity), 1);
+
+    ctx.run(100);
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 0);
+    assert_eq!(atlas_index(&ctx, ui_entity), 2);
+
+    // The sprite resumes from where it was
+
+    set_focus(&mut ctx, true);
+
+    ctx.run(100);
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 1);
+    assert_eq!(atlas_index(&ctx, ui_entity), 3);
+}
+
+#[test]
+fn pause_all() {
+    let mut ctx = Context::with_plugin(SpritesheetAnimationPlugin {
+        focus_loss_behavior: FocusLossBehavior::PauseAll,
+        ..default()
+    });
+
+    let clip = Clip::from_frames([0, 1, 2]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 0);
+
+    set_focus(&mut ctx, false);
+
+    ctx.run(500);
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 0);
+
+    set_focus(&mut ctx, true);
+
+    ctx.run(100);
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 1);
+}
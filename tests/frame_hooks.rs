@@ -0,0 +1,157 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[derive(Component)]
+struct Transition(FrameContext);
+
+fn record_transition(commands: &mut Commands, context: &FrameContext) {
+    commands.spawn(Transition(context.clone()));
+}
+
+fn transitions(ctx: &mut Context) -> Vec<FrameContext> {
+    let mut query = ctx.app.world_mut().query::<&Transition>();
+
+    let transitions = query
+        .iter(ctx.app.world())
+        .map(|transition| transition.0.clone())
+        .collect();
+
+    // Forget the transitions for the next checks
+
+    let entities: Vec<_> = ctx
+        .app
+        .world_mut()
+        .query_filtered::<Entity, With<Transition>>()
+        .iter(ctx.app.world())
+        .collect();
+
+    for entity in entities {
+        ctx.app.world_mut().despawn(entity);
+    }
+
+    transitions
+}
+
+#[test]
+fn frame_hooks() {
+    let mut ctx = Context::new();
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<FrameHooks>()
+        .add(record_transition);
+
+    let marker_id = ctx.library().new_marker();
+
+    let clip = Clip::from_frames([4, 5, 6, 7])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(marker_id, 1);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(1));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    // The first frame has no previous frame
+
+    ctx.run(50);
+
+    let first = transitions(&mut ctx);
+
+    assert_eq!(first.len(), 1);
+    assert_eq!(first[0].entity, ctx.sprite_entity);
+    assert_eq!(first[0].animation_id, animation_id);
+    assert_eq!(first[0].previous_frame, None);
+    assert_eq!(first[0].frame.atlas_index, 4);
+    assert_eq!(first[0].frame.clip_id, clip_id);
+    assert!(first[0].events.is_empty());
+
+    // No transition while the frame is displayed
+
+    ctx.run(20);
+
+    assert!(transitions(&mut ctx).is_empty());
+
+    // The next frame knows the previous one and carries its events
+
+    ctx.run(100);
+
+    let second = transitions(&mut ctx);
+
+    assert_eq!(second.len(), 1);
+    assert_eq!(
+        second[0].previous_frame.map(|frame| frame.atlas_index),
+        Some(4)
+    );
+    assert_eq!(second[0].frame.atlas_index, 5);
+    assert_eq!(
+        second[0].events,
+        [ctx.marker_hit(marker_id, animation_id, 0, clip_id, 0)]
+    );
+
+    // Several transitions can happen in the same update
+
+    ctx.run(200);
+
+    let last = transitions(&mut ctx);
+
+    assert_eq!(last.len(), 2);
+    assert_eq!(last[0].frame.atlas_index, 6);
+    assert_eq!(
+        last[1].previous_frame.map(|frame| frame.atlas_index),
+        Some(6)
+    );
+    assert_eq!(last[1].frame.atlas_index, 7);
+
+    // The end of the animation is not a transition
+
+    ctx.run(500);
+
+    assert!(transitions(&mut ctx).is_empty());
+}
+
+#[test]
+fn frame_labels() {
+    let mut ctx = Context::new();
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<FrameHooks>()
+        .add(record_transition);
+
+    let clip = Clip::from_frames([4, 5, 6])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_frame_label(1, "windup-2")
+        .with_frame_label(2, "strike");
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+
+    assert_eq!(transitions(&mut ctx)[0].frame.label, None);
+
+    ctx.run(100); // 150
+
+    let transition = transitions(&mut ctx)[0].clone();
+
+    assert_eq!(transition.previous_frame.unwrap().label, None);
+    assert_eq!(transition.frame.label, Some(FrameLabel::new("windup-2")));
+
+    ctx.run(100); // 250
+
+    let transition = transitions(&mut ctx)[0].clone();
+
+    assert_eq!(
+        transition.previous_frame.unwrap().label,
+        Some(FrameLabel::new("windup-2"))
+    );
+    assert_eq!(transition.frame.label, Some(FrameLabel::new("strike")));
+}
@@ -0,0 +1,74 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn marker_follows_atlas_index_changes() {
+    let mut ctx = Context::with_plugin(SpritesheetAnimationPlugin {
+        frame_change_markers: true,
+        ..default()
+    });
+
+    let clip = Clip::from_frames([0, 0, 1]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(1));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    let changed = |ctx: &Context| {
+        ctx.app
+            .world()
+            .get::<FrameJustChanged>(ctx.sprite_entity)
+            .is_some()
+    };
+
+    // The first frame is a change
+
+    ctx.run(50);
+    assert!(changed(&ctx));
+
+    ctx.run(10); // 60
+    assert!(!changed(&ctx));
+
+    // Same atlas index on the next frame
+
+    ctx.run(90); // 150
+    ctx.check(0, []);
+    assert!(!changed(&ctx));
+
+    // Different atlas index
+
+    ctx.run(100); // 250
+    ctx.check(1, []);
+    assert!(changed(&ctx));
+
+    ctx.run(10); // 260
+    assert!(!changed(&ctx));
+}
+
+#[test]
+fn no_marker_by_default() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([0, 1]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    for _ in 0..3 {
+        ctx.run(100);
+
+        assert!(ctx
+            .app
+            .world()
+            .get::<FrameJustChanged>(ctx.sprite_entity)
+            .is_none());
+    }
+}
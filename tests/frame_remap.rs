@@ -0,0 +1,42 @@
+pub mod context;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn frame_remap() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([0, 1, 2]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.update_sprite_animation(|anim| {
+        *anim = SpritesheetAnimation::from_id(animation_id).with_frame_remap(FrameRemap {
+            indices: [(1, 20)].into(),
+            offset: 8,
+        });
+    });
+
+    ctx.run(50);
+    ctx.check(8, [ctx.anim_start(animation_id)]);
+
+    // Replacements are applied before the offset
+
+    ctx.run(100); // 150
+    ctx.check(28, []);
+
+    ctx.run(100); // 250
+    ctx.check(10, []);
+
+    // Changing the remapping takes effect immediately
+
+    ctx.update_sprite_animation(|anim| anim.frame_remap = FrameRemap::default());
+
+    ctx.run(10); // 260
+    ctx.check(2, []);
+}
@@ -0,0 +1,95 @@
+pub mod context;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn skippable_frames_are_merged() {
+    let mut ctx = Context::new();
+
+    ctx.app.world_mut().resource_mut::<FrameSkipping>().enabled = true;
+
+    let marker_id = ctx.library().new_marker();
+
+    let clip = Clip::from_frames([4, 5, 6, 7])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_skippable_frames([1, 2])
+        .with_marker(marker_id, 2);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    // The first frame stays on screen for the duration of the skipped frames
+    // and emits their markers
+
+    ctx.run(50);
+    ctx.check(
+        4,
+        [
+            ctx.anim_start(animation_id),
+            ctx.marker_hit(marker_id, animation_id, 0, clip_id, 0),
+        ],
+    );
+
+    ctx.run(200); // 250
+    ctx.check(4, []);
+
+    ctx.run(100); // 350
+    ctx.check(7, []);
+
+    // The overall duration does not change
+
+    ctx.run(100); // 450
+    ctx.check(
+        4,
+        [
+            ctx.clip_rep_end(animation_id, clip_id, 0),
+            ctx.clip_end(animation_id, clip_id),
+            ctx.anim_rep_end(animation_id, 0),
+            ctx.marker_hit(marker_id, animation_id, 1, clip_id, 0),
+        ],
+    );
+}
+
+#[test]
+fn disable_frame_skipping() {
+    let mut ctx = Context::new();
+
+    ctx.app.world_mut().resource_mut::<FrameSkipping>().enabled = true;
+
+    let clip = Clip::from_frames([4, 5, 6, 7])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_skippable_frames([1, 2]);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+    ctx.check(4, [ctx.anim_start(animation_id)]);
+
+    // Disabling frame skipping applies to the next frames
+
+    ctx.app.world_mut().resource_mut::<FrameSkipping>().enabled = false;
+
+    ctx.run(300); // 350
+    ctx.check(7, []);
+
+    ctx.run(100); // 450
+    ctx.check(
+        4,
+        [
+            ctx.clip_rep_end(animation_id, clip_id, 0),
+            ctx.clip_end(animation_id, clip_id),
+            ctx.anim_rep_end(animation_id, 0),
+        ],
+    );
+
+    ctx.run(100); // 550
+    ctx.check(5, []);
+}
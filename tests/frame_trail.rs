@@ -0,0 +1,59 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn frame_trail() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([4, 5, 6, 7]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(FrameTrail::new(3));
+
+    let move_sprite = |ctx: &mut Context, x: f32| {
+        ctx.app
+            .world_mut()
+            .get_mut::<Transform>(ctx.sprite_entity)
+            .unwrap()
+            .translation
+            .x = x;
+    };
+
+    ctx.run(50);
+
+    move_sprite(&mut ctx, 10.0);
+    ctx.run(100);
+
+    move_sprite(&mut ctx, 20.0);
+    ctx.run(100);
+
+    move_sprite(&mut ctx, 30.0);
+    ctx.run(100);
+
+    // Only the last 3 frames are kept, with the positions where they were displayed
+
+    let trail = ctx
+        .app
+        .world()
+        .get::<FrameTrail>(ctx.sprite_entity)
+        .unwrap();
+
+    assert_eq!(
+        trail
+            .samples()
+            .map(|sample| (sample.atlas_index, sample.transform.translation.x))
+            .collect::<Vec<_>>(),
+        [(5, 10.0), (6, 20.0), (7, 30.0)]
+    );
+}
@@ -0,0 +1,156 @@
+pub mod context;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+fn atlas_index(ctx: &Context, entity: Entity) -> usize {
+    ctx.app
+        .world()
+        .get::<Sprite>(entity)
+        .unwrap()
+        .texture_atlas
+        .as_ref()
+        .unwrap()
+        .index
+}
+
+fn spawn_sprite(ctx: &mut Context, animation_id: AnimationId, group: HitPauseGroup) -> Entity {
+    ctx.app
+        .world_mut()
+        .spawn((
+            Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..default()
+            },
+            SpritesheetAnimation::from_id(animation_id),
+            group,
+        ))
+        .id()
+}
+
+#[test]
+fn entity_scope() {
+    let mut ctx = Context::new();
+
+    let marker_id = ctx.library().new_marker();
+
+    let clip = Clip::from_frames([0, 1, 2, 3])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(marker_id, 1);
+
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(HitPauseOnMarker::new(marker_id, Duration::from_millis(200)));
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    // The marker frame is held for the duration of the pause
+
+    ctx.run(100); // 150
+    ctx.check(1, [ctx.marker_hit(marker_id, animation_id, 0, clip_id, 0)]);
+
+    ctx.run(100); // 250
+    ctx.check(1, []);
+
+    ctx.run(100); // 350
+    ctx.check(1, []);
+
+    // The playback resumes as if the pause had been inserted at the marker frame
+
+    ctx.run(100); // 450
+    ctx.check(2, []);
+
+    ctx.run(100); // 550
+    ctx.check(3, []);
+}
+
+#[test]
+fn group_scope() {
+    let mut ctx = Context::new();
+
+    let marker_id = ctx.library().new_marker();
+
+    let attack_clip = Clip::from_frames([0, 1, 2, 3])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(marker_id, 1);
+
+    let attack_clip_id = ctx.library().register_clip(attack_clip);
+
+    let attack_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(attack_clip_id));
+
+    let idle_clip =
+        Clip::from_frames([10, 11, 12, 13]).with_duration(AnimationDuration::PerFrame(100));
+
+    let idle_clip_id = ctx.library().register_clip(idle_clip);
+
+    let idle_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(idle_clip_id));
+
+    let other_clip =
+        Clip::from_frames([20, 21, 22, 23]).with_duration(AnimationDuration::PerFrame(100));
+
+    let other_clip_id = ctx.library().register_clip(other_clip);
+
+    let other_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(other_clip_id));
+
+    // The attacker pauses its group, which contains a target but not the bystander
+
+    ctx.add_animation_to_sprite(attack_id);
+
+    ctx.app.world_mut().entity_mut(ctx.sprite_entity).insert((
+        HitPauseOnMarker::new(marker_id, Duration::from_millis(200))
+            .with_scope(HitPauseScope::Group(1)),
+        HitPauseGroup(1),
+    ));
+
+    let target = spawn_sprite(&mut ctx, idle_id, HitPauseGroup(1));
+    let bystander = spawn_sprite(&mut ctx, other_id, HitPauseGroup(2));
+
+    ctx.run(50);
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 0);
+    assert_eq!(atlas_index(&ctx, target), 10);
+    assert_eq!(atlas_index(&ctx, bystander), 20);
+
+    ctx.run(100); // 150
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 1);
+    assert_eq!(atlas_index(&ctx, target), 11);
+    assert_eq!(atlas_index(&ctx, bystander), 21);
+
+    ctx.run(100); // 250
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 1);
+    assert_eq!(atlas_index(&ctx, target), 11);
+    assert_eq!(atlas_index(&ctx, bystander), 22);
+
+    ctx.run(100); // 350
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 1);
+    assert_eq!(atlas_index(&ctx, target), 11);
+    assert_eq!(atlas_index(&ctx, bystander), 23);
+
+    ctx.run(100); // 450
+
+    assert_eq!(atlas_index(&ctx, ctx.sprite_entity), 2);
+    assert_eq!(atlas_index(&ctx, target), 12);
+    assert_eq!(atlas_index(&ctx, bystander), 20);
+}
@@ -0,0 +1,92 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn image_node_frame_size() {
+    let mut ctx = Context::new();
+
+    // A trimmed atlas with frames of different sizes
+
+    let mut layout = TextureAtlasLayout::new_empty(UVec2::new(64, 64));
+    layout.add_texture(URect::new(0, 0, 16, 32));
+    layout.add_texture(URect::new(16, 0, 40, 20));
+
+    let layout = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<TextureAtlasLayout>>()
+        .add(layout);
+
+    let clip = Clip::from_frames([0, 1]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    let entity = ctx
+        .app
+        .world_mut()
+        .spawn((
+            ImageNode::from_atlas_image(Handle::default(), TextureAtlas { layout, index: 0 }),
+            SpritesheetAnimation::from_id(animation_id),
+            ImageNodeFrameSize::from_scale(2.0),
+        ))
+        .id();
+
+    let node_size = |ctx: &Context| {
+        let node = ctx.app.world().get::<Node>(entity).unwrap();
+        (node.width, node.height)
+    };
+
+    ctx.run(50);
+
+    assert_eq!(node_size(&ctx), (Val::Px(32.0), Val::Px(64.0)));
+
+    ctx.run(100);
+
+    assert_eq!(node_size(&ctx), (Val::Px(48.0), Val::Px(40.0)));
+
+    ctx.run(100);
+
+    assert_eq!(node_size(&ctx), (Val::Px(32.0), Val::Px(64.0)));
+}
+
+#[test]
+fn image_node_frame_size_pixel_perfect() {
+    let mut ctx = Context::new();
+
+    let mut layout = TextureAtlasLayout::new_empty(UVec2::new(64, 64));
+    layout.add_texture(URect::new(0, 0, 16, 32));
+
+    let layout = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<TextureAtlasLayout>>()
+        .add(layout);
+
+    let clip_id = ctx.library().register_clip(Clip::from_frames([0]));
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    let entity = ctx
+        .app
+        .world_mut()
+        .spawn((
+            ImageNode::from_atlas_image(Handle::default(), TextureAtlas { layout, index: 0 }),
+            SpritesheetAnimation::from_id(animation_id),
+            ImageNodeFrameSize::from_scale(1.6).with_pixel_perfect(),
+        ))
+        .id();
+
+    ctx.run(50);
+
+    // The scale is rounded to cover whole pixels
+
+    let node = ctx.app.world().get::<Node>(entity).unwrap();
+
+    assert_eq!((node.width, node.height), (Val::Px(32.0), Val::Px(64.0)));
+}
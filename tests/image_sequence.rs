@@ -0,0 +1,97 @@
+pub mod context;
+
+use bevy::{
+    asset::LoadedFolder,
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+};
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn image_sequence() {
+    let mut ctx = Context::new();
+
+    // Frames of different sizes to tell them apart in the atlas
+
+    let frame_sizes = [2, 3, 4];
+
+    let frame_handles = frame_sizes.map(|size| {
+        ctx.app
+            .world_mut()
+            .resource_mut::<Assets<Image>>()
+            .add(Image::new_fill(
+                Extent3d {
+                    width: size,
+                    height: size,
+                    depth_or_array_layers: 1,
+                },
+                TextureDimension::D2,
+                &[255, 255, 255, 255],
+                TextureFormat::Rgba8UnormSrgb,
+                RenderAssetUsages::default(),
+            ))
+    });
+
+    let folder = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<LoadedFolder>>()
+        .add(LoadedFolder {
+            handles: frame_handles
+                .iter()
+                .map(|handle| handle.clone().untyped())
+                .collect(),
+        });
+
+    let entity = ctx
+        .app
+        .world_mut()
+        .spawn(ImageSequence::new(folder).with_duration(AnimationDuration::PerFrame(100)))
+        .id();
+
+    ctx.run(50);
+
+    // The entity plays the frames in order from an atlas
+
+    let animation_id = ctx
+        .app
+        .world()
+        .get::<SpritesheetAnimation>(entity)
+        .unwrap()
+        .animation_id;
+
+    let layout_handle = ctx
+        .app
+        .world()
+        .get::<Sprite>(entity)
+        .unwrap()
+        .texture_atlas
+        .as_ref()
+        .unwrap()
+        .layout
+        .clone();
+
+    let layout = ctx
+        .app
+        .world()
+        .resource::<Assets<TextureAtlasLayout>>()
+        .get(&layout_handle)
+        .unwrap()
+        .clone();
+
+    let frames: Vec<_> = ctx.library().animation_frames(animation_id).collect();
+
+    assert_eq!(frames.len(), frame_sizes.len());
+
+    for (frame, size) in frames.iter().zip(frame_sizes) {
+        assert_eq!(
+            layout.textures[frame.atlas_index].size(),
+            UVec2::splat(size)
+        );
+        assert_eq!(frame.duration.as_millis(), 100);
+    }
+}
@@ -0,0 +1,123 @@
+pub mod context;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn edit_playing_clip() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([0, 1, 2]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Loop);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    // Slow down the clip from a system
+
+    ctx.app
+        .world_mut()
+        .run_system_once(move |mut animation_commands: AnimationCommands| {
+            animation_commands.set_clip_duration(clip_id, AnimationDuration::PerFrame(300));
+        })
+        .unwrap();
+
+    assert_eq!(
+        ctx.library().get_clip(clip_id).duration(),
+        &Some(AnimationDuration::PerFrame(300))
+    );
+
+    // The current frame follows the new duration
+
+    ctx.run(100); // 150
+    ctx.check(0, []);
+
+    ctx.run(200); // 350
+    ctx.check(1, []);
+
+    ctx.run(300); // 650
+    ctx.check(2, []);
+}
+
+#[test]
+fn edit_animation_updates_deduplication() {
+    let mut library = AnimationLibrary::default();
+
+    let clip_id = library.register_clip(Clip::from_frames([1, 2, 3]));
+
+    let animation_id = library.register_animation(Animation::from_clip(clip_id));
+
+    library.edit_animation(animation_id, |animation| {
+        animation.set_repetitions(AnimationRepeat::Loop);
+    });
+
+    assert_eq!(
+        library.register_animation_deduplicated(
+            Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Loop)
+        ),
+        animation_id
+    );
+
+    assert_ne!(
+        library.register_animation_deduplicated(Animation::from_clip(clip_id)),
+        animation_id
+    );
+}
+
+#[test]
+fn edit_batch() {
+    let mut ctx = Context::new();
+
+    let clip1 = Clip::from_frames([0, 1]).with_duration(AnimationDuration::PerFrame(100));
+    let clip1_id = ctx.library().register_clip(clip1);
+
+    let clip2 = Clip::from_frames([2, 3]).with_duration(AnimationDuration::PerFrame(100));
+    let clip2_id = ctx.library().register_clip(clip2);
+
+    let animation =
+        Animation::from_clips([clip1_id, clip2_id]).with_repetitions(AnimationRepeat::Loop);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    // Edit both clips and the animation from a system, at once
+
+    ctx.app
+        .world_mut()
+        .run_system_once(move |mut animation_commands: AnimationCommands| {
+            animation_commands.edit_batch(move |edits| {
+                edits
+                    .edit_clip(clip1_id, |clip| {
+                        clip.set_duration(AnimationDuration::PerFrame(300));
+                    })
+                    .edit_clip(clip2_id, |clip| {
+                        clip.set_duration(AnimationDuration::PerFrame(300));
+                    })
+                    .edit_animation(animation_id, |animation| {
+                        animation.remove_clip(clip2_id);
+                    });
+            });
+        })
+        .unwrap();
+
+    let cache = ctx.library().animation_cache(animation_id);
+
+    assert_eq!(cache.frames.len(), 2);
+
+    // The current frame follows the new parameters
+
+    ctx.run(100); // 150
+    ctx.check(0, []);
+
+    ctx.run(200); // 350
+    ctx.check(1, []);
+}
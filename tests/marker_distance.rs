@@ -0,0 +1,55 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn markers_only_emitted_near_listeners() {
+    let mut ctx = Context::new();
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<AnimationEventSettings>()
+        .marker_max_distance = Some(100.0);
+
+    let marker_id = ctx.library().new_marker();
+
+    let clip = Clip::from_frames([0, 1, 2])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(marker_id, 1)
+        .with_marker(marker_id, 2);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    let place_listener = |ctx: &mut Context, entity: Entity, x: f32| {
+        ctx.app.world_mut().entity_mut(entity).insert((
+            Transform::from_xyz(x, 0.0, 0.0),
+            GlobalTransform::from_xyz(x, 0.0, 0.0),
+        ));
+    };
+
+    // A listener far from the sprite
+
+    let listener = ctx.app.world_mut().spawn(MarkerListener).id();
+
+    place_listener(&mut ctx, listener, 500.0);
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+
+    // The listener comes closer
+
+    place_listener(&mut ctx, listener, 50.0);
+
+    ctx.run(100); // 250
+    ctx.check(2, [ctx.marker_hit(marker_id, animation_id, 0, clip_id, 0)]);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "gamepad_rumble")]
+
+pub mod context;
+
+use std::time::Duration;
+
+use bevy::{input::gamepad::GamepadRumbleRequest, prelude::*};
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn rumble_on_markers() {
+    let mut ctx = Context::new();
+
+    let gamepad = ctx.app.world_mut().spawn_empty().id();
+
+    let impact_marker = ctx.library().new_marker();
+    let other_marker = ctx.library().new_marker();
+
+    let clip = Clip::from_frames([4, 5, 6])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(other_marker, 1)
+        .with_marker(impact_marker, 2);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.app.world_mut().entity_mut(ctx.sprite_entity).insert(
+        MarkerRumble::new(gamepad).with_rumble(
+            impact_marker,
+            RumblePattern::new(0.8, 0.4, Duration::from_millis(150)),
+        ),
+    );
+
+    let mut requests = ctx
+        .app
+        .world()
+        .resource::<Events<GamepadRumbleRequest>>()
+        .get_cursor();
+
+    let mut read_requests = |ctx: &Context| {
+        requests
+            .read(ctx.app.world().resource::<Events<GamepadRumbleRequest>>())
+            .map(|request| match request {
+                GamepadRumbleRequest::Add {
+                    gamepad,
+                    intensity,
+                    duration,
+                } => Some((
+                    *gamepad,
+                    intensity.strong_motor,
+                    intensity.weak_motor,
+                    *duration,
+                )),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Markers without patterns do not rumble
+
+    ctx.run(50);
+    ctx.run(100);
+
+    assert!(read_requests(&ctx).is_empty());
+
+    // The impact marker rumbles the gamepad
+
+    ctx.run(100);
+
+    assert_eq!(
+        read_requests(&ctx),
+        [Some((gamepad, 0.8, 0.4, Duration::from_millis(150)))]
+    );
+}
@@ -1,5 +1,7 @@
 pub mod context;
 
+use std::{collections::HashSet, time::Duration};
+
 use bevy_spritesheet_animation::prelude::*;
 use context::*;
 
@@ -31,7 +33,10 @@ fn markers_emit_events() {
     ctx.run(50);
     ctx.check(
         0,
-        [ctx.marker_hit(marker1_id, animation_id, 0, clip1_id, 0)],
+        [
+            ctx.anim_start(animation_id),
+            ctx.marker_hit(marker1_id, animation_id, 0, clip1_id, 0),
+        ],
     );
 
     ctx.run(100); // 150
@@ -81,3 +86,165 @@ fn markers_emit_events() {
         ],
     );
 }
+
+#[test]
+fn next_marker_within() {
+    let mut ctx = Context::new();
+
+    let marker_id = ctx.library().new_marker();
+
+    let clip = Clip::from_frames([0, 1, 2, 3])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(marker_id, 2);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    let next_marker = |ctx: &Context, within: u64| {
+        ctx.app
+            .world()
+            .resource::<Animator>()
+            .next_marker_within(ctx.sprite_entity, Duration::from_millis(within))
+            .map(|(marker_id, time_left)| (marker_id, time_left.as_millis()))
+    };
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    // The marker is hit in 150ms
+
+    assert_eq!(next_marker(&ctx, 100), None);
+    assert_eq!(next_marker(&ctx, 200), Some((marker_id, 150)));
+
+    // Twice as fast
+
+    ctx.update_sprite_animation(|animation| animation.speed_factor = 2.0);
+
+    ctx.run(0);
+
+    assert_eq!(next_marker(&ctx, 100), Some((marker_id, 75)));
+}
+
+#[test]
+fn marker_repetitions() {
+    let mut ctx = Context::new();
+
+    let every_other_id = ctx.library().new_marker();
+    let first_only_id = ctx.library().new_marker();
+
+    let clip = Clip::from_frames([0, 1])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(every_other_id, 0)
+        .with_marker(first_only_id, 1)
+        .with_marker_repetitions(
+            every_other_id,
+            MarkerRepetitions::Every {
+                period: 2,
+                offset: 1,
+            },
+        )
+        .with_marker_repetitions(first_only_id, MarkerRepetitions::Only(0));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    // Repetition 0
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    ctx.run(100); // 150
+    ctx.check(
+        1,
+        [ctx.marker_hit(first_only_id, animation_id, 0, clip_id, 0)],
+    );
+
+    // Repetition 1
+
+    ctx.run(100); // 250
+    ctx.check(
+        0,
+        [
+            ctx.marker_hit(every_other_id, animation_id, 1, clip_id, 0),
+            ctx.clip_rep_end(animation_id, clip_id, 0),
+            ctx.clip_end(animation_id, clip_id),
+            ctx.anim_rep_end(animation_id, 0),
+        ],
+    );
+
+    ctx.run(100); // 350
+    ctx.check(1, []);
+
+    // Repetition 2
+
+    ctx.run(100); // 450
+    ctx.check(
+        0,
+        [
+            ctx.clip_rep_end(animation_id, clip_id, 0),
+            ctx.clip_end(animation_id, clip_id),
+            ctx.anim_rep_end(animation_id, 1),
+        ],
+    );
+
+    ctx.run(100); // 550
+    ctx.check(1, []);
+
+    // Repetition 3
+
+    ctx.run(100); // 650
+    ctx.check(
+        0,
+        [
+            ctx.marker_hit(every_other_id, animation_id, 3, clip_id, 0),
+            ctx.clip_rep_end(animation_id, clip_id, 0),
+            ctx.clip_end(animation_id, clip_id),
+            ctx.anim_rep_end(animation_id, 2),
+        ],
+    );
+}
+
+#[test]
+fn marker_groups() {
+    let mut library = AnimationLibrary::default();
+
+    let footsteps = library.new_marker_group();
+    let impacts = library.new_marker_group();
+
+    assert_ne!(footsteps, impacts);
+
+    let walk_step = library.new_marker();
+    let run_step = library.new_marker();
+    let stomp = library.new_marker();
+
+    library.add_marker_to_group(walk_step, footsteps);
+    library.add_marker_to_group(run_step, footsteps);
+    library.add_marker_to_group(stomp, footsteps);
+    library.add_marker_to_group(stomp, impacts);
+
+    assert!(library.is_marker_in_group(walk_step, footsteps));
+    assert!(!library.is_marker_in_group(walk_step, impacts));
+    assert!(library.is_marker_in_group(stomp, impacts));
+
+    assert_eq!(
+        library.markers_in_group(footsteps),
+        HashSet::from([walk_step, run_step, stomp])
+    );
+
+    library.remove_marker_from_group(stomp, footsteps);
+
+    assert!(!library.is_marker_in_group(stomp, footsteps));
+    assert!(library.is_marker_in_group(stomp, impacts));
+    assert_eq!(
+        library.markers_in_group(footsteps),
+        HashSet::from([walk_step, run_step])
+    );
+}
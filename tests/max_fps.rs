@@ -0,0 +1,34 @@
+pub mod context;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn max_fps_quantizes_updates() {
+    let mut ctx = Context::new();
+
+    let clip =
+        Clip::from_frames([0, 1, 2, 3, 4, 5, 6, 7]).with_duration(AnimationDuration::PerFrame(40));
+    let clip_id = ctx.library().register_clip(clip);
+
+    // Only updates every 100 ms
+
+    let animation = Animation::from_clip(clip_id).with_max_fps(10);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(60);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    // 100 ms are applied at once
+
+    ctx.run(60); // 120
+    ctx.check(2, []);
+
+    ctx.run(30); // 150
+    ctx.check(2, []);
+
+    ctx.run(60); // 210
+    ctx.check(4, []);
+}
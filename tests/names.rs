@@ -239,3 +239,107 @@ fn markers() {
 
     assert_eq!(ctx.library().marker_names().len(), 1);
 }
+
+#[test]
+fn clip_names_in_animations() {
+    let mut ctx = Context::new();
+
+    let aim_id = ctx.library().register_clip(Clip::from_frames([0, 1]));
+    let shoot_id = ctx.library().register_clip(Clip::from_frames([2, 3]));
+
+    let mut animation = Animation::from_clips([aim_id, shoot_id])
+        .with_clip_name(aim_id, "aim")
+        .with_clip_name(shoot_id, "fire");
+
+    assert_eq!(animation.clip_named("aim"), Some(aim_id));
+    assert_eq!(animation.clip_named("fire"), Some(shoot_id));
+    assert_eq!(animation.clip_name(shoot_id), Some("fire"));
+
+    // Rename a clip
+
+    animation.set_clip_name(shoot_id, "shoot");
+
+    assert_eq!(animation.clip_named("fire"), None);
+    assert_eq!(animation.clip_named("shoot"), Some(shoot_id));
+
+    // Removed clips lose their names
+
+    animation.remove_clip(aim_id);
+
+    assert_eq!(animation.clip_named("aim"), None);
+
+    // The names are local to the animation
+
+    let animation_id = ctx.library().register_animation(animation);
+
+    assert_eq!(
+        ctx.library()
+            .get_animation(animation_id)
+            .clip_named("shoot"),
+        Some(shoot_id)
+    );
+    assert_eq!(ctx.library().clip_with_name("shoot"), None);
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn named_clip_ids_are_stable() {
+    // Changing this ID breaks the events and saves that stored it
+
+    assert_eq!(
+        ClipId::from_name("walk").to_string(),
+        "clip16030206589648374320"
+    );
+}
+
+#[test]
+fn named_clip_ids() {
+    let mut ctx = Context::new();
+
+    let walk_id = ctx
+        .library()
+        .register_named_clip("walk", Clip::from_frames([0, 1, 2]))
+        .unwrap();
+
+    assert_eq!(walk_id, ClipId::from_name("walk"));
+    assert_eq!(ctx.library().clip_with_name("walk"), Some(walk_id));
+
+    // Named clips do not shift sequential IDs
+
+    let other_id = ctx.library().register_clip(Clip::from_frames([3]));
+
+    let mut other_library = AnimationLibrary::default();
+    assert_eq!(
+        other_library.register_clip(Clip::from_frames([3])),
+        other_id
+    );
+
+    // Registering the name again replaces the clip, as when reloading
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(walk_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    let reloaded_walk_id = ctx
+        .library()
+        .register_named_clip("walk", Clip::from_frames([5, 6]))
+        .unwrap();
+
+    assert_eq!(reloaded_walk_id, walk_id);
+    assert_eq!(ctx.library().get_clip(walk_id).frames(), &[5, 6]);
+
+    ctx.run(50);
+    ctx.check(5, [ctx.anim_start(animation_id)]);
+
+    // Names given with name_clip are still unique
+
+    ctx.library().name_clip(other_id, "run").unwrap();
+
+    assert!(matches!(
+        ctx.library()
+            .register_named_clip("run", Clip::from_frames([])),
+        Err(LibraryError::NameAlreadyTaken)
+    ));
+}
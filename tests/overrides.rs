@@ -0,0 +1,107 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn overrides() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([0, 1, 2]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.app.world_mut().entity_mut(ctx.sprite_entity).insert(
+        AnimationOverrides::default()
+            .with_duration(AnimationDuration::PerFrame(50))
+            .with_repetitions(AnimationRepeat::Times(1))
+            .with_direction(AnimationDirection::Backwards),
+    );
+
+    // Another entity plays the same animation without the overrides
+
+    let other_entity = ctx
+        .app
+        .world_mut()
+        .spawn((
+            Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..default()
+            },
+            SpritesheetAnimation::from_id(animation_id),
+        ))
+        .id();
+
+    let other_atlas_index = |ctx: &Context| {
+        ctx.app
+            .world()
+            .get::<Sprite>(other_entity)
+            .unwrap()
+            .texture_atlas
+            .as_ref()
+            .unwrap()
+            .index
+    };
+
+    ctx.run(25);
+    ctx.check(2, [ctx.anim_start(animation_id)]);
+    assert_eq!(other_atlas_index(&ctx), 0);
+
+    ctx.run(50); // 75
+    ctx.check(1, []);
+
+    ctx.run(50); // 125
+    ctx.check(0, []);
+    assert_eq!(other_atlas_index(&ctx), 1);
+
+    ctx.run(50); // 175
+    ctx.check(
+        0,
+        [
+            ctx.clip_rep_end(animation_id, clip_id, 0),
+            ctx.clip_end(animation_id, clip_id),
+            ctx.anim_rep_end(animation_id, 0),
+            ctx.anim_end(animation_id),
+        ],
+    );
+    assert_eq!(other_atlas_index(&ctx), 1);
+}
+
+#[test]
+fn changing_overrides_continues_from_the_current_frame() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([0, 1, 2, 3]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+
+    // Slow down the animation
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(AnimationOverrides::default().with_duration(AnimationDuration::PerFrame(200)));
+
+    ctx.run(100); // 250
+    ctx.check(1, []);
+
+    ctx.run(100); // 350
+    ctx.check(2, []);
+}
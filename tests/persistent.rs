@@ -0,0 +1,91 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn progress_preserved_across_respawn() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([0, 1, 2, 3]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(PersistentAnimationKey("torch".to_string()));
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    ctx.run(200); // 250
+    ctx.check(2, []);
+
+    // Despawn the sprite
+
+    ctx.app.world_mut().despawn(ctx.sprite_entity);
+
+    ctx.run(1000);
+
+    // Respawn it: the animation resumes where it was
+
+    ctx.sprite_entity = ctx
+        .app
+        .world_mut()
+        .spawn((
+            Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..default()
+            },
+            SpritesheetAnimation::from_id(animation_id),
+            PersistentAnimationKey("torch".to_string()),
+        ))
+        .id();
+
+    ctx.run(10); // 260
+    ctx.check(2, []);
+
+    ctx.run(50); // 310
+    ctx.check(3, []);
+}
+
+#[test]
+fn progress_not_preserved_without_key() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([0, 1, 2, 3]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(250);
+    ctx.check(2, [ctx.anim_start(animation_id)]);
+
+    ctx.app.world_mut().despawn(ctx.sprite_entity);
+
+    ctx.run(10);
+
+    ctx.sprite_entity = ctx
+        .app
+        .world_mut()
+        .spawn((
+            Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..default()
+            },
+            SpritesheetAnimation::from_id(animation_id),
+        ))
+        .id();
+
+    ctx.run(10);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+}
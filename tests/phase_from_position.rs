@@ -0,0 +1,104 @@
+pub mod context;
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn phases_from_positions() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames(0..16).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Loop);
+    let animation_id = ctx.library().register_animation(animation);
+
+    let sprite = ctx
+        .app
+        .world()
+        .get::<Sprite>(ctx.sprite_entity)
+        .unwrap()
+        .clone();
+
+    let spawn_tile = |ctx: &mut Context, x: f32, animation: SpritesheetAnimation| {
+        ctx.app
+            .world_mut()
+            .spawn((
+                sprite.clone(),
+                animation,
+                Transform::from_xyz(x, 0.0, 0.0),
+                PhaseFromPosition { scale: 1.0 / 16.0 },
+            ))
+            .id()
+    };
+
+    let tiles: Vec<Entity> = (0..10)
+        .map(|x| {
+            spawn_tile(
+                &mut ctx,
+                x as f32 * 16.0,
+                SpritesheetAnimation::from_id(animation_id),
+            )
+        })
+        .collect();
+
+    // In the same cell as the first tile
+    let same_cell_tile = spawn_tile(&mut ctx, 8.0, SpritesheetAnimation::from_id(animation_id));
+
+    // With an explicit starting progress
+    let explicit_tile = spawn_tile(
+        &mut ctx,
+        16.0,
+        SpritesheetAnimation {
+            progress: AnimationProgress {
+                frame: 3,
+                repetition: 0,
+            },
+            ..SpritesheetAnimation::from_id(animation_id)
+        },
+    );
+
+    // In the same cell as the fourth tile, through its parent
+
+    let chunk = ctx
+        .app
+        .world_mut()
+        .spawn(Transform::from_xyz(40.0, 0.0, 0.0))
+        .id();
+
+    let child_tile = spawn_tile(&mut ctx, 8.0, SpritesheetAnimation::from_id(animation_id));
+
+    ctx.app.world_mut().entity_mut(child_tile).set_parent(chunk);
+
+    ctx.run(50);
+
+    let atlas_index = |ctx: &Context, entity: Entity| {
+        ctx.app
+            .world()
+            .get::<Sprite>(entity)
+            .unwrap()
+            .texture_atlas
+            .as_ref()
+            .unwrap()
+            .index
+    };
+
+    let tile_frames: HashSet<usize> = tiles
+        .iter()
+        .map(|entity| atlas_index(&ctx, *entity))
+        .collect();
+
+    assert!(tile_frames.len() > 1);
+
+    assert_eq!(
+        atlas_index(&ctx, same_cell_tile),
+        atlas_index(&ctx, tiles[0])
+    );
+
+    assert_eq!(atlas_index(&ctx, child_tile), atlas_index(&ctx, tiles[3]));
+
+    assert_eq!(atlas_index(&ctx, explicit_tile), 3);
+}
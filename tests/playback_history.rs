@@ -0,0 +1,61 @@
+pub mod context;
+
+use std::time::Duration;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn record_playback_history() {
+    let mut ctx = Context::new();
+
+    let marker_id = ctx.library().new_marker();
+
+    let clip = Clip::from_frames([4, 5, 6, 7])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(marker_id, 2);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(PlaybackHistory::new(Duration::from_millis(250)));
+
+    ctx.run(50);
+    ctx.run(100); // 150
+    ctx.run(100); // 250
+    ctx.run(100); // 350
+
+    // Only the frames of the last 250 ms are kept
+
+    let history = ctx
+        .app
+        .world()
+        .get::<PlaybackHistory>(ctx.sprite_entity)
+        .unwrap();
+
+    let records: Vec<_> = history.records().collect();
+
+    assert_eq!(
+        records
+            .iter()
+            .map(|record| record.atlas_index)
+            .collect::<Vec<_>>(),
+        [5, 6, 7]
+    );
+
+    assert_eq!(
+        records[2].time - records[0].time,
+        Duration::from_millis(200)
+    );
+
+    assert_eq!(
+        records[1].events,
+        [ctx.marker_hit(marker_id, animation_id, 0, clip_id, 0)]
+    );
+}
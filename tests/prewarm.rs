@@ -0,0 +1,79 @@
+#![cfg(feature = "animation_prewarm")]
+
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+enum GameState {
+    #[default]
+    Loading,
+    Playing,
+}
+
+#[test]
+fn prewarm_during_loading_state() {
+    let mut ctx = Context::new();
+
+    ctx.app
+        .add_plugins(AnimationPrewarmPlugin {
+            caches_per_frame: 2,
+            ..AnimationPrewarmPlugin::new(GameState::Loading)
+        })
+        .init_state::<GameState>();
+
+    // The caches are not built on registration anymore
+
+    let clip_id = ctx.library().register_clip(Clip::from_frames([0, 1, 2]));
+
+    for repetitions in 1..=3 {
+        ctx.library().register_animation(
+            Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(repetitions)),
+        );
+    }
+
+    assert_eq!(ctx.library().cache_memory_size(), 0);
+
+    let progress = |ctx: &Context| *ctx.app.world().resource::<AnimationPrewarmProgress>();
+
+    // Two caches per frame
+
+    ctx.run(0);
+
+    assert_eq!(progress(&ctx).ready, 2);
+    assert_eq!(progress(&ctx).total, 3);
+    assert!(!progress(&ctx).is_done());
+
+    ctx.run(0);
+
+    assert!(progress(&ctx).is_done());
+    assert_eq!(progress(&ctx).progress(), 1.0);
+
+    // Once out of the loading state, caches are built when played
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Playing);
+
+    ctx.run(0);
+
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.run(0);
+
+    assert_eq!(progress(&ctx).total, 3);
+
+    let memory_size = ctx.library().cache_memory_size();
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    assert!(ctx.library().cache_memory_size() > memory_size);
+}
@@ -1,5 +1,8 @@
 pub mod context;
 
+use std::time::Duration;
+
+use bevy::prelude::Mut;
 use bevy_spritesheet_animation::prelude::*;
 use context::*;
 
@@ -16,7 +19,7 @@ fn manual_control() {
     ctx.add_animation_to_sprite(animation_id);
 
     ctx.run(800);
-    ctx.check(4, []);
+    ctx.check(4, [ctx.anim_start(animation_id)]);
 
     ctx.run(400); // 1200, switched to the next frame
     ctx.check(5, []);
@@ -55,7 +58,7 @@ fn manual_control_while_paused() {
     ctx.add_animation_to_sprite(animation_id);
 
     ctx.run(500);
-    ctx.check(4, []);
+    ctx.check(4, [ctx.anim_start(animation_id)]);
 
     // Pause
 
@@ -222,3 +225,170 @@ fn manual_control_invalid_repetition() {
         assert_eq!(sprite.progress.repetition, 0);
     });
 }
+
+#[test]
+fn set_progress() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([4, 5, 6]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(2));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+    ctx.check(4, [ctx.anim_start(animation_id)]);
+
+    let sprite_entity = ctx.sprite_entity;
+
+    ctx.app
+        .world_mut()
+        .resource_scope(|world, library: Mut<AnimationLibrary>| {
+            let mut sprite_animation = world
+                .get_mut::<SpritesheetAnimation>(sprite_entity)
+                .unwrap();
+
+            // Invalid seeks are reported and ignored
+
+            assert_eq!(
+                sprite_animation.set_progress(
+                    AnimationProgress {
+                        frame: 3,
+                        repetition: 0
+                    },
+                    &library
+                ),
+                Err(ProgressError::InvalidFrame {
+                    frame: 3,
+                    frame_count: 3
+                })
+            );
+
+            assert_eq!(
+                sprite_animation.set_progress(
+                    AnimationProgress {
+                        frame: 0,
+                        repetition: 2
+                    },
+                    &library
+                ),
+                Err(ProgressError::InvalidRepetition {
+                    repetition: 2,
+                    repetitions: 2
+                })
+            );
+
+            assert_eq!(sprite_animation.progress, AnimationProgress::default());
+
+            // Valid seeks update the progress
+
+            assert_eq!(
+                sprite_animation.set_progress(
+                    AnimationProgress {
+                        frame: 2,
+                        repetition: 1
+                    },
+                    &library
+                ),
+                Ok(())
+            );
+        });
+
+    ctx.run(10);
+    ctx.check(6, []);
+}
+
+#[test]
+fn seek_and_reset() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([4, 5, 6]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id)
+        .with_direction(AnimationDirection::Backwards)
+        .with_repetitions(AnimationRepeat::Times(1));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+    ctx.check(6, [ctx.anim_start(animation_id)]);
+
+    let sprite_entity = ctx.sprite_entity;
+
+    let seek = |ctx: &mut Context, f: &dyn Fn(&mut SpritesheetAnimation, &AnimationLibrary)| {
+        ctx.app
+            .world_mut()
+            .resource_scope(|world, library: Mut<AnimationLibrary>| {
+                let mut sprite_animation = world
+                    .get_mut::<SpritesheetAnimation>(sprite_entity)
+                    .unwrap();
+
+                f(&mut sprite_animation, &library);
+            });
+    };
+
+    // Frames are indexed in playing order
+
+    seek(&mut ctx, &|sprite_animation, library| {
+        assert_eq!(
+            sprite_animation.seek_to_frame(3, library),
+            Err(ProgressError::InvalidFrame {
+                frame: 3,
+                frame_count: 3
+            })
+        );
+
+        assert_eq!(sprite_animation.seek_to_frame(2, library), Ok(()));
+    });
+
+    ctx.run(10);
+    ctx.check(4, []);
+
+    ctx.run(150);
+    ctx.check(
+        4,
+        [
+            ctx.clip_rep_end(animation_id, clip_id, 0),
+            ctx.clip_end(animation_id, clip_id),
+            ctx.anim_rep_end(animation_id, 0),
+            ctx.anim_end(animation_id),
+        ],
+    );
+
+    // Seeking after the end replays the animation
+
+    seek(&mut ctx, &|sprite_animation, library| {
+        assert_eq!(
+            sprite_animation.seek_to_time(Duration::from_millis(150), library),
+            Ok(())
+        );
+
+        assert_eq!(
+            sprite_animation.progress,
+            AnimationProgress {
+                frame: 1,
+                repetition: 0
+            }
+        );
+    });
+
+    ctx.run(10);
+    ctx.check(5, []);
+
+    ctx.run(150);
+    ctx.check(4, []);
+
+    // Resetting restarts from the first frame
+
+    ctx.update_sprite_animation(|sprite_animation| sprite_animation.reset());
+
+    ctx.run(10);
+    ctx.check(6, []);
+
+    ctx.run(150);
+    ctx.check(5, []);
+}
@@ -0,0 +1,92 @@
+pub mod context;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn queued_animations_play_in_order() {
+    let mut ctx = Context::new();
+
+    let once = |ctx: &mut Context, frames: [usize; 2]| {
+        let clip = Clip::from_frames(frames).with_duration(AnimationDuration::PerFrame(100));
+        let clip_id = ctx.library().register_clip(clip);
+
+        let animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(1));
+        let animation_id = ctx.library().register_animation(animation);
+
+        (animation_id, clip_id)
+    };
+
+    let (attack_id, attack_clip_id) = once(&mut ctx, [0, 1]);
+    let (recoil_id, recoil_clip_id) = once(&mut ctx, [2, 3]);
+
+    let idle_clip_id = ctx.library().register_clip(Clip::from_frames([5, 6]));
+    let idle_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(idle_clip_id));
+
+    ctx.add_animation_to_sprite(attack_id);
+
+    ctx.update_sprite_animation(|animation| {
+        animation.queue(recoil_id);
+        animation.queue(idle_id);
+    });
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(attack_id)]);
+
+    ctx.run(100); // 150
+    ctx.check(1, []);
+
+    ctx.run(100); // 250
+    ctx.check(
+        1,
+        [
+            ctx.clip_rep_end(attack_id, attack_clip_id, 0),
+            ctx.clip_end(attack_id, attack_clip_id),
+            ctx.anim_rep_end(attack_id, 0),
+            ctx.anim_end(attack_id),
+        ],
+    );
+
+    // The first queued animation starts
+
+    ctx.run(10); // 260
+    ctx.check(2, [ctx.anim_start(recoil_id)]);
+
+    ctx.get_sprite(|animation| {
+        assert_eq!(animation.animation_id, recoil_id);
+        assert_eq!(
+            animation
+                .queued_animations
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![idle_id]
+        );
+    });
+
+    ctx.run(100); // 360
+    ctx.check(3, []);
+
+    ctx.run(100); // 460
+    ctx.check(
+        3,
+        [
+            ctx.clip_rep_end(recoil_id, recoil_clip_id, 0),
+            ctx.clip_end(recoil_id, recoil_clip_id),
+            ctx.anim_rep_end(recoil_id, 0),
+            ctx.anim_end(recoil_id),
+        ],
+    );
+
+    // The last queued animation starts
+
+    ctx.run(10); // 470
+    ctx.check(5, [ctx.anim_start(idle_id)]);
+
+    ctx.get_sprite(|animation| {
+        assert_eq!(animation.animation_id, idle_id);
+        assert!(animation.queued_animations.is_empty());
+    });
+}
@@ -38,7 +38,7 @@ fn clip_once() {
     ctx.add_animation_to_sprite(animation_id);
 
     ctx.run(50);
-    ctx.check(0, []);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
 
     ctx.run(100);
     ctx.check(1, []);
@@ -79,7 +79,7 @@ fn clip_many() {
     // 9 repetitions
 
     ctx.run(50);
-    ctx.check(0, []);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
 
     for i in 0..9 {
         ctx.run(100);
@@ -139,7 +139,7 @@ fn some_clips_repeated_zero_times() {
     ctx.add_animation_to_sprite(animation_id);
 
     ctx.run(100);
-    ctx.check(9, []);
+    ctx.check(9, [ctx.anim_start(animation_id)]);
 
     ctx.run(100);
     ctx.check(8, []);
@@ -201,7 +201,7 @@ fn animation_once() {
     ctx.add_animation_to_sprite(animation_id);
 
     ctx.run(50);
-    ctx.check(0, []);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
 
     ctx.run(100);
     ctx.check(1, []);
@@ -242,7 +242,7 @@ fn animation_many() {
     // 9 repetitions
 
     ctx.run(50);
-    ctx.check(0, []);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
 
     for i in 0..9 {
         ctx.run(100);
@@ -298,7 +298,7 @@ fn animation_forever() {
     ctx.add_animation_to_sprite(animation_id);
 
     ctx.run(50);
-    ctx.check(0, []);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
 
     for i in 0..1000 {
         ctx.run(100); // 100 * i + 50
@@ -0,0 +1,275 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+};
+
+use bevy_spritesheet_animation::prelude::*;
+
+#[test]
+fn sample_frames() {
+    let mut library = AnimationLibrary::default();
+
+    let clip = Clip::from_frames([3, 4, 5]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = library.register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(2));
+    let animation_id = library.register_animation(animation);
+
+    let sample = |time: u64| {
+        library
+            .sample_animation(animation_id, None, Duration::from_millis(time))
+            .unwrap()
+    };
+
+    assert_eq!(sample(0).atlas_index, 3);
+    assert_eq!(sample(99).atlas_index, 3);
+    assert_eq!(sample(100).atlas_index, 4);
+    assert_eq!(sample(250).atlas_index, 5);
+
+    // Second repetition
+
+    let frame = sample(320);
+
+    assert_eq!(frame.atlas_index, 3);
+    assert_eq!(frame.clip_id, clip_id);
+    assert_eq!(
+        frame.progress,
+        AnimationProgress {
+            frame: 0,
+            repetition: 1
+        }
+    );
+
+    // The last frame is held after the end
+
+    assert_eq!(sample(10000).atlas_index, 5);
+}
+
+#[test]
+fn sample_markers() {
+    let mut library = AnimationLibrary::default();
+
+    let marker1 = library.new_marker();
+    let marker2 = library.new_marker();
+
+    let clip = Clip::from_frames([0, 1, 2, 3])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(marker1, 0)
+        .with_marker(marker2, 2);
+    let clip_id = library.register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Loop);
+    let animation_id = library.register_animation(animation);
+
+    let markers = |previous_time: Option<u64>, time: u64| {
+        library
+            .sample_animation(
+                animation_id,
+                previous_time.map(Duration::from_millis),
+                Duration::from_millis(time),
+            )
+            .unwrap()
+            .markers
+    };
+
+    // The markers of the first frame are reached at the start
+
+    assert_eq!(markers(None, 0), vec![marker1]);
+    assert!(markers(Some(0), 150).is_empty());
+
+    // The range excludes its start and includes its end
+
+    assert_eq!(markers(Some(150), 200), vec![marker2]);
+    assert!(markers(Some(200), 350).is_empty());
+
+    // Wrapping around the loop
+
+    assert_eq!(markers(Some(350), 650), vec![marker1, marker2]);
+    assert_eq!(markers(None, 650), vec![marker1, marker2, marker1, marker2]);
+}
+
+#[test]
+fn sample_empty_animation() {
+    let mut library = AnimationLibrary::default();
+
+    let clip_id = library.register_clip(Clip::from_frames([]));
+
+    let animation_id = library.register_animation(Animation::from_clip(clip_id));
+
+    assert_eq!(
+        library.sample_animation(animation_id, None, Duration::ZERO),
+        None
+    );
+}
+
+#[test]
+fn sample_from_progress() {
+    let mut library = AnimationLibrary::default();
+
+    let marker = library.new_marker();
+
+    let clip = Clip::from_frames([0, 1, 2])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(marker, 0);
+    let clip_id = library.register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Loop);
+    let animation_id = library.register_animation(animation);
+
+    let sample = |frame: usize, repetition: usize, elapsed: u64| {
+        library
+            .sample_animation_from(
+                animation_id,
+                AnimationProgress { frame, repetition },
+                Duration::from_millis(elapsed),
+            )
+            .unwrap()
+            .unwrap()
+    };
+
+    // The markers of the starting frame are not reached again
+
+    let frame = sample(0, 0, 50);
+
+    assert_eq!(frame.atlas_index, 0);
+    assert!(frame.markers.is_empty());
+
+    // Wrapping to the next repetition
+
+    let frame = sample(2, 3, 220);
+
+    assert_eq!(frame.atlas_index, 1);
+    assert_eq!(
+        frame.progress,
+        AnimationProgress {
+            frame: 1,
+            repetition: 4
+        }
+    );
+    assert_eq!(frame.markers, vec![marker]);
+
+    // Invalid progress
+
+    assert!(library
+        .sample_animation_from(
+            animation_id,
+            AnimationProgress {
+                frame: 3,
+                repetition: 0
+            },
+            Duration::ZERO
+        )
+        .is_err());
+}
+
+#[test]
+fn list_frames() {
+    let mut library = AnimationLibrary::default();
+
+    let clip1 = Clip::from_frames([0, 1]).with_duration(AnimationDuration::PerFrame(100));
+    let clip1_id = library.register_clip(clip1);
+
+    let clip2 = Clip::from_frames([5, 6])
+        .with_duration(AnimationDuration::PerFrame(50))
+        .with_direction(AnimationDirection::Backwards)
+        .with_repetitions(2);
+    let clip2_id = library.register_clip(clip2);
+
+    let animation = Animation::from_clips([clip1_id, clip2_id]);
+    let animation_id = library.register_animation(animation);
+
+    let frames = library
+        .animation_frames(animation_id)
+        .map(|frame| {
+            (
+                frame.clip_id,
+                frame.frame_index,
+                frame.atlas_index,
+                frame.duration.as_millis(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        frames,
+        vec![
+            (clip1_id, 0, 0, 100),
+            (clip1_id, 1, 1, 100),
+            (clip2_id, 2, 6, 50),
+            (clip2_id, 3, 5, 50),
+            (clip2_id, 4, 6, 50),
+            (clip2_id, 5, 5, 50),
+        ]
+    );
+}
+
+#[test]
+fn frame_hashes() {
+    let mut library = AnimationLibrary::default();
+
+    // Three 2x2 frames, the first and last ones with the same pixels
+
+    let pixels: Vec<u8> = [[1, 2, 9, 9, 1, 2], [3, 4, 9, 8, 3, 4]]
+        .iter()
+        .flatten()
+        .flat_map(|value| [*value, 0, 0, 255])
+        .collect();
+
+    let image = Image::new(
+        Extent3d {
+            width: 6,
+            height: 2,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        pixels,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+
+    let atlas_layout = Spritesheet::new(3, 1).atlas_layout(2, 2);
+
+    let clip = Clip::from_frames([0, 1, 2, 7]);
+    let clip_id = library.register_clip(clip);
+
+    let animation_id = library.register_animation(Animation::from_clip(clip_id));
+
+    let hashes = library.animation_frame_hashes(animation_id, &image, &atlas_layout);
+
+    assert_eq!(hashes.len(), 4);
+    assert!(hashes[0].is_some());
+    assert_ne!(hashes[0], hashes[1]);
+    assert_eq!(hashes[0], hashes[2]);
+
+    // Out of the atlas layout
+
+    assert_eq!(hashes[3], None);
+
+    // Only the first mip level is hashed
+
+    let mut mipmapped_image = image.clone();
+
+    mipmapped_image.texture_descriptor.mip_level_count = 2;
+    mipmapped_image.data.extend([7; 3 * 4]);
+
+    assert_eq!(
+        library.animation_frame_hashes(animation_id, &mipmapped_image, &atlas_layout),
+        hashes
+    );
+
+    // Compressed images cannot be hashed
+
+    let mut compressed_image = image.clone();
+
+    compressed_image.texture_descriptor.format = TextureFormat::Bc1RgbaUnorm;
+
+    assert_eq!(
+        library.animation_frame_hashes(animation_id, &compressed_image, &atlas_layout),
+        [None; 4]
+    );
+}
@@ -0,0 +1,91 @@
+pub mod context;
+
+use bevy::{prelude::*, ui::RelativeCursorPosition};
+use bevy_spritesheet_animation::{
+    components::animation_scrubber::{ScrubberClipBoundary, ScrubberFrame, ScrubberMarker},
+    prelude::*,
+};
+use context::*;
+
+fn count<T: Component>(ctx: &mut Context) -> usize {
+    ctx.app
+        .world_mut()
+        .query_filtered::<(), With<T>>()
+        .iter(ctx.app.world())
+        .count()
+}
+
+fn frame_position(ctx: &mut Context) -> Val {
+    ctx.app
+        .world_mut()
+        .query_filtered::<&Node, With<ScrubberFrame>>()
+        .single(ctx.app.world())
+        .left
+}
+
+#[test]
+fn scrubber() {
+    let mut ctx = Context::new();
+
+    let marker_id = ctx.library().new_marker();
+
+    let clip1 = Clip::from_frames([4, 5, 6])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(marker_id, 1);
+    let clip1_id = ctx.library().register_clip(clip1);
+
+    let clip2 = Clip::from_frames([7, 8]).with_duration(AnimationDuration::PerFrame(100));
+    let clip2_id = ctx.library().register_clip(clip2);
+
+    let animation = Animation::from_clips([clip1_id, clip2_id]);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    let scrubber = ctx
+        .app
+        .world_mut()
+        .spawn(AnimationScrubber::new(ctx.sprite_entity))
+        .id();
+
+    ctx.run(50);
+
+    // The scrubber shows the structure of the animation
+
+    assert_eq!(
+        ctx.app
+            .world()
+            .get::<AnimationScrubber>(scrubber)
+            .unwrap()
+            .frame_count(),
+        Some(5)
+    );
+
+    assert_eq!(count::<ScrubberFrame>(&mut ctx), 1);
+    assert_eq!(count::<ScrubberClipBoundary>(&mut ctx), 1);
+    assert_eq!(count::<ScrubberMarker>(&mut ctx), 1);
+
+    assert_eq!(frame_position(&mut ctx), Val::Percent(0.0));
+
+    // The scrubber follows the animation
+
+    ctx.run(100);
+    ctx.check(5, [ctx.marker_hit(marker_id, animation_id, 0, clip1_id, 0)]);
+
+    assert_eq!(frame_position(&mut ctx), Val::Percent(20.0));
+
+    // Pressing the scrubber seeks the animation
+
+    ctx.app.world_mut().entity_mut(scrubber).insert((
+        Interaction::Pressed,
+        RelativeCursorPosition {
+            normalized_visible_node_rect: Rect::new(0.0, 0.0, 1.0, 1.0),
+            normalized: Some(Vec2::new(0.9, 0.5)),
+        },
+    ));
+
+    ctx.run(10);
+    ctx.check(8, []);
+
+    assert_eq!(frame_position(&mut ctx), Val::Percent(80.0));
+}
@@ -0,0 +1,73 @@
+pub mod context;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn shake_on_marker() {
+    let mut ctx = Context::new();
+
+    let impact_marker = ctx.library().new_marker();
+    let other_marker = ctx.library().new_marker();
+
+    let clip = Clip::from_frames([4, 5, 6])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(other_marker, 1)
+        .with_marker(impact_marker, 2);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.app
+        .world_mut()
+        .entity_mut(ctx.sprite_entity)
+        .insert(ShakeOnMarker::new(
+            impact_marker,
+            6.0,
+            Duration::from_millis(200),
+        ));
+
+    let mut requests = ctx
+        .app
+        .world()
+        .resource::<Events<CameraShakeRequest>>()
+        .get_cursor();
+
+    let mut read_requests = |ctx: &Context| {
+        requests
+            .read(ctx.app.world().resource::<Events<CameraShakeRequest>>())
+            .copied()
+            .collect::<Vec<_>>()
+    };
+
+    // Other markers do not shake the camera
+
+    ctx.run(50);
+    ctx.run(100);
+
+    assert!(read_requests(&ctx).is_empty());
+
+    // The shake is requested when the impact frame is displayed
+
+    ctx.run(100);
+
+    ctx.check(
+        6,
+        [ctx.marker_hit(impact_marker, animation_id, 0, clip_id, 0)],
+    );
+
+    assert_eq!(
+        read_requests(&ctx),
+        [CameraShakeRequest {
+            entity: ctx.sprite_entity,
+            amplitude: 6.0,
+            duration: Duration::from_millis(200),
+        }]
+    );
+}
@@ -0,0 +1,585 @@
+pub mod context;
+
+use bevy::{
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        view::NoFrustumCulling,
+    },
+};
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn placeholder() {
+    let mut ctx = Context::new();
+
+    // An image that is not loaded yet
+
+    let image: Handle<Image> = Handle::weak_from_u128(0x5917_e3d0_1a2b_4c5d_8e9f_0123_4567_89ab);
+
+    let layout = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<TextureAtlasLayout>>()
+        .add(TextureAtlasLayout::from_grid(
+            UVec2::new(96, 96),
+            8,
+            8,
+            None,
+            None,
+        ));
+
+    let placeholder_color = Color::srgb(1.0, 0.0, 0.0);
+
+    let entity = ctx
+        .app
+        .world_mut()
+        .spawn(
+            Sprite3d::from_atlas_image(image.clone(), TextureAtlas { layout, index: 0 })
+                .with_placeholder(Sprite3dPlaceholder::Color(placeholder_color)),
+        )
+        .id();
+
+    let material = |ctx: &Context| {
+        let handle = ctx
+            .app
+            .world()
+            .get::<MeshMaterial3d<StandardMaterial>>(entity)
+            .unwrap();
+
+        ctx.app
+            .world()
+            .resource::<Assets<StandardMaterial>>()
+            .get(handle)
+            .unwrap()
+            .clone()
+    };
+
+    let ready_entities = |ctx: &Context| {
+        ctx.app
+            .world()
+            .resource::<Events<Sprite3dReady>>()
+            .iter_current_update_events()
+            .map(|event| event.entity)
+            .collect::<Vec<_>>()
+    };
+
+    // The placeholder is displayed while the image is loading
+
+    ctx.app.update();
+
+    assert!(ctx.app.world().get::<Mesh3d>(entity).is_some());
+    assert_eq!(material(&ctx).base_color, placeholder_color);
+    assert_eq!(material(&ctx).base_color_texture, None);
+    assert!(ready_entities(&ctx).is_empty());
+
+    // The actual image replaces the placeholder once loaded
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<Assets<Image>>()
+        .insert(&image, Image::default());
+
+    ctx.app.update();
+
+    assert_eq!(material(&ctx).base_color_texture, Some(image));
+    assert_eq!(ready_entities(&ctx), vec![entity]);
+}
+
+#[test]
+fn sampler() {
+    let mut ctx = Context::new();
+
+    let image = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<Image>>()
+        .add(Image::default());
+
+    let default_entity = ctx
+        .app
+        .world_mut()
+        .spawn(Sprite3d::from_image(image.clone()))
+        .id();
+
+    let sampler = Sprite3dSampler::linear().with_anisotropy(4);
+
+    let sampled_entities = [0, 1].map(|_| {
+        ctx.app
+            .world_mut()
+            .spawn(Sprite3d::from_image(image.clone()).with_sampler(sampler))
+            .id()
+    });
+
+    ctx.app.update();
+
+    let texture = |ctx: &Context, entity: Entity| {
+        let handle = ctx
+            .app
+            .world()
+            .get::<MeshMaterial3d<StandardMaterial>>(entity)
+            .unwrap();
+
+        ctx.app
+            .world()
+            .resource::<Assets<StandardMaterial>>()
+            .get(handle)
+            .unwrap()
+            .base_color_texture
+            .clone()
+            .unwrap()
+    };
+
+    // Sprites without a sampler use their image directly
+
+    assert_eq!(texture(&ctx, default_entity), image);
+
+    // Sprites with a sampler share a copy of the image with that sampler
+
+    let sampled_texture = texture(&ctx, sampled_entities[0]);
+
+    assert_ne!(sampled_texture, image);
+    assert_eq!(texture(&ctx, sampled_entities[1]), sampled_texture);
+
+    let copy_sampler = ctx
+        .app
+        .world()
+        .resource::<Assets<Image>>()
+        .get(&sampled_texture)
+        .unwrap()
+        .sampler
+        .clone();
+
+    assert_eq!(
+        copy_sampler,
+        bevy::image::ImageSampler::Descriptor(sampler.descriptor())
+    );
+}
+
+#[test]
+fn pixel_perfect() {
+    let mut ctx = Context::new();
+
+    let image = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<Image>>()
+        .add(Image::new_fill(
+            Extent3d {
+                width: 768,
+                height: 768,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[0, 0, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        ));
+
+    // A layout at half the resolution of the image
+
+    let layout = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<TextureAtlasLayout>>()
+        .add(TextureAtlasLayout::from_grid(
+            UVec2::new(48, 48),
+            8,
+            8,
+            None,
+            None,
+        ));
+
+    let camera = ctx.app.world_mut().spawn(Camera2d).id();
+
+    let entity = ctx
+        .app
+        .world_mut()
+        .spawn((
+            Sprite3d::from_atlas_image(image, TextureAtlas { layout, index: 0 }),
+            Sprite3dPixelPerfect::new(camera).with_pixels_per_texel(2),
+        ))
+        .id();
+
+    let size = |ctx: &Context| {
+        ctx.app
+            .world()
+            .get::<Sprite3d>(entity)
+            .unwrap()
+            .custom_size
+            .unwrap()
+    };
+
+    // One world unit per pixel, 96 texels per frame
+
+    ctx.app.update();
+
+    assert_eq!(size(&ctx), Vec2::splat(192.0));
+
+    // Zooming in shrinks the sprite in world units
+
+    ctx.app
+        .world_mut()
+        .get_mut::<OrthographicProjection>(camera)
+        .unwrap()
+        .scale = 0.5;
+
+    ctx.app.update();
+
+    assert_eq!(size(&ctx), Vec2::splat(96.0));
+}
+
+#[test]
+fn material() {
+    let mut ctx = Context::new();
+
+    let image = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<Image>>()
+        .add(Image::default());
+
+    let template = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<StandardMaterial>>()
+        .add(StandardMaterial {
+            double_sided: true,
+            cull_mode: None,
+            metallic: 1.0,
+            ..default()
+        });
+
+    let entity = ctx
+        .app
+        .world_mut()
+        .spawn(
+            Sprite3d::from_image(image.clone())
+                .with_unlit(false)
+                .with_perceptual_roughness(0.8)
+                .with_metallic(0.3)
+                .with_depth_bias(2.0)
+                .with_material_template(template),
+        )
+        .id();
+
+    ctx.app.update();
+
+    let handle = ctx
+        .app
+        .world()
+        .get::<MeshMaterial3d<StandardMaterial>>(entity)
+        .unwrap();
+
+    let material = ctx
+        .app
+        .world()
+        .resource::<Assets<StandardMaterial>>()
+        .get(handle)
+        .unwrap();
+
+    // The parameters of the sprite are applied on top of the template
+
+    assert_eq!(material.base_color_texture, Some(image));
+    assert!(!material.unlit);
+    assert_eq!(material.perceptual_roughness, 0.8);
+    assert_eq!(material.reflectance, 0.15);
+    assert_eq!(material.metallic, 0.3);
+    assert_eq!(material.depth_bias, 2.0);
+
+    // The template provides the rest
+
+    assert!(material.double_sided);
+    assert_eq!(material.cull_mode, None);
+}
+
+#[test]
+fn missing_atlas_layout() {
+    let mut ctx = Context::new();
+
+    let image = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<Image>>()
+        .add(Image::default());
+
+    // A layout that is not loaded yet
+
+    let layout: Handle<TextureAtlasLayout> =
+        Handle::weak_from_u128(0x2c4e_6a8b_0d1f_4e3a_9b7c_5d2e_8f10_3a4b);
+
+    let entity = ctx
+        .app
+        .world_mut()
+        .spawn(Sprite3d::from_atlas_image(
+            image,
+            TextureAtlas {
+                layout: layout.clone(),
+                index: 0,
+            },
+        ))
+        .id();
+
+    // The sprite waits for the layout
+
+    ctx.app.update();
+    ctx.app.update();
+
+    assert!(ctx.app.world().get::<Mesh3d>(entity).is_none());
+
+    // The mesh is created once the layout is available
+
+    ctx.app
+        .world_mut()
+        .resource_mut::<Assets<TextureAtlasLayout>>()
+        .insert(
+            &layout,
+            TextureAtlasLayout::from_grid(UVec2::new(96, 96), 8, 8, None, None),
+        );
+
+    ctx.app.update();
+
+    assert!(ctx.app.world().get::<Mesh3d>(entity).is_some());
+}
+
+#[test]
+fn uv_inset() {
+    let mut ctx = Context::new();
+
+    let image = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<Image>>()
+        .add(Image::default());
+
+    let layout = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<TextureAtlasLayout>>()
+        .add(TextureAtlasLayout::from_grid(
+            UVec2::new(10, 10),
+            4,
+            2,
+            None,
+            None,
+        ));
+
+    let entity = ctx
+        .app
+        .world_mut()
+        .spawn(
+            Sprite3d::from_atlas_image(image, TextureAtlas { layout, index: 1 })
+                .with_uv_inset(Sprite3dUvInset::Pixels(0.5)),
+        )
+        .id();
+
+    ctx.app.update();
+
+    let handle = ctx.app.world().get::<Mesh3d>(entity).unwrap();
+
+    let mesh = ctx
+        .app
+        .world()
+        .resource::<Assets<Mesh>>()
+        .get(handle)
+        .unwrap();
+
+    let Some(bevy::render::mesh::VertexAttributeValues::Float32x2(uvs)) =
+        mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+    else {
+        panic!("the mesh has no UVs");
+    };
+
+    // The UVs are moved inward by half a pixel on all sides
+
+    let min = uvs
+        .iter()
+        .fold(Vec2::MAX, |min, uv| min.min(Vec2::from(*uv)));
+
+    let max = uvs
+        .iter()
+        .fold(Vec2::MIN, |max, uv| max.max(Vec2::from(*uv)));
+
+    assert_eq!(min, Vec2::new(10.5 / 40.0, 0.5 / 20.0));
+    assert_eq!(max, Vec2::new(19.5 / 40.0, 9.5 / 20.0));
+}
+
+#[test]
+fn uv_inset_half_texel() {
+    let mut ctx = Context::new();
+
+    // An image at twice the resolution of the layout
+
+    let image = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<Image>>()
+        .add(Image::new_fill(
+            Extent3d {
+                width: 80,
+                height: 40,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[0, 0, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        ));
+
+    let layout = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Assets<TextureAtlasLayout>>()
+        .add(TextureAtlasLayout::from_grid(
+            UVec2::new(10, 10),
+            4,
+            2,
+            None,
+            None,
+        ));
+
+    let entity = ctx
+        .app
+        .world_mut()
+        .spawn(
+            Sprite3d::from_atlas_image(image, TextureAtlas { layout, index: 0 })
+                .with_uv_inset(Sprite3dUvInset::HalfTexel),
+        )
+        .id();
+
+    ctx.app.update();
+
+    let handle = ctx.app.world().get::<Mesh3d>(entity).unwrap();
+
+    let mesh = ctx
+        .app
+        .world()
+        .resource::<Assets<Mesh>>()
+        .get(handle)
+        .unwrap();
+
+    let Some(bevy::render::mesh::VertexAttributeValues::Float32x2(uvs)) =
+        mesh.attribute(Mesh::ATTRIBUTE_UV_0)
+    else {
+        panic!("the mesh has no UVs");
+    };
+
+    // Half a texel of the image is a quarter of a pixel of the layout
+
+    let min = uvs
+        .iter()
+        .fold(Vec2::MAX, |min, uv| min.min(Vec2::from(*uv)));
+
+    let max = uvs
+        .iter()
+        .fold(Vec2::MIN, |max, uv| max.max(Vec2::from(*uv)));
+
+    assert_eq!(min, Vec2::new(0.5 / 80.0, 0.5 / 40.0));
+    assert_eq!(max, Vec2::new(9.75 / 40.0, 9.75 / 20.0));
+}
+
+#[test]
+fn batching() {
+    let mut ctx = Context::with_plugin(SpritesheetAnimationPlugin {
+        batch_3d: true,
+        ..default()
+    });
+
+    let mut images = ctx.app.world_mut().resource_mut::<Assets<Image>>();
+
+    let image1 = images.add(Image::default());
+    let image2 = images.add(Image::default());
+
+    let sprites1 = [0.0, 10.0].map(|x| {
+        ctx.app
+            .world_mut()
+            .spawn((
+                Sprite3d::from_image(image1.clone()),
+                Transform::from_xyz(x, 0.0, 0.0),
+            ))
+            .id()
+    });
+
+    let sprite2 = ctx
+        .app
+        .world_mut()
+        .spawn(Sprite3d::from_image(image2.clone()))
+        .id();
+
+    ctx.app.update();
+
+    // One batch per material
+
+    let batch_meshes = |ctx: &mut Context| {
+        let mut batches = ctx
+            .app
+            .world_mut()
+            .query_filtered::<&Mesh3d, (With<NoFrustumCulling>, Without<Sprite3d>)>();
+
+        batches
+            .iter(ctx.app.world())
+            .map(|mesh| mesh.0.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let vertex_count = |ctx: &Context, mesh: &Handle<Mesh>| {
+        ctx.app
+            .world()
+            .resource::<Assets<Mesh>>()
+            .get(mesh)
+            .unwrap()
+            .count_vertices()
+    };
+
+    let meshes = batch_meshes(&mut ctx);
+
+    assert_eq!(meshes.len(), 2);
+
+    let (mesh1, mesh2) = if vertex_count(&ctx, &meshes[0]) == 12 {
+        (meshes[0].clone(), meshes[1].clone())
+    } else {
+        (meshes[1].clone(), meshes[0].clone())
+    };
+
+    assert_eq!(vertex_count(&ctx, &mesh1), 12);
+    assert_eq!(vertex_count(&ctx, &mesh2), 6);
+
+    // Moving a sprite only updates its own batch
+
+    ctx.app
+        .world_mut()
+        .get_mut::<Transform>(sprites1[1])
+        .unwrap()
+        .translation
+        .x = 20.0;
+
+    ctx.app.update();
+
+    let modified_meshes: Vec<_> = ctx
+        .app
+        .world()
+        .resource::<Events<AssetEvent<Mesh>>>()
+        .iter_current_update_events()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    assert!(modified_meshes.contains(&mesh1.id()));
+    assert!(!modified_meshes.contains(&mesh2.id()));
+    assert_eq!(vertex_count(&ctx, &mesh1), 12);
+
+    // Removing sprites shrinks or removes the batches
+
+    ctx.app.world_mut().despawn(sprites1[0]);
+
+    *ctx.app.world_mut().get_mut::<Visibility>(sprite2).unwrap() = Visibility::Hidden;
+
+    ctx.app.update();
+
+    assert_eq!(batch_meshes(&mut ctx), vec![mesh1.clone()]);
+    assert_eq!(vertex_count(&ctx, &mesh1), 6);
+}
@@ -0,0 +1,175 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn swapped_slots_stay_in_sync() {
+    let mut ctx = Context::new();
+
+    let animation = |ctx: &mut Context, frames: [usize; 4]| {
+        let clip = Clip::from_frames(frames).with_duration(AnimationDuration::PerFrame(100));
+        let clip_id = ctx.library().register_clip(clip);
+
+        ctx.library()
+            .register_animation(Animation::from_clip(clip_id))
+    };
+
+    let body_id = animation(&mut ctx, [0, 1, 2, 3]);
+    let hair_id = animation(&mut ctx, [10, 11, 12, 13]);
+    let helmet_id = animation(&mut ctx, [20, 21, 22, 23]);
+
+    let sprite = ctx
+        .app
+        .world()
+        .get::<Sprite>(ctx.sprite_entity)
+        .unwrap()
+        .clone();
+
+    let helmet_image = Handle::<Image>::weak_from_u128(0x2761_0000_0000_0000_0000_0000_0000_0001);
+
+    let rig = ctx
+        .app
+        .world_mut()
+        .spawn(
+            SpriteRig::default()
+                .with_slot("body", sprite.clone(), body_id)
+                .with_slot("hair", sprite, hair_id),
+        )
+        .id();
+
+    let slot = |ctx: &Context, name: &str| {
+        ctx.app
+            .world()
+            .get::<SpriteRig>(rig)
+            .unwrap()
+            .slot(name)
+            .unwrap()
+    };
+
+    let atlas_indices = |ctx: &Context| {
+        ["body", "hair"].map(|name| {
+            ctx.app
+                .world()
+                .get::<Sprite>(slot(ctx, name))
+                .unwrap()
+                .texture_atlas
+                .as_ref()
+                .unwrap()
+                .index
+        })
+    };
+
+    // The slots are spawned as children of the rig
+
+    ctx.run(50);
+
+    assert_eq!(
+        ctx.app
+            .world()
+            .get::<Children>(rig)
+            .unwrap()
+            .iter()
+            .copied()
+            .collect::<Vec<_>>(),
+        vec![slot(&ctx, "body"), slot(&ctx, "hair")]
+    );
+
+    assert_eq!(atlas_indices(&ctx), [0, 10]);
+
+    ctx.run(100); // 150
+    assert_eq!(atlas_indices(&ctx), [1, 11]);
+
+    // A swapped slot continues from the frame of the other slots
+
+    ctx.app.world_mut().get_mut::<SpriteRig>(rig).unwrap().swap(
+        "hair",
+        helmet_id,
+        Some(helmet_image.clone()),
+    );
+
+    ctx.run(20); // 170
+    assert_eq!(atlas_indices(&ctx), [1, 21]);
+
+    let hair_sprite = ctx.app.world().get::<Sprite>(slot(&ctx, "hair")).unwrap();
+
+    assert_eq!(hair_sprite.image, helmet_image);
+
+    // ... and changes frames along with them
+
+    ctx.run(40); // 210
+    assert_eq!(atlas_indices(&ctx), [2, 22]);
+
+    ctx.run(100); // 310
+    assert_eq!(atlas_indices(&ctx), [3, 23]);
+}
+
+#[test]
+fn overlays_play_independently() {
+    let mut ctx = Context::new();
+
+    let body_clip = Clip::from_frames([0, 1, 2, 3]).with_duration(AnimationDuration::PerFrame(100));
+    let body_clip_id = ctx.library().register_clip(body_clip);
+    let body_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(body_clip_id));
+
+    let flash_clip = Clip::from_frames([30, 31]).with_duration(AnimationDuration::PerFrame(50));
+    let flash_clip_id = ctx.library().register_clip(flash_clip);
+    let flash_id = ctx.library().register_animation(
+        Animation::from_clip(flash_clip_id).with_repetitions(AnimationRepeat::Times(1)),
+    );
+
+    let sprite = ctx
+        .app
+        .world()
+        .get::<Sprite>(ctx.sprite_entity)
+        .unwrap()
+        .clone();
+
+    let rig = ctx
+        .app
+        .world_mut()
+        .spawn(
+            SpriteRig::default()
+                .with_slot("body", sprite.clone(), body_id)
+                .with_overlay("flash", sprite, flash_id),
+        )
+        .id();
+
+    let atlas_indices = |ctx: &Context| {
+        let rig = ctx.app.world().get::<SpriteRig>(rig).unwrap();
+
+        ["body", "flash"].map(|name| {
+            ctx.app
+                .world()
+                .get::<Sprite>(rig.slot(name).unwrap())
+                .unwrap()
+                .texture_atlas
+                .as_ref()
+                .unwrap()
+                .index
+        })
+    };
+
+    ctx.run(25);
+    assert_eq!(atlas_indices(&ctx), [0, 30]);
+
+    ctx.run(100); // 125
+    assert_eq!(atlas_indices(&ctx), [1, 31]);
+
+    // Swapping an overlay replays it from the start instead of following the rig
+
+    ctx.app
+        .world_mut()
+        .get_mut::<SpriteRig>(rig)
+        .unwrap()
+        .swap("flash", flash_id, None);
+
+    ctx.run(10); // 135
+    assert_eq!(atlas_indices(&ctx), [1, 30]);
+
+    ctx.run(50); // 185
+    assert_eq!(atlas_indices(&ctx), [1, 31]);
+}
@@ -1,4 +1,10 @@
-use bevy::prelude::*;
+use bevy::{
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+};
 use bevy_spritesheet_animation::prelude::*;
 
 #[test]
@@ -22,6 +28,15 @@ fn row() {
     assert_eq!(sheet.row(1000), Vec::<usize>::new());
 }
 
+#[test]
+fn row_reversed() {
+    let sheet = Spritesheet::new(3, 6);
+
+    assert_eq!(sheet.row_reversed(0), vec![2, 1, 0]);
+    assert_eq!(sheet.row_reversed(3), vec![11, 10, 9]);
+    assert_eq!(sheet.row_reversed(1000), Vec::<usize>::new());
+}
+
 #[test]
 fn row_partial() {
     let sheet = Spritesheet::new(5, 4);
@@ -71,6 +86,15 @@ fn column() {
     assert_eq!(sheet.column(1000), Vec::<usize>::new());
 }
 
+#[test]
+fn column_reversed() {
+    let sheet = Spritesheet::new(5, 3);
+
+    assert_eq!(sheet.column_reversed(0), vec![10, 5, 0]);
+    assert_eq!(sheet.column_reversed(1), vec![11, 6, 1]);
+    assert_eq!(sheet.column_reversed(1000), Vec::<usize>::new());
+}
+
 #[test]
 fn column_partial() {
     let sheet = Spritesheet::new(3, 4);
@@ -111,6 +135,63 @@ fn column_partial() {
     assert_eq!(sheet.column_partial(100, 0..=100), Vec::<usize>::new());
 }
 
+#[test]
+fn row_range() {
+    let sheet = Spritesheet::new(3, 4);
+
+    assert_eq!(sheet.row_range(0..0), Vec::<usize>::new());
+    assert_eq!(sheet.row_range(0..1), vec![0, 1, 2]);
+    assert_eq!(sheet.row_range(1..=2), vec![3, 4, 5, 6, 7, 8]);
+    assert_eq!(sheet.row_range(2..), vec![6, 7, 8, 9, 10, 11]);
+    assert_eq!(sheet.row_range(3..100), vec![9, 10, 11]);
+    assert_eq!(sheet.row_range(100..), Vec::<usize>::new());
+}
+
+#[test]
+fn grid() {
+    let sheet = Spritesheet::new(5, 4);
+
+    assert_eq!(sheet.grid(.., ..), sheet.all());
+    assert_eq!(sheet.grid(0..0, ..), Vec::<usize>::new());
+    assert_eq!(sheet.grid(1..3, 0..2), vec![1, 2, 6, 7]);
+    assert_eq!(sheet.grid(3.., 2..=3), vec![13, 14, 18, 19]);
+    assert_eq!(sheet.grid(..=0, ..), vec![0, 5, 10, 15]);
+
+    // Out of bounds
+
+    assert_eq!(sheet.grid(4..100, 3..100), vec![19]);
+    assert_eq!(sheet.grid(100.., ..), Vec::<usize>::new());
+}
+
+#[test]
+fn column_major() {
+    let sheet = Spritesheet::new(3, 2);
+
+    assert_eq!(sheet.all_in_order(TraversalOrder::RowMajor), sheet.all());
+    assert_eq!(
+        sheet.all_in_order(TraversalOrder::ColumnMajor),
+        vec![0, 3, 1, 4, 2, 5]
+    );
+
+    assert_eq!(
+        sheet.grid_in_order(1.., .., TraversalOrder::ColumnMajor),
+        vec![1, 4, 2, 5]
+    );
+    assert_eq!(
+        sheet.grid_in_order(1..100, .., TraversalOrder::ColumnMajor),
+        vec![1, 4, 2, 5]
+    );
+
+    assert_eq!(
+        sheet.strip_in_order(1, 1, 3, TraversalOrder::RowMajor),
+        vec![4, 5]
+    );
+    assert_eq!(
+        sheet.strip_in_order(1, 1, 3, TraversalOrder::ColumnMajor),
+        vec![4, 2, 5]
+    );
+}
+
 #[test]
 fn horizontal_strip() {
     let sheet = Spritesheet::new(8, 8);
@@ -152,3 +233,417 @@ fn atlas_layout() {
         Some(&URect::new(100, 400, 200, 600))
     );
 }
+
+#[test]
+fn atlas_layout_with_padding_and_offset() {
+    let sheet = Spritesheet::new(2, 2)
+        .with_padding(UVec2::new(2, 4))
+        .with_offset(UVec2::new(1, 3));
+
+    let layout = sheet.atlas_layout(10, 20);
+
+    // The size includes the border on all sides
+
+    assert_eq!(layout.size, UVec2::new(24, 50));
+    assert_eq!(layout.textures.len(), 4);
+
+    assert_eq!(layout.textures.get(0), Some(&URect::new(1, 3, 11, 23)));
+    assert_eq!(layout.textures.get(1), Some(&URect::new(13, 3, 23, 23)));
+    assert_eq!(layout.textures.get(2), Some(&URect::new(1, 27, 11, 47)));
+    assert_eq!(layout.textures.get(3), Some(&URect::new(13, 27, 23, 47)));
+
+    // The frame indices are not affected
+
+    assert_eq!(sheet.row(1), vec![2, 3]);
+}
+
+#[test]
+fn atlas_layout_with_inset() {
+    let sheet = Spritesheet::new(2, 1)
+        .with_padding(UVec2::new(2, 0))
+        .with_inset(UVec2::new(1, 2));
+
+    let layout = sheet.atlas_layout(10, 20);
+
+    // The frames are cropped but the size is not affected
+
+    assert_eq!(layout.size, UVec2::new(22, 20));
+
+    assert_eq!(layout.textures.get(0), Some(&URect::new(1, 2, 9, 18)));
+    assert_eq!(layout.textures.get(1), Some(&URect::new(13, 2, 21, 18)));
+
+    // The inset cannot exceed half of a frame
+
+    let layout = Spritesheet::new(1, 1)
+        .with_inset(UVec2::splat(100))
+        .atlas_layout(10, 20);
+
+    assert_eq!(layout.textures.get(0), Some(&URect::new(5, 10, 5, 10)));
+}
+
+#[test]
+fn rects() {
+    let sheet = RectSpritesheet::from_rects(
+        UVec2::new(200, 100),
+        [
+            URect::new(0, 0, 20, 50),
+            URect::new(20, 0, 80, 50),
+            URect::new(0, 50, 100, 100),
+        ],
+    );
+
+    assert_eq!(sheet.all(), vec![0, 1, 2]);
+
+    assert_eq!(sheet.rect(1), Some(URect::new(20, 0, 80, 50)));
+    assert_eq!(sheet.rect(3), None);
+
+    // Frames in the top row
+
+    assert_eq!(sheet.frames_within(URect::new(0, 0, 200, 50)), vec![0, 1]);
+
+    // Frames partially in the area are excluded
+
+    assert_eq!(sheet.frames_within(URect::new(0, 0, 50, 100)), vec![0]);
+
+    // The layout has the same rects
+
+    let layout = sheet.atlas_layout();
+
+    assert_eq!(layout.size, UVec2::new(200, 100));
+    assert_eq!(
+        layout.textures,
+        vec![
+            URect::new(0, 0, 20, 50),
+            URect::new(20, 0, 80, 50),
+            URect::new(0, 50, 100, 100),
+        ]
+    );
+}
+
+#[test]
+fn const_queries() {
+    const SHEET: Spritesheet = Spritesheet::new(3, 2);
+
+    const INDEX: usize = SHEET.index(1, 1);
+    const ROW: [usize; 3] = SHEET.row_array(1);
+    const COLUMN: [usize; 2] = SHEET.column_array(2);
+    const HORIZONTAL_STRIP: [usize; 4] = SHEET.horizontal_strip_array(1, 0);
+    const VERTICAL_STRIP: [usize; 3] = SHEET.vertical_strip_array(1, 0);
+
+    assert_eq!(INDEX, 4);
+    assert_eq!(ROW, [3, 4, 5]);
+    assert_eq!(COLUMN, [2, 5]);
+    assert_eq!(HORIZONTAL_STRIP, [1, 2, 3, 4]);
+    assert_eq!(VERTICAL_STRIP, [1, 4, 2]);
+
+    // Same results as the non-const queries
+
+    assert_eq!(ROW.to_vec(), SHEET.row(1));
+    assert_eq!(COLUMN.to_vec(), SHEET.column(2));
+    assert_eq!(HORIZONTAL_STRIP.to_vec(), SHEET.horizontal_strip(1, 0, 4));
+    assert_eq!(VERTICAL_STRIP.to_vec(), SHEET.vertical_strip(1, 0, 3));
+}
+
+#[test]
+#[should_panic]
+fn const_queries_out_of_bounds() {
+    let sheet = Spritesheet::new(3, 2);
+
+    let _: [usize; 3] = sheet.horizontal_strip_array(1, 1);
+}
+
+#[test]
+fn named_cells() {
+    let mut sheet = NamedSpritesheet::new(Spritesheet::new(4, 3));
+
+    sheet
+        .name_cell("idle_1", (0, 0))
+        .name_cell("idle_2", (1, 0))
+        .name_cell("muzzle_flash", (3, 2));
+
+    assert_eq!(sheet.named_cell("muzzle_flash"), Some(11));
+    assert_eq!(sheet.named_cell("unknown"), None);
+    assert_eq!(sheet.cell_names().len(), 3);
+
+    assert_eq!(
+        sheet.named_frames(["idle_1", "idle_2", "idle_1"]),
+        vec![0, 1, 0]
+    );
+
+    // Unknown names are skipped
+
+    assert_eq!(sheet.named_frames(["idle_2", "unknown"]), vec![1]);
+
+    // Out-of-bounds cells are not named
+
+    sheet.name_cell("outside", (4, 0));
+
+    assert_eq!(sheet.named_cell("outside"), None);
+
+    // Renaming a cell after the spritesheet is reorganized
+
+    let sheet = sheet.with_named_cell("idle_1", (2, 1));
+
+    assert_eq!(sheet.named_frames(["idle_1", "idle_2"]), vec![6, 1]);
+
+    // The regular queries are still available
+
+    assert_eq!(sheet.row(2), vec![8, 9, 10, 11]);
+}
+
+#[test]
+fn animated_components() {
+    let mut library = AnimationLibrary::default();
+
+    let clip_id = library.register_clip(Clip::from_frames([0, 1, 2]));
+    let animation_id = library.register_animation(Animation::from_clip(clip_id));
+
+    let mut atlas_layouts = Assets::<TextureAtlasLayout>::default();
+
+    let sheet = Spritesheet::new(4, 2);
+
+    // 2D sprite
+
+    let (sprite, animation) =
+        sheet.animated_sprite(Handle::default(), 10, 20, &mut atlas_layouts, animation_id);
+
+    let atlas = sprite.texture_atlas.unwrap();
+
+    assert_eq!(atlas.index, 0);
+    assert_eq!(
+        atlas_layouts.get(&atlas.layout).unwrap().size,
+        UVec2::new(40, 40)
+    );
+    assert_eq!(animation.animation_id, animation_id);
+
+    // 3D sprite
+
+    let (sprite, animation) =
+        sheet.animated_sprite3d(Handle::default(), 10, 20, &mut atlas_layouts, animation_id);
+
+    assert!(atlas_layouts.contains(&sprite.texture_atlas.unwrap().layout));
+    assert_eq!(animation.animation_id, animation_id);
+
+    // UI image
+
+    let (image_node, animation) =
+        sheet.animated_image_node(Handle::default(), 10, 20, &mut atlas_layouts, animation_id);
+
+    assert!(atlas_layouts.contains(&image_node.texture_atlas.unwrap().layout));
+    assert_eq!(animation.animation_id, animation_id);
+}
+
+#[test]
+fn rects_with_inset() {
+    let sheet = RectSpritesheet::from_rects(
+        UVec2::new(100, 50),
+        [URect::new(0, 0, 20, 50), URect::new(20, 0, 23, 50)],
+    )
+    .with_inset(UVec2::new(2, 1));
+
+    // The inset cannot exceed half of a frame
+
+    assert_eq!(
+        sheet.atlas_layout().textures,
+        vec![URect::new(2, 1, 18, 49), URect::new(21, 1, 22, 49)]
+    );
+
+    // The frame rects are not affected
+
+    assert_eq!(sheet.rect(1), Some(URect::new(20, 0, 23, 50)));
+}
+
+#[test]
+fn from_cell_size() {
+    let mut images = Assets::<Image>::default();
+
+    let image = images.add(Image::new_fill(
+        Extent3d {
+            width: 96,
+            height: 64,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[255, 255, 255, 255],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    ));
+
+    let sheet = Spritesheet::from_cell_size(&image, UVec2::splat(32), &images).unwrap();
+
+    assert_eq!(sheet.columns(), 3);
+    assert_eq!(sheet.rows(), 2);
+
+    // Uneven cell size
+
+    assert_eq!(
+        Spritesheet::from_cell_size(&image, UVec2::new(32, 24), &images).unwrap_err(),
+        SpritesheetError::UnevenCellSize {
+            image_size: UVec2::new(96, 64),
+            cell_size: UVec2::new(32, 24)
+        }
+    );
+
+    // Empty cell
+
+    assert_eq!(
+        Spritesheet::from_cell_size(&image, UVec2::new(0, 32), &images).unwrap_err(),
+        SpritesheetError::EmptyCell
+    );
+
+    // Image not loaded
+
+    assert_eq!(
+        Spritesheet::from_cell_size(&Handle::default(), UVec2::splat(32), &images).unwrap_err(),
+        SpritesheetError::ImageNotLoaded
+    );
+}
+
+#[test]
+fn coordinates() {
+    let sheet = Spritesheet::new(4, 3);
+
+    assert_eq!(sheet.index_of(0, 0), Some(0));
+    assert_eq!(sheet.index_of(3, 2), Some(11));
+    assert_eq!(sheet.index_of(4, 0), None);
+    assert_eq!(sheet.index_of(0, 3), None);
+
+    assert_eq!(sheet.coords_of(0), Some((0, 0)));
+    assert_eq!(sheet.coords_of(7), Some((3, 1)));
+    assert_eq!(sheet.coords_of(12), None);
+
+    for index in sheet.all() {
+        let (x, y) = sheet.coords_of(index).unwrap();
+
+        assert_eq!(sheet.index_of(x, y), Some(index));
+    }
+}
+
+#[test]
+fn cell_rect() {
+    // The cells match the atlas layout
+
+    let sheet = Spritesheet::new(3, 2)
+        .with_padding(UVec2::new(2, 1))
+        .with_offset(UVec2::splat(4));
+
+    let layout = sheet.atlas_layout(10, 20);
+
+    for index in sheet.all() {
+        assert_eq!(
+            sheet.cell_rect(index, layout.size),
+            Some(layout.textures[index])
+        );
+    }
+
+    assert_eq!(sheet.cell_rect(6, layout.size), None);
+}
+
+#[test]
+fn sub_sheet() {
+    // ┌─────────────┐
+    // │ 0  1  2  3  4│
+    // │ 5  6  7  8  9│
+    // │10 11 12 13 14│
+    // │15 16 17 18 19│
+    // └─────────────┘
+
+    let sheet = Spritesheet::new(5, 4);
+
+    let block = sheet.sub_sheet(2..4, 1..);
+
+    assert_eq!(block.columns(), 2);
+    assert_eq!(block.rows(), 3);
+
+    assert_eq!(block.all(), vec![7, 8, 12, 13, 17, 18]);
+    assert_eq!(block.row(1), vec![12, 13]);
+    assert_eq!(block.column(1), vec![8, 13, 18]);
+    assert_eq!(block.positions([(1, 0), (0, 2)]), vec![8, 17]);
+    assert_eq!(block.horizontal_strip(1, 0, 3), vec![8, 12, 13]);
+    assert_eq!(block.vertical_strip(0, 2, 2), vec![17, 8]);
+    assert_eq!(block.index(1, 2), 18);
+    assert_eq!(block.row_array::<2>(0), [7, 8]);
+
+    // Coordinates are relative to the block
+
+    assert_eq!(block.coords_of(13), Some((1, 1)));
+    assert_eq!(block.coords_of(6), None);
+    assert_eq!(block.index_of(2, 0), None);
+
+    // Nested sub-sheets
+
+    assert_eq!(block.sub_sheet(1.., 1..2).all(), vec![13]);
+
+    // The atlas layout covers the whole spritesheet
+
+    assert_eq!(
+        block.atlas_layout(16, 16).textures,
+        sheet.atlas_layout(16, 16).textures
+    );
+
+    assert_eq!(
+        block.cell_rect(13, UVec2::new(80, 64)),
+        sheet.cell_rect(13, UVec2::new(80, 64))
+    );
+
+    // Out of bounds blocks are clamped
+
+    assert_eq!(sheet.sub_sheet(3..8, 3..).all(), vec![18, 19]);
+}
+
+#[test]
+fn strip() {
+    let mut images = Assets::<Image>::default();
+
+    let image = images.add(Image::new_fill(
+        Extent3d {
+            width: 96,
+            height: 16,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[255, 255, 255, 255],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    ));
+
+    let sheet = Spritesheet::strip(&image, 6, &images).unwrap();
+
+    assert_eq!(sheet.columns(), 6);
+    assert_eq!(sheet.rows(), 1);
+    assert_eq!(sheet.all(), vec![0, 1, 2, 3, 4, 5]);
+
+    // The width of the image must be a multiple of the number of frames
+
+    assert_eq!(
+        Spritesheet::strip(&image, 5, &images).unwrap_err(),
+        SpritesheetError::UnevenCellSize {
+            image_size: UVec2::new(96, 16),
+            cell_size: UVec2::new(19, 16)
+        }
+    );
+
+    assert_eq!(
+        Spritesheet::strip(&image, 0, &images).unwrap_err(),
+        SpritesheetError::EmptyCell
+    );
+
+    // One-call animation
+
+    let mut library = AnimationLibrary::default();
+
+    let animation_id = library.create_strip_animation(6, AnimationDuration::PerFrame(100));
+
+    let animation = library.get_animation(animation_id).clone();
+
+    assert_eq!(animation.clip_ids().len(), 1);
+    assert_eq!(
+        *animation.duration(),
+        Some(AnimationDuration::PerFrame(100))
+    );
+    assert_eq!(*animation.repetitions(), Some(AnimationRepeat::Loop));
+
+    let clip = library.get_clip(animation.clip_ids()[0]);
+
+    assert_eq!(clip.frames(), &[0, 1, 2, 3, 4, 5]);
+}
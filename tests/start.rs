@@ -0,0 +1,210 @@
+pub mod context;
+
+use std::time::Duration;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn start_event() {
+    let mut ctx = Context::new();
+
+    let clip1 = Clip::from_frames([4, 5]).with_duration(AnimationDuration::PerFrame(100));
+    let clip1_id = ctx.library().register_clip(clip1);
+
+    let animation1 = Animation::from_clip(clip1_id);
+    let animation1_id = ctx.library().register_animation(animation1);
+
+    ctx.add_animation_to_sprite(animation1_id);
+
+    ctx.run(50);
+    ctx.check(4, [ctx.anim_start(animation1_id)]);
+
+    ctx.run(100); // 150
+    ctx.check(5, []);
+
+    // Looping does not restart the animation
+
+    ctx.run(100); // 250
+    ctx.check(
+        4,
+        [
+            ctx.clip_rep_end(animation1_id, clip1_id, 0),
+            ctx.clip_end(animation1_id, clip1_id),
+            ctx.anim_rep_end(animation1_id, 0),
+        ],
+    );
+
+    // Switching to another animation starts it
+
+    let clip2 = Clip::from_frames([7, 8]).with_duration(AnimationDuration::PerFrame(100));
+    let clip2_id = ctx.library().register_clip(clip2);
+
+    let animation2 = Animation::from_clip(clip2_id);
+    let animation2_id = ctx.library().register_animation(animation2);
+
+    ctx.update_sprite_animation(|anim| {
+        anim.switch(animation2_id);
+    });
+
+    ctx.run(50);
+    ctx.check(7, [ctx.anim_start(animation2_id)]);
+}
+
+#[test]
+fn no_start_event_while_paused() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([4, 5]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.update_sprite_animation(|anim| {
+        anim.playing = false;
+    });
+
+    ctx.run(50);
+    ctx.check(4, []);
+
+    ctx.update_sprite_animation(|anim| {
+        anim.playing = true;
+    });
+
+    ctx.run(50);
+    ctx.check(4, [ctx.anim_start(animation_id)]);
+}
+
+#[test]
+fn first_frame_held_during_delay() {
+    let mut ctx = Context::new();
+
+    let marker_id = ctx.library().new_marker();
+
+    let clip = Clip::from_frames([4, 5, 6])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(marker_id, 0);
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.update_sprite_animation(|anim| {
+        anim.delay = Duration::from_millis(250);
+    });
+
+    // The first frame is displayed but its events are held back
+
+    ctx.run(100);
+    ctx.check(4, []);
+
+    ctx.run(100); // 200
+    ctx.check(4, []);
+
+    // The animation starts at 250
+
+    ctx.run(100); // 300, 50 into the animation
+    ctx.check(
+        4,
+        [
+            ctx.anim_start(animation_id),
+            ctx.marker_hit(marker_id, animation_id, 0, clip_id, 0),
+        ],
+    );
+
+    ctx.get_sprite(|sprite| {
+        assert_eq!(sprite.delay, Duration::ZERO);
+    });
+
+    ctx.run(100); // 150 into the animation
+    ctx.check(5, []);
+
+    ctx.run(100); // 250
+    ctx.check(6, []);
+}
+
+#[test]
+fn delay_paused() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([4, 5, 6]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.update_sprite_animation(|anim| {
+        anim.delay = Duration::from_millis(200);
+        anim.playing = false;
+    });
+
+    // The delay does not elapse while paused
+
+    for _ in 0..10 {
+        ctx.run(100);
+        ctx.check(4, []);
+    }
+
+    ctx.update_sprite_animation(|anim| {
+        anim.playing = true;
+    });
+
+    ctx.run(150);
+    ctx.check(4, []);
+
+    ctx.run(100); // the animation starts at 200, 50 into the animation
+    ctx.check(4, [ctx.anim_start(animation_id)]);
+
+    ctx.run(100); // 150
+    ctx.check(5, []);
+}
+
+#[test]
+fn restart_in_place() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([0, 1, 2]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id).with_repetitions(AnimationRepeat::Times(1));
+    let animation_id = ctx.library().register_animation(animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    ctx.run(500); // 550
+    ctx.check(
+        2,
+        [
+            ctx.clip_rep_end(animation_id, clip_id, 0),
+            ctx.clip_end(animation_id, clip_id),
+            ctx.anim_rep_end(animation_id, 0),
+            ctx.anim_end(animation_id),
+        ],
+    );
+
+    // Restart the ended animation as if the entity was spawned again
+
+    let sprite_entity = ctx.sprite_entity;
+
+    assert!(ctx
+        .app
+        .world_mut()
+        .resource_mut::<Animator>()
+        .restart(sprite_entity));
+
+    ctx.run(50); // 600
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    ctx.run(100); // 700
+    ctx.check(1, []);
+}
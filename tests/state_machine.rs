@@ -0,0 +1,133 @@
+pub mod context;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn trigger_and_end_transitions() {
+    let mut ctx = Context::new();
+
+    let idle_clip = Clip::from_frames([0, 1]).with_duration(AnimationDuration::PerFrame(100));
+    let idle_clip_id = ctx.library().register_clip(idle_clip);
+
+    let idle = Animation::from_clip(idle_clip_id).with_repetitions(AnimationRepeat::Loop);
+    let idle_id = ctx.library().register_animation(idle);
+
+    let attack_clip = Clip::from_frames([5, 6]).with_duration(AnimationDuration::PerFrame(100));
+    let attack_clip_id = ctx.library().register_clip(attack_clip);
+
+    let attack = Animation::from_clip(attack_clip_id).with_repetitions(AnimationRepeat::Times(1));
+    let attack_id = ctx.library().register_animation(attack);
+
+    ctx.add_animation_to_sprite(idle_id);
+
+    ctx.app.world_mut().entity_mut(ctx.sprite_entity).insert(
+        AnimationStateMachine::new(idle_id)
+            .with_transition(idle_id, attack_id, TransitionCondition::Trigger("attack"))
+            .with_transition(attack_id, idle_id, TransitionCondition::AnimationEnd),
+    );
+
+    let state = |ctx: &Context| {
+        ctx.app
+            .world()
+            .get::<AnimationStateMachine>(ctx.sprite_entity)
+            .unwrap()
+            .state()
+    };
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(idle_id)]);
+
+    // Unknown triggers are ignored
+
+    ctx.app
+        .world_mut()
+        .get_mut::<AnimationStateMachine>(ctx.sprite_entity)
+        .unwrap()
+        .trigger("jump");
+
+    ctx.run(10); // 60
+    ctx.check(0, []);
+    assert_eq!(state(&ctx), idle_id);
+
+    // Trigger the attack
+
+    ctx.app
+        .world_mut()
+        .get_mut::<AnimationStateMachine>(ctx.sprite_entity)
+        .unwrap()
+        .trigger("attack");
+
+    ctx.run(10); // 70
+    ctx.check(0, []);
+    assert_eq!(state(&ctx), attack_id);
+
+    ctx.run(50); // 120
+    ctx.check(5, [ctx.anim_start(attack_id)]);
+
+    ctx.run(100); // 220
+    ctx.check(6, []);
+
+    // Return to idle at the end of the attack
+
+    ctx.run(100); // 320
+    ctx.check(
+        6,
+        [
+            ctx.clip_rep_end(attack_id, attack_clip_id, 0),
+            ctx.clip_end(attack_id, attack_clip_id),
+            ctx.anim_rep_end(attack_id, 0),
+            ctx.anim_end(attack_id),
+        ],
+    );
+    assert_eq!(state(&ctx), idle_id);
+
+    ctx.run(50); // 370
+    ctx.check(0, [ctx.anim_start(idle_id)]);
+}
+
+#[test]
+fn marker_transitions() {
+    let mut ctx = Context::new();
+
+    let marker_id = ctx.library().new_marker();
+
+    let swing_clip = Clip::from_frames([0, 1, 2])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_marker(marker_id, 1);
+    let swing_clip_id = ctx.library().register_clip(swing_clip);
+
+    let swing_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(swing_clip_id));
+
+    let recover_clip_id = ctx.library().register_clip(Clip::from_frames([7]));
+
+    let recover_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(recover_clip_id));
+
+    ctx.add_animation_to_sprite(swing_id);
+
+    ctx.app.world_mut().entity_mut(ctx.sprite_entity).insert(
+        AnimationStateMachine::new(swing_id).with_transition(
+            swing_id,
+            recover_id,
+            TransitionCondition::Marker(marker_id),
+        ),
+    );
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(swing_id)]);
+
+    ctx.run(100); // 150
+    ctx.check(
+        1,
+        [ctx.marker_hit(marker_id, swing_id, 0, swing_clip_id, 0)],
+    );
+
+    // The marker switched the animation
+
+    ctx.run(10); // 160
+    ctx.check(7, [ctx.anim_start(recover_id)]);
+}
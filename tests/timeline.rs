@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use bevy_spritesheet_animation::prelude::*;
+
+#[test]
+fn timeline() {
+    let mut library = AnimationLibrary::default();
+
+    let marker = library.new_marker();
+
+    let clip1 = Clip::from_frames([0, 1])
+        .with_duration(AnimationDuration::PerFrame(100))
+        .with_repetitions(2)
+        .with_marker(marker, 1);
+    let clip1_id = library.register_clip(clip1);
+
+    let clip2 = Clip::from_frames([5]).with_duration(AnimationDuration::PerFrame(300));
+    let clip2_id = library.register_clip(clip2);
+
+    let animation = Animation::from_clips([clip1_id, clip2_id]);
+    let animation_id = library.register_animation(animation);
+
+    let timeline = library.animation_timeline(animation_id);
+
+    let ms = Duration::from_millis;
+
+    assert_eq!(timeline.duration, ms(700));
+
+    assert_eq!(
+        timeline
+            .frames
+            .iter()
+            .map(|frame| (frame.atlas_index, frame.start, frame.duration))
+            .collect::<Vec<_>>(),
+        vec![
+            (0, ms(0), ms(100)),
+            (1, ms(100), ms(100)),
+            (0, ms(200), ms(100)),
+            (1, ms(300), ms(100)),
+            (5, ms(400), ms(300)),
+        ]
+    );
+
+    assert_eq!(
+        timeline.clips,
+        vec![
+            TimelineClip {
+                clip_id: clip1_id,
+                clip_repetition: 0,
+                first_frame: 0,
+                frame_count: 2,
+                start: ms(0),
+                end: ms(200),
+            },
+            TimelineClip {
+                clip_id: clip1_id,
+                clip_repetition: 1,
+                first_frame: 2,
+                frame_count: 2,
+                start: ms(200),
+                end: ms(400),
+            },
+            TimelineClip {
+                clip_id: clip2_id,
+                clip_repetition: 0,
+                first_frame: 4,
+                frame_count: 1,
+                start: ms(400),
+                end: ms(700),
+            },
+        ]
+    );
+
+    assert_eq!(
+        timeline.markers,
+        vec![
+            TimelineMarker {
+                marker_id: marker,
+                clip_id: clip1_id,
+                frame: 1,
+                time: ms(100),
+            },
+            TimelineMarker {
+                marker_id: marker,
+                clip_id: clip1_id,
+                frame: 3,
+                time: ms(300),
+            },
+        ]
+    );
+}
@@ -0,0 +1,80 @@
+pub mod context;
+
+use bevy::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn playback_continues_on_destination() {
+    let mut ctx = Context::new();
+
+    let clip = Clip::from_frames([0, 1, 2, 3]).with_duration(AnimationDuration::PerFrame(100));
+    let clip_id = ctx.library().register_clip(clip);
+
+    let animation = Animation::from_clip(clip_id);
+    let animation_id = ctx.library().register_animation(animation);
+
+    let other_clip = Clip::from_frames([8, 9]);
+    let other_clip_id = ctx.library().register_clip(other_clip);
+
+    let other_animation = Animation::from_clip(other_clip_id);
+    let other_animation_id = ctx.library().register_animation(other_animation);
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+    ctx.check(0, [ctx.anim_start(animation_id)]);
+
+    ctx.run(200); // 250
+    ctx.check(2, []);
+
+    // Replace the sprite with another entity playing another animation
+
+    let replacement = ctx
+        .app
+        .world_mut()
+        .spawn((
+            Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..default()
+            },
+            SpritesheetAnimation::from_id(other_animation_id),
+        ))
+        .id();
+
+    let transferred = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Animator>()
+        .transfer(ctx.sprite_entity, replacement);
+
+    assert!(transferred);
+
+    ctx.app.world_mut().despawn(ctx.sprite_entity);
+    ctx.sprite_entity = replacement;
+
+    // The replacement picks up the animation where it was, without restarting it
+
+    ctx.run(10); // 260
+    ctx.check(2, []);
+
+    ctx.get_sprite(|animation| assert_eq!(animation.animation_id, animation_id));
+
+    ctx.run(50); // 310
+    ctx.check(3, []);
+}
+
+#[test]
+fn nothing_to_transfer() {
+    let mut ctx = Context::new();
+
+    let other = ctx.app.world_mut().spawn_empty().id();
+
+    let transferred = ctx
+        .app
+        .world_mut()
+        .resource_mut::<Animator>()
+        .transfer(ctx.sprite_entity, other);
+
+    assert!(!transferred);
+}
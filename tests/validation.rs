@@ -0,0 +1,132 @@
+pub mod context;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn valid_animation() {
+    let mut ctx = Context::new();
+
+    let clip_id = ctx.library().register_clip(
+        Clip::from_frames([0, 1, 2])
+            .with_duration(AnimationDuration::PerFrame(100))
+            .with_frame_duration(1, 50),
+    );
+
+    let animation = Animation::from_clips([clip_id, clip_id]);
+
+    assert!(ctx
+        .library()
+        .validate_animation(&animation, Some(3))
+        .is_empty());
+
+    // The animation is registered
+
+    let animation_id = ctx
+        .library()
+        .try_register_animation(animation.clone(), Some(3))
+        .unwrap();
+
+    assert_eq!(ctx.library().get_animation(animation_id), &animation);
+}
+
+#[test]
+fn all_errors_are_reported() {
+    let mut ctx = Context::new();
+
+    // A clip from another library
+
+    let mut other_library = AnimationLibrary::default();
+
+    other_library.register_clip(Clip::from_frames([0]));
+    other_library.register_clip(Clip::from_frames([0]));
+    let unknown_clip_id = other_library.register_clip(Clip::from_frames([0]));
+
+    // Invalid clips
+
+    let empty_clip_id = ctx.library().register_clip(Clip::from_frames([]));
+
+    let out_of_bounds_clip_id = ctx.library().register_clip(
+        Clip::from_frames([2, 8, 9]).with_duration(AnimationDuration::PerRepetition(0)),
+    );
+
+    let animation = Animation::from_clips([empty_clip_id, out_of_bounds_clip_id, unknown_clip_id])
+        .with_duration(AnimationDuration::PerFrame(0));
+
+    let errors = ctx.library().validate_animation(&animation, Some(8));
+
+    assert_eq!(
+        errors,
+        vec![
+            ValidationError::ZeroAnimationDuration,
+            ValidationError::EmptyClip(empty_clip_id),
+            ValidationError::InvalidAtlasIndex {
+                clip_id: out_of_bounds_clip_id,
+                frame_index: 1,
+                atlas_index: 8,
+                frame_count: 8
+            },
+            ValidationError::InvalidAtlasIndex {
+                clip_id: out_of_bounds_clip_id,
+                frame_index: 2,
+                atlas_index: 9,
+                frame_count: 8
+            },
+            ValidationError::ZeroClipDuration(out_of_bounds_clip_id),
+            ValidationError::UnknownClip(unknown_clip_id),
+        ]
+    );
+
+    // The atlas indices are not checked without a frame count
+
+    assert_eq!(
+        ctx.library().validate_animation(&animation, None).len(),
+        errors.len() - 2
+    );
+
+    // The animation is not registered
+
+    let animation_count = ctx.library().animations().len();
+
+    assert_eq!(
+        ctx.library().try_register_animation(animation, Some(8)),
+        Err(errors)
+    );
+
+    assert_eq!(ctx.library().animations().len(), animation_count);
+}
+
+#[test]
+fn zero_frame_durations() {
+    let mut ctx = Context::new();
+
+    let clip_id = ctx
+        .library()
+        .register_clip(Clip::from_timed_frames([(0, 100), (1, 0), (2, 0)]));
+
+    assert_eq!(
+        ctx.library()
+            .validate_animation(&Animation::from_clip(clip_id), None),
+        vec![
+            ValidationError::ZeroFrameDuration {
+                clip_id,
+                frame_index: 1
+            },
+            ValidationError::ZeroFrameDuration {
+                clip_id,
+                frame_index: 2
+            },
+        ]
+    );
+}
+
+#[test]
+fn no_clips() {
+    let mut ctx = Context::new();
+
+    assert_eq!(
+        ctx.library()
+            .validate_animation(&Animation::from_clips([]), None),
+        vec![ValidationError::NoClips]
+    );
+}
@@ -0,0 +1,102 @@
+pub mod context;
+
+use bevy_spritesheet_animation::prelude::*;
+use context::*;
+
+#[test]
+fn pick_is_deterministic_and_weighted() {
+    let mut library = AnimationLibrary::default();
+
+    let clip_id = library.register_clip(Clip::from_frames([0]));
+
+    let common_id = library.register_animation(Animation::from_clip(clip_id));
+    let rare_id = library.register_animation(Animation::from_clip(clip_id));
+    let never_id = library.register_animation(Animation::from_clip(clip_id));
+
+    let set = VariantSet::default()
+        .with_variant(common_id, 3)
+        .with_variant(never_id, 0)
+        .with_variant(rare_id, 1);
+
+    let picks: Vec<_> = (0..1000).map(|seed| set.pick(seed).unwrap()).collect();
+
+    // The same seeds pick the same variants
+
+    assert_eq!(
+        picks,
+        (0..1000)
+            .map(|seed| set.pick(seed).unwrap())
+            .collect::<Vec<_>>()
+    );
+
+    // Variants are picked according to their weights
+
+    let common_count = picks.iter().filter(|id| **id == common_id).count();
+    let rare_count = picks.iter().filter(|id| **id == rare_id).count();
+
+    assert!(!picks.contains(&never_id));
+    assert!(common_count > rare_count * 2);
+    assert!(rare_count > 0);
+
+    // Nothing to pick from
+
+    assert_eq!(VariantSet::default().pick(0), None);
+    assert_eq!(
+        VariantSet::default().with_variant(common_id, 0).pick(0),
+        None
+    );
+}
+
+#[test]
+fn play_variant() {
+    let mut ctx = Context::new();
+
+    let clip1_id = ctx.library().register_clip(Clip::from_frames([3]));
+    let animation1_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip1_id));
+
+    let clip2_id = ctx.library().register_clip(Clip::from_frames([6]));
+    let animation2_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip2_id));
+
+    let set = VariantSet::default()
+        .with_variant(animation1_id, 1)
+        .with_variant(animation2_id, 1);
+
+    // Find seeds that pick each variant
+
+    let seed1 = (0..)
+        .find(|seed| set.pick(*seed) == Some(animation1_id))
+        .unwrap();
+    let seed2 = (0..)
+        .find(|seed| set.pick(*seed) == Some(animation2_id))
+        .unwrap();
+
+    // The picked animation is inserted
+
+    let sprite_entity = ctx.sprite_entity;
+
+    ctx.app
+        .world_mut()
+        .entity_mut(sprite_entity)
+        .insert(PlayVariant {
+            set: set.clone(),
+            seed: seed1,
+        });
+
+    ctx.run(50);
+    ctx.check(3, [ctx.anim_start(animation1_id)]);
+
+    // Changing the seed switches the animation
+
+    ctx.app
+        .world_mut()
+        .get_mut::<PlayVariant>(sprite_entity)
+        .unwrap()
+        .seed = seed2;
+
+    ctx.run(50);
+    ctx.check(6, [ctx.anim_start(animation2_id)]);
+}
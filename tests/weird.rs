@@ -1,3 +1,4 @@
+use bevy::prelude::*;
 use bevy_spritesheet_animation::prelude::*;
 use context::Context;
 
@@ -15,6 +16,9 @@ fn clip_without_frames() {
 
     ctx.add_animation_to_sprite(animation_id);
 
+    ctx.run(100);
+    ctx.check(0, [ctx.anim_empty(animation_id)]);
+
     for _ in 0..100 {
         ctx.run(100);
         ctx.check(0, []);
@@ -30,12 +34,55 @@ fn animation_without_clips() {
 
     ctx.add_animation_to_sprite(animation_id);
 
+    ctx.run(100);
+    ctx.check(0, [ctx.anim_empty(animation_id)]);
+
     for _ in 0..100 {
         ctx.run(100);
         ctx.check(0, []);
     }
 }
 
+#[test]
+fn hide_empty_animation() {
+    let mut ctx = Context::with_plugin(SpritesheetAnimationPlugin {
+        empty_animation_behavior: EmptyAnimationBehavior::Hide,
+        ..default()
+    });
+
+    let empty_clip_id = ctx.library().register_clip(Clip::from_frames([]));
+    let empty_animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(empty_clip_id));
+
+    let clip_id = ctx.library().register_clip(Clip::from_frames([4, 5]));
+    let animation_id = ctx
+        .library()
+        .register_animation(Animation::from_clip(clip_id));
+
+    ctx.add_animation_to_sprite(empty_animation_id);
+
+    ctx.run(100);
+    ctx.check(0, [ctx.anim_empty(empty_animation_id)]);
+
+    assert_eq!(
+        ctx.app.world().get::<Visibility>(ctx.sprite_entity),
+        Some(&Visibility::Hidden)
+    );
+
+    // The entity is visible again when playing an animation with frames
+
+    ctx.add_animation_to_sprite(animation_id);
+
+    ctx.run(50);
+    ctx.check(4, [ctx.anim_start(animation_id)]);
+
+    assert_eq!(
+        ctx.app.world().get::<Visibility>(ctx.sprite_entity),
+        Some(&Visibility::Inherited)
+    );
+}
+
 #[test]
 fn animation_with_some_empty_clips() {
     let mut ctx = Context::new();
@@ -63,7 +110,7 @@ fn animation_with_some_empty_clips() {
     ctx.add_animation_to_sprite(animation_id);
 
     ctx.run(100);
-    ctx.check(9, []);
+    ctx.check(9, [ctx.anim_start(animation_id)]);
 
     ctx.run(100); // 0.2
     ctx.check(8, []);